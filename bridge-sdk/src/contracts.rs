@@ -0,0 +1,131 @@
+multiversx_sc::imports!();
+
+use core::cell::Cell;
+use multiversx_sc::api::StorageMapperApi;
+use multiversx_sc::codec::TopDecodeMulti;
+use multiversx_sc_scenario::{num_bigint, DebugApi};
+
+thread_local! {
+    static DEBUG_API_READY: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Managed types need an API context, even off-chain, so this has to be called
+/// before building managed arguments. It is only set up once per thread,
+/// because a new context would invalidate the managed values created so far.
+pub fn init_debug_api() {
+    DEBUG_API_READY.with(|ready| {
+        if !ready.get() {
+            let _ = DebugApi::dummy();
+            ready.set(true);
+        }
+    });
+}
+
+/// The fields of a transaction calling a bridge contract, ready to be signed and sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallTx {
+    pub receiver: Address,
+    pub egld_value: num_bigint::BigUint,
+    /// Endpoint name and hex-encoded arguments, e.g. `sign@05`
+    pub data: String,
+}
+
+macro_rules! bridge_contract {
+    ($name:ident, $proxy:ty, $contract_name:literal) => {
+        #[doc = concat!("A deployed `", $contract_name, "` contract.")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            pub address: Address,
+        }
+
+        impl $name {
+            pub fn new(address: Address) -> Self {
+                $name { address }
+            }
+
+            /// Typed builder for any of the contract's endpoints and views.
+            pub fn proxy(&self) -> $proxy {
+                init_debug_api();
+                <$proxy>::new_proxy_obj().contract(ManagedAddress::from(&self.address))
+            }
+
+            /// Encodes an endpoint call built with `proxy`.
+            /// Payments must be attached with `with_egld_or_single_esdt_transfer`,
+            /// as multi-token transfers are not sent to the contract directly.
+            pub fn call_tx<C: ContractCall<DebugApi>>(&self, call: C) -> CallTx {
+                build_call_tx(&self.address, call)
+            }
+        }
+    };
+}
+
+bridge_contract!(MultisigContract, multisig::Proxy<DebugApi>, "Multisig");
+bridge_contract!(EsdtSafeContract, esdt_safe::Proxy<DebugApi>, "EsdtSafe");
+bridge_contract!(
+    MultiTransferEsdtContract,
+    multi_transfer_esdt::Proxy<DebugApi>,
+    "MultiTransferEsdt"
+);
+
+fn build_call_tx<C: ContractCall<DebugApi>>(receiver: &Address, call: C) -> CallTx {
+    let normalized = call.into_normalized();
+    let egld_value = normalized.egld_payment.to_bytes_be();
+    let data = normalized.to_call_data_string().to_boxed_bytes();
+
+    CallTx {
+        receiver: receiver.clone(),
+        egld_value: num_bigint::BigUint::from_bytes_be(egld_value.as_slice()),
+        data: String::from_utf8(data.into_vec()).expect("call data is hex-encoded"),
+    }
+}
+
+/// Decodes the raw results of a view query into the return type of the view.
+/// The call is only used to pick the type, e.g.
+/// `decode_view_result(multisig.proxy().get_action_signer_count(action_id), raw_results)`.
+pub fn decode_view_result<C>(
+    _call: C,
+    mut raw_results: Vec<Vec<u8>>,
+) -> core::result::Result<C::OriginalResult, DecodeError>
+where
+    C: ContractCall<DebugApi>,
+    C::OriginalResult: TopDecodeMulti,
+{
+    init_debug_api();
+    C::OriginalResult::multi_decode(&mut raw_results)
+}
+
+/// Views declared directly on storage mappers have the mapper as return type in the proxies,
+/// so their results are decoded into the stored values instead.
+pub trait StorageViewResult {
+    type Value: TopDecodeMulti;
+}
+
+impl<SA, T> StorageViewResult for SingleValueMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+{
+    type Value = T;
+}
+
+impl<SA, T> StorageViewResult for UnorderedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    type Value = MultiValueVec<T>;
+}
+
+/// Same as `decode_view_result`, for views declared on storage mappers, e.g.
+/// `decode_storage_view_result(multisig.proxy().quorum(), raw_results)`.
+pub fn decode_storage_view_result<C>(
+    _call: C,
+    mut raw_results: Vec<Vec<u8>>,
+) -> core::result::Result<<C::OriginalResult as StorageViewResult>::Value, DecodeError>
+where
+    C: ContractCall<DebugApi>,
+    C::OriginalResult: StorageViewResult,
+{
+    init_debug_api();
+    <C::OriginalResult as StorageViewResult>::Value::multi_decode(&mut raw_results)
+}
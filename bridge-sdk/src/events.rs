@@ -0,0 +1,349 @@
+use multiversx_sc::codec::{DecodeError, TopDecode};
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::num_bigint::BigUint;
+use transaction::{transaction_status::TransactionStatus, ChainId};
+
+/// An event emitted by one of the bridge contracts.
+/// All the event arguments are indexed, so they can all be found in the log topics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BridgeEvent {
+    // Multisig
+    QuorumReached {
+        action_id: usize,
+    },
+    SlashEscrowed {
+        board_member: Address,
+        amount: BigUint,
+        appeal_deadline: u64,
+    },
+    SlashReversed {
+        board_member: Address,
+        amount: BigUint,
+    },
+    SlashFinalized {
+        board_member: Address,
+        amount: BigUint,
+    },
+    StakeDelegated {
+        delegator: Address,
+        board_member: Address,
+        amount: BigUint,
+    },
+    StakeUndelegated {
+        delegator: Address,
+        board_member: Address,
+        amount: BigUint,
+    },
+    RemoteReservesAttested {
+        token_id: Vec<u8>,
+        eth_block_number: u64,
+        locked_amount: BigUint,
+        action_id: usize,
+    },
+    AccountingSnapshot {
+        token_id: Vec<u8>,
+        snapshot_index: usize,
+        timestamp: u64,
+    },
+
+    // EsdtSafe
+    CreateTransaction {
+        batch_id: u64,
+        tx_id: u64,
+        net_amount: BigUint,
+        fee: BigUint,
+    },
+    CreateChainTransaction {
+        chain_id: ChainId,
+        batch_id: u64,
+        tx_id: u64,
+        net_amount: BigUint,
+        fee: BigUint,
+    },
+    ForwardedTransaction {
+        tx_id: u64,
+        forwarder: Address,
+    },
+    RelayerTipAdded {
+        tx_id: u64,
+        tip: BigUint,
+    },
+    RelayerTipPaid {
+        batch_id: u64,
+        tx_id: u64,
+        relayer: Address,
+        tip: BigUint,
+    },
+    AddRefundTransaction {
+        batch_id: u64,
+        tx_id: u64,
+        original_tx_id: u64,
+    },
+    RefundExpired {
+        address: Address,
+        token_id: String,
+        amount: BigUint,
+    },
+    ExpiredRefundsSwept {
+        token_id: String,
+        treasury: Address,
+        amount: BigUint,
+    },
+    BatchClosed {
+        chain_id: ChainId,
+        batch_id: u64,
+    },
+    SetStatus {
+        batch_id: u64,
+        tx_id: u64,
+        tx_status: TransactionStatus,
+    },
+    SetChainStatus {
+        chain_id: ChainId,
+        batch_id: u64,
+        tx_id: u64,
+        tx_status: TransactionStatus,
+    },
+
+    // MultiTransferEsdt
+    TransferPerformed {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    TransferFailedInvalidDestination {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    TransferFailedInvalidToken {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    TransferFailedFrozenDestinationAccount {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    TransferFailedScreening {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    BatchPartiallyExecuted {
+        batch_id: u64,
+        nr_remaining_transfers: usize,
+    },
+    EgldUnwrapped {
+        dest: Address,
+        amount: BigUint,
+    },
+    WegldSupplyDivergence {
+        outstanding_wegld: BigUint,
+        locked_egld: BigUint,
+    },
+    TransferOverMaxAmount {
+        batch_id: u64,
+        tx_id: u64,
+    },
+    TransferQuarantined {
+        batch_id: u64,
+        tx_id: u64,
+    },
+
+    // All contracts
+    /// Key and values are raw storage bytes, see the `config-events-module` crate.
+    ConfigChanged {
+        parameter_key: Vec<u8>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+        action_id: usize,
+    },
+}
+
+impl BridgeEvent {
+    /// Decodes the topics of a log entry, the first one being the event identifier.
+    /// Returns `None` for events that are not emitted by the bridge contracts,
+    /// like the ESDT transfer events that come with the bridge transactions.
+    pub fn decode(topics: &[Vec<u8>]) -> Result<Option<Self>, DecodeError> {
+        let (identifier, args) = match topics.split_first() {
+            Some(split) => split,
+            None => return Ok(None),
+        };
+        let mut args = EventArgs(args.iter());
+
+        let event = match identifier.as_slice() {
+            b"quorumReached" => BridgeEvent::QuorumReached {
+                action_id: args.next()?,
+            },
+            b"slashEscrowed" => BridgeEvent::SlashEscrowed {
+                board_member: args.next()?,
+                amount: args.next_big_uint()?,
+                appeal_deadline: args.next()?,
+            },
+            b"slashReversed" => BridgeEvent::SlashReversed {
+                board_member: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"slashFinalized" => BridgeEvent::SlashFinalized {
+                board_member: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"stakeDelegated" => BridgeEvent::StakeDelegated {
+                delegator: args.next()?,
+                board_member: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"stakeUndelegated" => BridgeEvent::StakeUndelegated {
+                delegator: args.next()?,
+                board_member: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"remoteReservesAttested" => BridgeEvent::RemoteReservesAttested {
+                token_id: args.next_topic()?.to_vec(),
+                eth_block_number: args.next()?,
+                locked_amount: args.next_big_uint()?,
+                action_id: args.next()?,
+            },
+            b"accountingSnapshot" => BridgeEvent::AccountingSnapshot {
+                token_id: args.next_topic()?.to_vec(),
+                snapshot_index: args.next()?,
+                timestamp: args.next()?,
+            },
+            b"createTransactionEvent" => BridgeEvent::CreateTransaction {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                net_amount: args.next_big_uint()?,
+                fee: args.next_big_uint()?,
+            },
+            b"createChainTransactionEvent" => BridgeEvent::CreateChainTransaction {
+                chain_id: args.next()?,
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                net_amount: args.next_big_uint()?,
+                fee: args.next_big_uint()?,
+            },
+            b"forwardedTransactionEvent" => BridgeEvent::ForwardedTransaction {
+                tx_id: args.next()?,
+                forwarder: args.next()?,
+            },
+            b"relayerTipAddedEvent" => BridgeEvent::RelayerTipAdded {
+                tx_id: args.next()?,
+                tip: args.next_big_uint()?,
+            },
+            b"relayerTipPaidEvent" => BridgeEvent::RelayerTipPaid {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                relayer: args.next()?,
+                tip: args.next_big_uint()?,
+            },
+            b"addRefundTransactionEvent" => BridgeEvent::AddRefundTransaction {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                original_tx_id: args.next()?,
+            },
+            b"refundExpiredEvent" => BridgeEvent::RefundExpired {
+                address: args.next()?,
+                token_id: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"expiredRefundsSweptEvent" => BridgeEvent::ExpiredRefundsSwept {
+                token_id: args.next()?,
+                treasury: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"batchClosedEvent" => BridgeEvent::BatchClosed {
+                chain_id: args.next()?,
+                batch_id: args.next()?,
+            },
+            b"setStatusEvent" => BridgeEvent::SetStatus {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                tx_status: args.next()?,
+            },
+            b"setChainStatusEvent" => BridgeEvent::SetChainStatus {
+                chain_id: args.next()?,
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+                tx_status: args.next()?,
+            },
+            b"transferPerformedEvent" => BridgeEvent::TransferPerformed {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"transferFailedInvalidDestination" => BridgeEvent::TransferFailedInvalidDestination {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"transferFailedInvalidToken" => BridgeEvent::TransferFailedInvalidToken {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"transferFailedFrozenDestinationAccount" => {
+                BridgeEvent::TransferFailedFrozenDestinationAccount {
+                    batch_id: args.next()?,
+                    tx_id: args.next()?,
+                }
+            }
+            b"transferFailedScreening" => BridgeEvent::TransferFailedScreening {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"batchPartiallyExecuted" => BridgeEvent::BatchPartiallyExecuted {
+                batch_id: args.next()?,
+                nr_remaining_transfers: args.next()?,
+            },
+            b"egldUnwrapped" => BridgeEvent::EgldUnwrapped {
+                dest: args.next()?,
+                amount: args.next_big_uint()?,
+            },
+            b"wegldSupplyDivergence" => BridgeEvent::WegldSupplyDivergence {
+                outstanding_wegld: args.next_big_uint()?,
+                locked_egld: args.next_big_uint()?,
+            },
+            b"transferOverMaxAmount" => BridgeEvent::TransferOverMaxAmount {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"transferQuarantined" => BridgeEvent::TransferQuarantined {
+                batch_id: args.next()?,
+                tx_id: args.next()?,
+            },
+            b"configChanged" => BridgeEvent::ConfigChanged {
+                parameter_key: args.next_topic()?.to_vec(),
+                old_value: args.next_topic()?.to_vec(),
+                new_value: args.next_topic()?.to_vec(),
+                action_id: args.next()?,
+            },
+            _ => return Ok(None),
+        };
+
+        args.finish()?;
+
+        Ok(Some(event))
+    }
+}
+
+struct EventArgs<'a>(core::slice::Iter<'a, Vec<u8>>);
+
+impl<'a> EventArgs<'a> {
+    fn next_topic(&mut self) -> Result<&'a [u8], DecodeError> {
+        self.0
+            .next()
+            .map(Vec::as_slice)
+            .ok_or(DecodeError::MULTI_TOO_FEW_ARGS)
+    }
+
+    fn next<T: TopDecode>(&mut self) -> Result<T, DecodeError> {
+        T::top_decode(self.next_topic()?)
+    }
+
+    /// Big integers are not decoded through the managed `BigUint`,
+    /// so decoding events does not need an API context.
+    fn next_big_uint(&mut self) -> Result<BigUint, DecodeError> {
+        Ok(BigUint::from_bytes_be(self.next_topic()?))
+    }
+
+    fn finish(mut self) -> Result<(), DecodeError> {
+        match self.0.next() {
+            Some(_) => Err(DecodeError::MULTI_TOO_MANY_ARGS),
+            None => Ok(()),
+        }
+    }
+}
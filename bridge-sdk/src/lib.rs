@@ -0,0 +1,18 @@
+//! Off-chain client for the bridge contracts, meant for the relayers and bots.
+//!
+//! Endpoint calls are built with the contracts' own proxies, so the arguments are always
+//! encoded the way the contracts expect them. View results are decoded into the return types
+//! of the same proxies, and events into `BridgeEvent`. Import `prelude::*` to get the endpoints
+//! of all the contract modules on the proxies.
+//!
+//! Errors returned by the contracts carry the codes from `bridge_errors`,
+//! re-exported here as `errors`.
+
+mod contracts;
+mod events;
+pub mod prelude;
+
+pub use bridge_errors as errors;
+pub use contracts::*;
+pub use events::*;
+pub use multiversx_sc_scenario::DebugApi;
@@ -0,0 +1,34 @@
+//! Brings the endpoints and views of every contract module into scope on the proxies,
+//! e.g. `sign` on `MultisigContract::proxy()`, which comes from a module of the multisig.
+
+pub use crate::{
+    decode_storage_view_result, decode_view_result, init_debug_api, BridgeEvent, CallTx,
+    EsdtSafeContract, MultiTransferEsdtContract, MultisigContract, StorageViewResult,
+};
+
+pub use esdt_safe::daily_limit::ProxyTrait as _;
+pub use esdt_safe::destination_limit::ProxyTrait as _;
+pub use esdt_safe::trusted_forwarders::ProxyTrait as _;
+pub use esdt_safe::ProxyTrait as _;
+pub use multi_transfer_esdt::ProxyTrait as _;
+pub use multisig::config_validation::ProxyTrait as _;
+pub use multisig::eth_attestation::ProxyTrait as _;
+pub use multisig::heartbeat::ProxyTrait as _;
+pub use multisig::multisig_general::ProxyTrait as _;
+pub use multisig::queries::ProxyTrait as _;
+pub use multisig::setup::ProxyTrait as _;
+pub use multisig::storage::ProxyTrait as _;
+pub use multisig::util::ProxyTrait as _;
+pub use multisig::ProxyTrait as _;
+
+pub use eth_signature_module::ProxyTrait as _;
+pub use execution_guard_module::ProxyTrait as _;
+pub use fee_estimator_module::ProxyTrait as _;
+pub use max_bridged_amount_module::ProxyTrait as _;
+pub use migration_module::ProxyTrait as _;
+pub use multiversx_sc_modules::pause::ProxyTrait as _;
+pub use ownership_module::ProxyTrait as _;
+pub use screening_module::ProxyTrait as _;
+pub use storage_inspection_module::ProxyTrait as _;
+pub use token_module::ProxyTrait as _;
+pub use tx_batch_module::ProxyTrait as _;
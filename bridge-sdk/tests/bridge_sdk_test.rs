@@ -0,0 +1,131 @@
+use bridge_sdk::{errors, prelude::*};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{
+    Address, BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedBuffer,
+};
+use multiversx_sc_scenario::{num_bigint, DebugApi};
+use transaction::transaction_status::TransactionStatus;
+
+#[test]
+fn test_endpoint_call_data() {
+    let multisig = MultisigContract::new(Address::from([1u8; 32]));
+
+    let tx = multisig.call_tx(multisig.proxy().sign(5usize));
+    assert_eq!(tx.receiver, multisig.address);
+    assert_eq!(tx.egld_value, num_bigint::BigUint::from(0u32));
+    assert_eq!(tx.data, "sign@05");
+}
+
+#[test]
+fn test_endpoint_call_with_esdt_payment() {
+    let safe = EsdtSafeContract::new(Address::from([2u8; 32]));
+    init_debug_api();
+
+    let payment = EgldOrEsdtTokenPayment::<DebugApi>::new(
+        EgldOrEsdtTokenIdentifier::esdt("WEGLD-123456"),
+        0,
+        BigUint::from(1_000u64),
+    );
+    let call = safe
+        .proxy()
+        .create_transaction(
+            ManagedBuffer::from(&[0x11u8; 20][..]),
+            OptionalValue::<u32>::None,
+        )
+        .with_egld_or_single_esdt_transfer(payment);
+
+    let tx = safe.call_tx(call);
+    assert_eq!(
+        tx.data,
+        format!(
+            "ESDTTransfer@{}@03e8@{}@{}",
+            hex::encode("WEGLD-123456"),
+            hex::encode("createTransaction"),
+            hex::encode([0x11u8; 20])
+        )
+    );
+}
+
+#[test]
+fn test_decode_view_result() {
+    let multisig = MultisigContract::new(Address::from([1u8; 32]));
+
+    let quorum = decode_storage_view_result(multisig.proxy().quorum(), vec![vec![3]]).unwrap();
+    assert_eq!(quorum, 3usize);
+
+    let signer_count = decode_view_result(
+        multisig.proxy().get_action_signer_count(1usize),
+        vec![vec![2]],
+    )
+    .unwrap();
+    assert_eq!(signer_count, 2usize);
+}
+
+#[test]
+fn test_decode_event() {
+    let topics = vec![
+        b"setStatusEvent".to_vec(),
+        vec![4],
+        vec![1, 0],
+        vec![TransactionStatus::Rejected as u8],
+    ];
+
+    assert_eq!(
+        BridgeEvent::decode(&topics).unwrap(),
+        Some(BridgeEvent::SetStatus {
+            batch_id: 4,
+            tx_id: 256,
+            tx_status: TransactionStatus::Rejected,
+        })
+    );
+}
+
+#[test]
+fn test_decode_config_changed_event() {
+    let topics = vec![
+        b"configChanged".to_vec(),
+        b"quorum".to_vec(),
+        vec![2],
+        vec![3],
+        vec![],
+    ];
+
+    assert_eq!(
+        BridgeEvent::decode(&topics).unwrap(),
+        Some(BridgeEvent::ConfigChanged {
+            parameter_key: b"quorum".to_vec(),
+            old_value: vec![2],
+            new_value: vec![3],
+            action_id: 0,
+        })
+    );
+}
+
+#[test]
+fn test_decode_unknown_or_malformed_event() {
+    let unknown = vec![b"ESDTTransfer".to_vec(), b"WEGLD-123456".to_vec()];
+    assert_eq!(BridgeEvent::decode(&unknown).unwrap(), None);
+
+    let missing_topic = vec![b"transferPerformedEvent".to_vec(), vec![1]];
+    assert!(BridgeEvent::decode(&missing_topic).is_err());
+
+    let extra_topic = vec![
+        b"transferPerformedEvent".to_vec(),
+        vec![1],
+        vec![2],
+        vec![3],
+    ];
+    assert!(BridgeEvent::decode(&extra_topic).is_err());
+}
+
+#[test]
+fn test_error_code() {
+    assert_eq!(
+        errors::error_code(errors::QUORUM_NOT_REACHED_ERR_MSG.as_bytes()),
+        Some(errors::ERR_QUORUM_NOT_REACHED)
+    );
+    assert_eq!(
+        errors::error_code(b"Endpoint can only be called by owner"),
+        None
+    );
+}
@@ -0,0 +1,5 @@
+//! Cross-contract scenarios for the whole bridge, run on the Rust blockchain mock.
+//!
+//! The scenarios in `mandos/` deploy the multisig next to EsdtSafe, MultiTransferEsdt
+//! and the BridgedTokensWrapper, and go through complete transfers in both directions,
+//! so a change in how the contracts call each other shows up as a failing test.
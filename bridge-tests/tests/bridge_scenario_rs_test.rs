@@ -0,0 +1,104 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("bridge-tests");
+
+    blockchain.register_contract(
+        "file:../multisig/output/multisig.wasm",
+        multisig::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "file:../esdt-safe/output/esdt-safe.wasm",
+        esdt_safe::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "file:../multi-transfer-esdt/output/multi-transfer-esdt.wasm",
+        multi_transfer_esdt::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "file:../bridged-tokens-wrapper/output/bridged-tokens-wrapper.wasm",
+        bridged_tokens_wrapper::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "file:../replay-protection/output/replay-protection.wasm",
+        replay_protection::ContractBuilder,
+    );
+
+    blockchain
+}
+
+#[test]
+fn accounting_snapshot_rs() {
+    world().run("mandos/accounting_snapshot.scen.json");
+}
+
+#[test]
+fn bridge_version_rs() {
+    world().run("mandos/bridge_version.scen.json");
+}
+
+#[test]
+fn config_changed_rs() {
+    world().run("mandos/config_changed.scen.json");
+}
+
+#[test]
+fn deposit_executed_rs() {
+    world().run("mandos/deposit_executed.scen.json");
+}
+
+#[test]
+fn deposit_rejected_refunded_rs() {
+    world().run("mandos/deposit_rejected_refunded.scen.json");
+}
+
+#[test]
+fn ethereum_to_multiversx_rs() {
+    world().run("mandos/ethereum_to_multiversx.scen.json");
+}
+
+#[test]
+fn gas_profile_1_rs() {
+    world().run("mandos/gas_profile_1.scen.json");
+}
+
+#[test]
+fn gas_profile_10_rs() {
+    world().run("mandos/gas_profile_10.scen.json");
+}
+
+#[test]
+fn gas_profile_50_rs() {
+    world().run("mandos/gas_profile_50.scen.json");
+}
+
+#[test]
+fn gas_profile_100_rs() {
+    world().run("mandos/gas_profile_100.scen.json");
+}
+
+#[test]
+fn gas_profile_200_rs() {
+    world().run("mandos/gas_profile_200.scen.json");
+}
+
+#[test]
+fn pause_rs() {
+    world().run("mandos/pause.scen.json");
+}
+
+#[test]
+fn relayer_tips_rs() {
+    world().run("mandos/relayer_tips.scen.json");
+}
+
+#[test]
+fn replay_protection_rs() {
+    world().run("mandos/replay_protection.scen.json");
+}
+
+#[test]
+fn slashed_relayer_rs() {
+    world().run("mandos/slashed_relayer.scen.json");
+}
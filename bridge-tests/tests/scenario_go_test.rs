@@ -0,0 +1,69 @@
+#[test]
+fn accounting_snapshot_go() {
+    multiversx_sc_scenario::run_go("mandos/accounting_snapshot.scen.json");
+}
+
+#[test]
+fn config_changed_go() {
+    multiversx_sc_scenario::run_go("mandos/config_changed.scen.json");
+}
+
+#[test]
+fn deposit_executed_go() {
+    multiversx_sc_scenario::run_go("mandos/deposit_executed.scen.json");
+}
+
+#[test]
+fn deposit_rejected_refunded_go() {
+    multiversx_sc_scenario::run_go("mandos/deposit_rejected_refunded.scen.json");
+}
+
+#[test]
+fn ethereum_to_multiversx_go() {
+    multiversx_sc_scenario::run_go("mandos/ethereum_to_multiversx.scen.json");
+}
+
+#[test]
+fn gas_profile_1_go() {
+    multiversx_sc_scenario::run_go("mandos/gas_profile_1.scen.json");
+}
+
+#[test]
+fn gas_profile_10_go() {
+    multiversx_sc_scenario::run_go("mandos/gas_profile_10.scen.json");
+}
+
+#[test]
+fn gas_profile_50_go() {
+    multiversx_sc_scenario::run_go("mandos/gas_profile_50.scen.json");
+}
+
+#[test]
+fn gas_profile_100_go() {
+    multiversx_sc_scenario::run_go("mandos/gas_profile_100.scen.json");
+}
+
+#[test]
+fn gas_profile_200_go() {
+    multiversx_sc_scenario::run_go("mandos/gas_profile_200.scen.json");
+}
+
+#[test]
+fn pause_go() {
+    multiversx_sc_scenario::run_go("mandos/pause.scen.json");
+}
+
+#[test]
+fn relayer_tips_go() {
+    multiversx_sc_scenario::run_go("mandos/relayer_tips.scen.json");
+}
+
+#[test]
+fn replay_protection_go() {
+    multiversx_sc_scenario::run_go("mandos/replay_protection.scen.json");
+}
+
+#[test]
+fn slashed_relayer_go() {
+    multiversx_sc_scenario::run_go("mandos/slashed_relayer.scen.json");
+}
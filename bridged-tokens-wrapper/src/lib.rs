@@ -4,18 +4,36 @@ mod dfp_big_uint;
 use core::ops::Deref;
 
 pub use dfp_big_uint::DFPBigUint;
+use migration_module::bridge_version::{BridgeVersion, InterfaceVersion};
+use migration_module::StorageVersion;
 use transaction::PaymentsVec;
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+const RETURN_CALL_GAS_RESERVE: u64 = 100_000;
+
 impl<M: ManagedTypeApi> DFPBigUint<M> {}
 
 #[multiversx_sc::contract]
-pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
+pub trait BridgedTokensWrapper:
+    ownership_module::OwnershipModule
+    + migration_module::MigrationModule
+    + multiversx_sc_modules::pause::PauseModule
+{
     #[init]
     fn init(&self) {
         self.set_paused(true);
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(INTERFACE_VERSION, 0, 0)
     }
 
     #[only_owner]
@@ -135,9 +153,16 @@ pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
     }
 
     /// Will wrap what it can, and send back the rest unchanged
+    ///
+    /// Smart contract callers can provide an endpoint of their own,
+    /// in which case the tokens are sent back through a call to that endpoint,
+    /// so the caller can react to the payment.
     #[payable("*")]
     #[endpoint(wrapTokens)]
-    fn wrap_tokens(&self) -> PaymentsVec<Self::Api> {
+    fn wrap_tokens(
+        &self,
+        opt_return_endpoint: OptionalValue<ManagedBuffer>,
+    ) -> PaymentsVec<Self::Api> {
         require!(self.not_paused(), "Contract is paused");
         let original_payments = self.call_value().all_esdt_transfers().deref().clone();
         if original_payments.is_empty() {
@@ -170,6 +195,8 @@ pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
 
             self.send()
                 .esdt_local_mint(&universal_token_id, 0, &converted_amount);
+            self.universal_token_supply(&universal_token_id)
+                .update(|supply| *supply += &converted_amount);
             new_payments.push(EsdtTokenPayment::new(
                 universal_token_id,
                 0,
@@ -178,7 +205,28 @@ pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
         }
 
         let caller = self.blockchain().get_caller();
-        self.send().direct_multi(&caller, &new_payments);
+        match opt_return_endpoint {
+            OptionalValue::Some(return_endpoint) => {
+                require!(
+                    self.blockchain().is_smart_contract(&caller),
+                    "Only smart contracts can receive a return call"
+                );
+
+                let gas_left = self.blockchain().get_gas_left();
+                require!(
+                    gas_left > RETURN_CALL_GAS_RESERVE,
+                    "Not enough gas for the return call"
+                );
+
+                let gas_limit = gas_left - RETURN_CALL_GAS_RESERVE;
+                self.send()
+                    .contract_call::<()>(caller, return_endpoint)
+                    .with_multi_token_transfer(new_payments.clone())
+                    .with_gas_limit(gas_limit)
+                    .transfer_execute();
+            }
+            OptionalValue::None => self.send().direct_multi(&caller, &new_payments),
+        }
 
         new_payments
     }
@@ -217,12 +265,54 @@ pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
 
         self.send()
             .esdt_local_burn(&universal_bridged_token_ids, 0, &payment_amount);
+        self.universal_token_supply(&universal_bridged_token_ids)
+            .update(|supply| {
+                // supply minted before this was tracked is not counted
+                if *supply > payment_amount {
+                    *supply -= &payment_amount;
+                } else {
+                    *supply = BigUint::zero();
+                }
+            });
 
         let caller = self.blockchain().get_caller();
         self.send()
             .direct_esdt(&caller, chain_specific_token_id, 0, &converted_amount);
     }
 
+    /// Liquidity of each chain-specific token of the universal token, as
+    /// (chain-specific token ID, liquidity, liquidity converted to the universal token's decimals).
+    ///
+    /// Universal tokens can only be unwrapped to a chain-specific token up to its liquidity,
+    /// so comparing the converted liquidity with `getUniversalTokenSupply` shows how much
+    /// of the supply each source chain backs.
+    #[view(getChainCollateralization)]
+    fn get_chain_collateralization(
+        &self,
+        universal_token_id: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue3<TokenIdentifier, BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for chain_specific_token_id in self.chain_specific_token_ids(&universal_token_id).iter() {
+            let liquidity = self.token_liquidity(&chain_specific_token_id).get();
+            let liquidity_in_universal_token = self.get_converted_amount(
+                &chain_specific_token_id,
+                &universal_token_id,
+                liquidity.clone(),
+            );
+
+            result.push(
+                (
+                    chain_specific_token_id,
+                    liquidity,
+                    liquidity_in_universal_token,
+                )
+                    .into(),
+            );
+        }
+
+        result
+    }
+
     fn get_converted_amount(
         &self,
         from: &TokenIdentifier,
@@ -267,6 +357,15 @@ pub trait BridgedTokensWrapper: multiversx_sc_modules::pause::PauseModule {
     #[storage_mapper("tokenLiquidity")]
     fn token_liquidity(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
+    /// Amount of the universal token minted by wrapping and not unwrapped since.
+    /// Only counts wraps done since the contract started tracking it.
+    #[view(getUniversalTokenSupply)]
+    #[storage_mapper("universalTokenSupply")]
+    fn universal_token_supply(
+        &self,
+        universal_token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
     #[view(getChainSpecificToUniversalMapping)]
     #[storage_mapper("chainSpecificToUniversalMapping")]
     fn chain_specific_to_universal_mapping(
@@ -27,3 +27,8 @@ fn add_wrapped_token_go() {
 fn remove_wrapped_token_go() {
     multiversx_sc_scenario::run_go("mandos/remove_wrapped_token.scen.json");
 }
+
+#[test]
+fn chain_collateralization_go() {
+    multiversx_sc_scenario::run_go("mandos/chain_collateralization.scen.json");
+}
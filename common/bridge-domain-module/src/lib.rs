@@ -0,0 +1,99 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    BRIDGE_DOMAIN_ALREADY_SET_ERR_MSG, BRIDGE_DOMAIN_NOT_SET_ERR_MSG,
+    EVM_DOMAIN_ALREADY_SET_ERR_MSG, EVM_DOMAIN_NOT_SET_ERR_MSG,
+};
+use eth_address::EthAddress;
+use transaction::eip712::{BridgeDomain, Eip712Domain};
+
+/// Keeps the domain of this bridge deployment, see `transaction::eip712::BridgeDomain`.
+/// Batch hashes and attestation digests can only be computed once it is set.
+///
+/// Also keeps the EIP-712 domain of the Ethereum-side contract,
+/// needed for the digests relayers sign for that side.
+#[multiversx_sc::module]
+pub trait BridgeDomainModule {
+    /// Can only be set once, as changing it would invalidate all the signatures made so far.
+    #[only_owner]
+    #[endpoint(setBridgeDomain)]
+    fn set_bridge_domain(&self, chain_id: ManagedBuffer, bridge_instance_id: u64) {
+        let bridge_domain_mapper = self.bridge_domain();
+        require!(
+            bridge_domain_mapper.is_empty(),
+            BRIDGE_DOMAIN_ALREADY_SET_ERR_MSG
+        );
+
+        bridge_domain_mapper.set(BridgeDomain {
+            chain_id,
+            bridge_instance_id,
+        });
+    }
+
+    /// The domain of the Ethereum-side bridge contract, as given to its EIP-712 domain separator.
+    /// Can only be set once, for the same reason as the bridge domain.
+    #[only_owner]
+    #[endpoint(setEvmDomain)]
+    fn set_evm_domain(
+        &self,
+        name: ManagedBuffer,
+        version: ManagedBuffer,
+        chain_id: BigUint,
+        verifying_contract: EthAddress<Self::Api>,
+    ) {
+        let evm_domain_mapper = self.evm_domain();
+        require!(evm_domain_mapper.is_empty(), EVM_DOMAIN_ALREADY_SET_ERR_MSG);
+
+        evm_domain_mapper.set(Eip712Domain {
+            name,
+            version,
+            chain_id,
+            verifying_contract,
+        });
+    }
+
+    #[view(getBridgeDomain)]
+    fn get_bridge_domain(&self) -> OptionalValue<BridgeDomain<Self::Api>> {
+        let bridge_domain_mapper = self.bridge_domain();
+        if bridge_domain_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(bridge_domain_mapper.get())
+    }
+
+    fn get_configured_bridge_domain(&self) -> BridgeDomain<Self::Api> {
+        let bridge_domain_mapper = self.bridge_domain();
+        require!(
+            !bridge_domain_mapper.is_empty(),
+            BRIDGE_DOMAIN_NOT_SET_ERR_MSG
+        );
+
+        bridge_domain_mapper.get()
+    }
+
+    #[view(getEvmDomain)]
+    fn get_evm_domain(&self) -> OptionalValue<Eip712Domain<Self::Api>> {
+        let evm_domain_mapper = self.evm_domain();
+        if evm_domain_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(evm_domain_mapper.get())
+    }
+
+    fn get_configured_evm_domain(&self) -> Eip712Domain<Self::Api> {
+        let evm_domain_mapper = self.evm_domain();
+        require!(!evm_domain_mapper.is_empty(), EVM_DOMAIN_NOT_SET_ERR_MSG);
+
+        evm_domain_mapper.get()
+    }
+
+    #[storage_mapper("bridgeDomain")]
+    fn bridge_domain(&self) -> SingleValueMapper<BridgeDomain<Self::Api>>;
+
+    #[storage_mapper("evmDomain")]
+    fn evm_domain(&self) -> SingleValueMapper<Eip712Domain<Self::Api>>;
+}
@@ -0,0 +1,571 @@
+//! Error codes shared by the bridge contracts.
+//!
+//! Every error message starts with a numeric code, followed by a description,
+//! e.g. `1007: quorum has not been reached`. Codes are stable: once released,
+//! a code is never reassigned, so off-chain tools should match on the code
+//! rather than on the text, which may be reworded.
+//!
+//! Codes are grouped by the contract that raises them:
+//! - `1xxx` - Multisig
+//! - `2xxx` - EsdtSafe
+//! - `3xxx` - MultiTransferEsdt
+//! - `4xxx` - modules shared between contracts
+//! - `5xxx` - ReplayProtection
+
+#![no_std]
+
+/// Separates the code from the description in an error message.
+const CODE_SEPARATOR: &[u8] = b": ";
+
+macro_rules! bridge_errors {
+    ($($code_name:ident = $code:literal, $msg_name:ident = $msg:literal;)*) => {
+        $(
+            pub const $code_name: u32 = $code;
+            pub const $msg_name: &str = concat!($code, ": ", $msg);
+        )*
+    };
+}
+
+/// Extracts the error code from an error message returned by a bridge contract.
+/// Returns `None` for messages that carry no code, like the framework's own errors.
+pub fn error_code(message: &[u8]) -> Option<u32> {
+    let separator_pos = message
+        .windows(CODE_SEPARATOR.len())
+        .position(|window| window == CODE_SEPARATOR)?;
+    let digits = &message[..separator_pos];
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    digits.iter().try_fold(0u32, |code, digit| {
+        code.checked_mul(10)?.checked_add((digit - b'0') as u32)
+    })
+}
+
+bridge_errors! {
+    // Multisig
+
+    ERR_ACTION_DOES_NOT_EXIST = 1001,
+    ACTION_DOES_NOT_EXIST_ERR_MSG = "action does not exist";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_SIGN = 1002,
+    ONLY_BOARD_MEMBERS_CAN_SIGN_ERR_MSG = "only board members can sign";
+
+    ERR_NOT_ENOUGH_STAKE = 1003,
+    NOT_ENOUGH_STAKE_ERR_MSG = "not enough stake";
+
+    ERR_ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_PROPOSE = 1004,
+    ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_PROPOSE_ERR_MSG = "only board members and proposers can propose";
+
+    ERR_PROPOSING_WHILE_PAUSED = 1005,
+    PROPOSING_WHILE_PAUSED_ERR_MSG = "No actions may be proposed while paused";
+
+    ERR_ONLY_BOARD_MEMBERS_PROPOSERS_AND_EXECUTORS_CAN_PERFORM = 1006,
+    ONLY_BOARD_MEMBERS_PROPOSERS_AND_EXECUTORS_CAN_PERFORM_ERR_MSG = "only board members, proposers and executors can perform actions";
+
+    ERR_QUORUM_NOT_REACHED = 1007,
+    QUORUM_NOT_REACHED_ERR_MSG = "quorum has not been reached";
+
+    ERR_SUPER_QUORUM_NOT_REACHED = 1008,
+    SUPER_QUORUM_NOT_REACHED_ERR_MSG = "super quorum has not been reached";
+
+    ERR_PERFORMING_WHILE_PAUSED = 1009,
+    PERFORMING_WHILE_PAUSED_ERR_MSG = "No actions may be executed while paused";
+
+    ERR_ACTION_ALREADY_EXECUTED = 1010,
+    ACTION_ALREADY_EXECUTED_ERR_MSG = "Action was already executed";
+
+    ERR_STALE_ACTION = 1011,
+    STALE_ACTION_ERR_MSG = "Action refers to an already settled batch";
+
+    ERR_ACTION_NOT_STALE = 1012,
+    ACTION_NOT_STALE_ERR_MSG = "Action is not stale";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_REGISTER_KEYS = 1013,
+    ONLY_BOARD_MEMBERS_CAN_REGISTER_KEYS_ERR_MSG = "only board members can register keys";
+
+    ERR_NOT_A_BOARD_MEMBER = 1014,
+    NOT_A_BOARD_MEMBER_ERR_MSG = "not a board member";
+
+    ERR_NO_ETH_PUBLIC_KEY = 1015,
+    NO_ETH_PUBLIC_KEY_ERR_MSG = "No Ethereum public key registered";
+
+    ERR_ATTESTATIONS_DO_NOT_CONFLICT = 1016,
+    ATTESTATIONS_DO_NOT_CONFLICT_ERR_MSG = "Attestations do not conflict";
+
+    ERR_INVALID_ETH_SIGNATURE = 1017,
+    INVALID_ETH_SIGNATURE_ERR_MSG = "Invalid Ethereum signature";
+
+    ERR_ACTION_SERIALIZATION_FAILED = 1018,
+    ACTION_SERIALIZATION_FAILED_ERR_MSG = "Failed to serialize action";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_SEND_HEARTBEATS = 1019,
+    ONLY_BOARD_MEMBERS_CAN_SEND_HEARTBEATS_ERR_MSG = "only board members can send heartbeats";
+
+    ERR_DUPLICATE_BOARD_MEMBER = 1020,
+    DUPLICATE_BOARD_MEMBER_ERR_MSG = "duplicate board member";
+
+    ERR_SLASH_AMOUNT_ABOVE_REQUIRED_STAKE = 1021,
+    SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG = "slash amount must be less than or equal to required stake";
+
+    ERR_ESDT_SAFE_NOT_SC = 1022,
+    ESDT_SAFE_NOT_SC_ERR_MSG = "Esdt Safe address is not a Smart Contract address";
+
+    ERR_MULTI_TRANSFER_NOT_SC = 1023,
+    MULTI_TRANSFER_NOT_SC_ERR_MSG = "Multi Transfer address is not a Smart Contract address";
+
+    ERR_TRANSFER_TO_SC = 1024,
+    TRANSFER_TO_SC_ERR_MSG = "Cannot transfer to smart contract dest_address";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_STAKE = 1025,
+    ONLY_BOARD_MEMBERS_CAN_STAKE_ERR_MSG = "Only board members can stake";
+
+    ERR_UNSTAKE_MORE_THAN_STAKED = 1026,
+    UNSTAKE_MORE_THAN_STAKED_ERR_MSG = "can't unstake more than amount staked";
+
+    ERR_UNSTAKE_BELOW_MINIMUM = 1027,
+    UNSTAKE_BELOW_MINIMUM_ERR_MSG = "can't unstake, must keep minimum amount as insurance";
+
+    ERR_BATCH_NOT_EXECUTED = 1028,
+    BATCH_NOT_EXECUTED_ERR_MSG = "Batch was not executed yet";
+
+    ERR_NO_TRANSFERS_PROVIDED = 1029,
+    NO_TRANSFERS_PROVIDED_ERR_MSG = "No transfers provided";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_MANAGE_PROPOSERS = 1030,
+    ONLY_BOARD_MEMBERS_CAN_MANAGE_PROPOSERS_ERR_MSG = "only board members can manage proposers";
+
+    ERR_USER_ALREADY_HAS_ROLE = 1031,
+    USER_ALREADY_HAS_ROLE_ERR_MSG = "user already has a role";
+
+    ERR_USER_NOT_PROPOSER = 1032,
+    USER_NOT_PROPOSER_ERR_MSG = "user is not a proposer";
+
+    ERR_BUNDLE_TOO_SMALL = 1033,
+    BUNDLE_TOO_SMALL_ERR_MSG = "Bundle must contain at least two actions";
+
+    ERR_BUNDLED_ACTION_DOES_NOT_EXIST = 1034,
+    BUNDLED_ACTION_DOES_NOT_EXIST_ERR_MSG = "Action does not exist";
+
+    ERR_NESTED_BUNDLE = 1035,
+    NESTED_BUNDLE_ERR_MSG = "Bundles cannot contain other bundles";
+
+    ERR_DUPLICATE_BUNDLED_ACTION = 1036,
+    DUPLICATE_BUNDLED_ACTION_ERR_MSG = "Action is already in the bundle";
+
+    ERR_BUNDLED_ACTION_ALREADY_EXECUTED = 1037,
+    BUNDLED_ACTION_ALREADY_EXECUTED_ERR_MSG = "Bundled action was already executed";
+
+    ERR_INVALID_BUNDLED_ACTION = 1038,
+    INVALID_BUNDLED_ACTION_ERR_MSG = "Invalid bundled action";
+
+    ERR_CURRENT_BATCH_EMPTY = 1039,
+    CURRENT_BATCH_EMPTY_ERR_MSG = "Current batch is empty";
+
+    ERR_ACTION_ALREADY_PROPOSED = 1040,
+    ACTION_ALREADY_PROPOSED_ERR_MSG = "Action already proposed";
+
+    ERR_STATUS_COUNT_MISMATCH = 1041,
+    STATUS_COUNT_MISMATCH_ERR_MSG = "Number of statuses provided must be equal to number of transactions in current batch";
+
+    ERR_WRONG_ESDT_SAFE_BATCH_ID = 1042,
+    WRONG_ESDT_SAFE_BATCH_ID_ERR_MSG = "Current EsdtSafe tx batch does not have the provided ID";
+
+    ERR_WRONG_NFT_SAFE_BATCH_ID = 1043,
+    WRONG_NFT_SAFE_BATCH_ID_ERR_MSG = "Current NftSafe tx batch does not have the provided ID";
+
+    ERR_NOT_NEXT_BATCH_ID = 1044,
+    NOT_NEXT_BATCH_ID_ERR_MSG = "Can only propose for next batch ID";
+
+    ERR_INVALID_TX_ID = 1045,
+    INVALID_TX_ID_ERR_MSG = "Invalid Tx ID";
+
+    ERR_INVALID_NUMBER_OF_ARGUMENTS = 1046,
+    INVALID_NUMBER_OF_ARGUMENTS_ERR_MSG = "Invalid number of arguments";
+
+    ERR_ENDPOINT_NOT_ALLOWED_IN_MULTICALL = 1047,
+    ENDPOINT_NOT_ALLOWED_IN_MULTICALL_ERR_MSG = "Endpoint not allowed in multicall";
+
+    ERR_INVALID_MULTICALL_ARGUMENT = 1048,
+    INVALID_MULTICALL_ARGUMENT_ERR_MSG = "Invalid multicall argument";
+
+    ERR_NO_SLASH_UNDER_APPEAL = 1049,
+    NO_SLASH_UNDER_APPEAL_ERR_MSG = "No slash under appeal";
+
+    ERR_NO_SLASH_IN_ESCROW = 1050,
+    NO_SLASH_IN_ESCROW_ERR_MSG = "No slash in escrow";
+
+    ERR_APPEAL_WINDOW_NOT_ENDED = 1051,
+    APPEAL_WINDOW_NOT_ENDED_ERR_MSG = "Appeal window has not ended";
+
+    ERR_TOKEN_ID_MAPPING_EXISTS = 1052,
+    TOKEN_ID_MAPPING_EXISTS_ERR_MSG = "Mapping already exists for token ID";
+
+    ERR_ERC20_MAPPING_EXISTS = 1053,
+    ERC20_MAPPING_EXISTS_ERR_MSG = "Mapping already exists for ERC20 token";
+
+    ERR_ERC20_MAPPING_DOES_NOT_EXIST = 1054,
+    ERC20_MAPPING_DOES_NOT_EXIST_ERR_MSG = "Mapping does not exist for ERC20 token";
+
+    ERR_TOKEN_ID_MAPPING_DOES_NOT_EXIST = 1055,
+    TOKEN_ID_MAPPING_DOES_NOT_EXIST_ERR_MSG = "Mapping does not exist for token id";
+
+    ERR_INVALID_MAPPING = 1056,
+    INVALID_MAPPING_ERR_MSG = "Invalid mapping";
+
+    ERR_NFT_SAFE_NOT_SC = 1057,
+    NFT_SAFE_NOT_SC_ERR_MSG = "Nft Safe address is not a Smart Contract address";
+
+    ERR_NFT_TRANSFER_NOT_SC = 1058,
+    NFT_TRANSFER_NOT_SC_ERR_MSG = "Nft Transfer address is not a Smart Contract address";
+
+    ERR_BATCH_SERIALIZATION_FAILED = 1059,
+    BATCH_SERIALIZATION_FAILED_ERR_MSG = "Failed to serialized batch";
+
+    ERR_NFT_BRIDGE_NOT_SET_UP = 1060,
+    NFT_BRIDGE_NOT_SET_UP_ERR_MSG = "NFT bridge not set up";
+
+    ERR_EMPTY_BOARD = 1061,
+    EMPTY_BOARD_ERR_MSG = "cannot remove all board members";
+
+    ERR_QUORUM_ABOVE_BOARD_SIZE = 1062,
+    QUORUM_ABOVE_BOARD_SIZE_ERR_MSG = "quorum cannot exceed board size";
+
+    ERR_QUORUM_BELOW_MINIMUM = 1063,
+    QUORUM_BELOW_MINIMUM_ERR_MSG = "quorum below minimum";
+
+    ERR_SUPER_QUORUM_OUT_OF_RANGE = 1064,
+    SUPER_QUORUM_OUT_OF_RANGE_ERR_MSG = "super quorum must be between quorum and board size";
+
+    ERR_BOARD_MEMBER_NOT_STAKED = 1065,
+    BOARD_MEMBER_NOT_STAKED_ERR_MSG = "board member has not enough stake";
+
+    ERR_BATCH_ALREADY_PROPOSED = 1066,
+    BATCH_ALREADY_PROPOSED_ERR_MSG = "This batch was already proposed";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_REGISTER_METADATA = 1067,
+    ONLY_BOARD_MEMBERS_CAN_REGISTER_METADATA_ERR_MSG = "only board members can register metadata";
+
+    ERR_INVALID_OPERATOR_NAME_LENGTH = 1068,
+    INVALID_OPERATOR_NAME_LENGTH_ERR_MSG = "operator name must have between 1 and 64 bytes";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_SET_SIGNING_KEY = 1069,
+    ONLY_BOARD_MEMBERS_CAN_SET_SIGNING_KEY_ERR_MSG = "only board members can set a signing key";
+
+    ERR_SIGNING_KEY_ALREADY_IN_USE = 1070,
+    SIGNING_KEY_ALREADY_IN_USE_ERR_MSG = "signing key is already in use";
+
+    ERR_ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_SET_NOTIFIER = 1071,
+    ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_SET_NOTIFIER_ERR_MSG = "only board members and proposers can set an execution notifier";
+
+    ERR_EXECUTION_NOTIFIER_NOT_SC = 1072,
+    EXECUTION_NOTIFIER_NOT_SC_ERR_MSG = "execution notifier must be a smart contract";
+
+    ERR_NO_BATCH_STATUSES_PROVIDED = 1073,
+    NO_BATCH_STATUSES_PROVIDED_ERR_MSG = "no batch statuses provided";
+
+    ERR_BATCH_IDS_NOT_CONSECUTIVE = 1074,
+    BATCH_IDS_NOT_CONSECUTIVE_ERR_MSG = "batch IDs must be consecutive";
+
+    ERR_BATCH_NOT_FULL = 1075,
+    BATCH_NOT_FULL_ERR_MSG = "batch is not full yet";
+
+    ERR_ONBOARDING_NOT_COMPLETED = 1076,
+    ONBOARDING_NOT_COMPLETED_ERR_MSG = "board member has not completed onboarding";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_MANAGE_EXECUTORS = 1077,
+    ONLY_BOARD_MEMBERS_CAN_MANAGE_EXECUTORS_ERR_MSG = "only board members can manage executors";
+
+    ERR_USER_NOT_EXECUTOR = 1078,
+    USER_NOT_EXECUTOR_ERR_MSG = "user is not an executor";
+
+    ERR_RESERVES_BLOCK_NOT_NEWER = 1079,
+    RESERVES_BLOCK_NOT_NEWER_ERR_MSG = "reserves must be attested for a block after the last attested one";
+
+    ERR_NO_RESERVES_PROVIDED = 1080,
+    NO_RESERVES_PROVIDED_ERR_MSG = "no reserves provided";
+
+    ERR_DUPLICATE_RESERVE_TOKEN = 1081,
+    DUPLICATE_RESERVE_TOKEN_ERR_MSG = "reserves provided more than once for the same token";
+
+    ERR_BOARD_ABOVE_MAX_SIZE = 1082,
+    BOARD_ABOVE_MAX_SIZE_ERR_MSG = "board size cannot exceed the maximum board size";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_ADD_BOARD_MEMBERS = 1083,
+    ONLY_BOARD_MEMBERS_CAN_ADD_BOARD_MEMBERS_ERR_MSG = "only board members can add board members";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_REMOVE_BOARD_MEMBERS = 1084,
+    ONLY_BOARD_MEMBERS_CAN_REMOVE_BOARD_MEMBERS_ERR_MSG = "only board members can remove board members";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_CHANGE_QUORUM = 1085,
+    ONLY_BOARD_MEMBERS_CAN_CHANGE_QUORUM_ERR_MSG = "only board members can change the quorum";
+
+    ERR_BOARD_MEMBERS_CANNOT_DELEGATE = 1086,
+    BOARD_MEMBERS_CANNOT_DELEGATE_ERR_MSG = "board members cannot delegate stake";
+
+    ERR_DELEGATION_TO_NON_BOARD_MEMBER = 1087,
+    DELEGATION_TO_NON_BOARD_MEMBER_ERR_MSG = "stake can only be delegated to board members";
+
+    ERR_UNDELEGATE_MORE_THAN_DELEGATED = 1088,
+    UNDELEGATE_MORE_THAN_DELEGATED_ERR_MSG = "can't undelegate more than amount delegated";
+
+    ERR_DELEGATION_POOL_SLASHED = 1089,
+    DELEGATION_POOL_SLASHED_ERR_MSG = "the stake delegated to this board member was slashed entirely";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_AUTO_PROPOSE = 1090,
+    ONLY_BOARD_MEMBERS_CAN_AUTO_PROPOSE_ERR_MSG = "only board members can auto-propose a round";
+
+    ERR_NOTHING_TO_PROPOSE = 1091,
+    NOTHING_TO_PROPOSE_ERR_MSG = "no statuses or transfers to propose";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_PROPOSE_SLASH = 1092,
+    ONLY_BOARD_MEMBERS_CAN_PROPOSE_SLASH_ERR_MSG = "only board members can propose a slash";
+
+    ERR_INVALID_STAKING_TOKEN = 1093,
+    INVALID_STAKING_TOKEN_ERR_MSG = "payment must be in the staking token";
+
+    ERR_STAKING_TOKEN_CHANGED = 1094,
+    STAKING_TOKEN_CHANGED_ERR_MSG = "staking token cannot be changed";
+
+    ERR_ACTION_NOT_SIGNED_BY_CALLER = 1095,
+    ACTION_NOT_SIGNED_BY_CALLER_ERR_MSG = "caller has not signed this action";
+
+    ERR_ACTION_EXPIRED = 1096,
+    ACTION_EXPIRED_ERR_MSG = "action has expired";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_VOTE_TO_DISCARD = 1097,
+    ONLY_BOARD_MEMBERS_CAN_VOTE_TO_DISCARD_ERR_MSG = "only board members can vote to discard an action";
+
+    ERR_INVALID_QUORUM_PERCENTAGE = 1098,
+    INVALID_QUORUM_PERCENTAGE_ERR_MSG = "quorum percentage cannot exceed 100%";
+
+    ERR_QUORUM_SET_AS_PERCENTAGE = 1099,
+    QUORUM_SET_AS_PERCENTAGE_ERR_MSG = "quorum is set as a percentage of the board size";
+
+    ERR_EXECUTION_TIMELOCK_NOT_ENDED = 1100,
+    EXECUTION_TIMELOCK_NOT_ENDED_ERR_MSG = "execution timelock has not ended";
+
+    ERR_ONLY_BOARD_MEMBERS_CAN_VETO = 1101,
+    ONLY_BOARD_MEMBERS_CAN_VETO_ERR_MSG = "only board members can veto an action";
+
+    ERR_VETOES_DISABLED = 1102,
+    VETOES_DISABLED_ERR_MSG = "vetoes are disabled";
+
+    ERR_ACTION_ALREADY_VETOED_BY_CALLER = 1103,
+    ACTION_ALREADY_VETOED_BY_CALLER_ERR_MSG = "caller already vetoed this action";
+
+    ERR_ACTION_CALL_PENDING = 1104,
+    ACTION_CALL_PENDING_ERR_MSG = "action call is pending";
+
+    ERR_ACTION_CALL_NOT_PENDING = 1105,
+    ACTION_CALL_NOT_PENDING_ERR_MSG = "action call is not pending";
+
+    ERR_NOT_ENOUGH_GAS_FOR_ACTION_CALL = 1106,
+    NOT_ENOUGH_GAS_FOR_ACTION_CALL_ERR_MSG = "not enough gas to perform the action";
+
+    ERR_ACTION_VETOED = 1107,
+    ACTION_VETOED_ERR_MSG = "action was vetoed and cannot be proposed again";
+
+    ERR_UNWRAP_EGLD_TX_NOT_IN_BATCH = 1108,
+    UNWRAP_EGLD_TX_NOT_IN_BATCH_ERR_MSG = "transfer to unwrap is not part of the batch";
+
+    // EsdtSafe
+
+    ERR_CREATE_TX_WHILE_PAUSED = 2001,
+    CREATE_TX_WHILE_PAUSED_ERR_MSG = "Cannot create transaction while paused";
+
+    ERR_FEES_OVER_AMOUNT = 2002,
+    FEES_OVER_AMOUNT_ERR_MSG = "Transaction fees cost more than the entire bridged amount";
+
+    ERR_DESTINATION_CHAIN_NOT_SUPPORTED = 2003,
+    DESTINATION_CHAIN_NOT_SUPPORTED_ERR_MSG = "Destination chain not supported";
+
+    ERR_INVALID_DESTINATION_ADDRESS = 2004,
+    INVALID_DESTINATION_ADDRESS_ERR_MSG = "Invalid destination address";
+
+    ERR_INVALID_ADDRESS_LENGTH_BOUNDS = 2005,
+    INVALID_ADDRESS_LENGTH_BOUNDS_ERR_MSG = "Invalid address length bounds";
+
+    ERR_NOTHING_TO_REFUND = 2006,
+    NOTHING_TO_REFUND_ERR_MSG = "Nothing to refund";
+
+    ERR_REFUND_EXPIRED = 2007,
+    REFUND_EXPIRED_ERR_MSG = "Refund expired";
+
+    ERR_REFUND_NOT_EXPIRED = 2008,
+    REFUND_NOT_EXPIRED_ERR_MSG = "Refund is not expired";
+
+    ERR_NO_EXPIRED_REFUNDS = 2009,
+    NO_EXPIRED_REFUNDS_ERR_MSG = "No expired refunds";
+
+    ERR_BATCH_DOES_NOT_EXIST = 2010,
+    BATCH_DOES_NOT_EXIST_ERR_MSG = "Batch does not exist";
+
+    ERR_BATCHES_OUT_OF_ORDER = 2011,
+    BATCHES_OUT_OF_ORDER_ERR_MSG = "Batches must be processed in order";
+
+    ERR_INVALID_NUMBER_OF_STATUSES = 2012,
+    INVALID_NUMBER_OF_STATUSES_ERR_MSG = "Invalid number of statuses provided";
+
+    ERR_INVALID_TX_STATUS = 2013,
+    INVALID_TX_STATUS_ERR_MSG = "Transaction status may only be set to Executed or Rejected";
+
+    ERR_DAILY_LIMIT_EXCEEDED = 2014,
+    DAILY_LIMIT_EXCEEDED_ERR_MSG = "Daily bridge limit exceeded";
+
+    ERR_NO_USD_PRICE = 2015,
+    NO_USD_PRICE_ERR_MSG = "No USD price for token";
+
+    ERR_DESTINATION_LIMIT_EXCEEDED = 2016,
+    DESTINATION_LIMIT_EXCEEDED_ERR_MSG = "Destination address limit exceeded";
+
+    ERR_TOO_MANY_PENDING_BATCHES = 2017,
+    TOO_MANY_PENDING_BATCHES_ERR_MSG = "Too many pending batches, the bridge is not processing transfers";
+
+    ERR_FORWARDER_NOT_SC = 2018,
+    FORWARDER_NOT_SC_ERR_MSG = "Forwarder must be a smart contract";
+
+    ERR_NOT_TRUSTED_FORWARDER = 2019,
+    NOT_TRUSTED_FORWARDER_ERR_MSG = "Caller is not a trusted forwarder";
+
+    ERR_DEPOSIT_REJECTED_BY_SCREENING = 2020,
+    DEPOSIT_REJECTED_BY_SCREENING_ERR_MSG = "Deposit rejected by address screening";
+
+    ERR_BATCH_NOT_READY = 2021,
+    BATCH_NOT_READY_ERR_MSG = "Batch is not ready for its statuses to be set";
+
+    ERR_RELAYED_REFUND_CLAIMS_DISABLED = 2022,
+    RELAYED_REFUND_CLAIMS_DISABLED_ERR_MSG = "Relayed refund claims are disabled for this token";
+
+    ERR_NOT_ENOUGH_FEES_FOR_CLAIM_BOUNTY = 2023,
+    NOT_ENOUGH_FEES_FOR_CLAIM_BOUNTY_ERR_MSG = "Not enough accumulated fees to pay the claim bounty";
+
+    ERR_RELAYER_TIP_OVER_AMOUNT = 2024,
+    RELAYER_TIP_OVER_AMOUNT_ERR_MSG = "Relayer tip must be lower than the deposited amount";
+
+    ERR_INVALID_FEE_PAYMENT = 2025,
+    INVALID_FEE_PAYMENT_ERR_MSG = "Fee payment must be in the deposited token, which must have its fee paid on top";
+
+    ERR_FEE_PAYMENT_TOO_LOW = 2026,
+    FEE_PAYMENT_TOO_LOW_ERR_MSG = "Fee payment is lower than the required fee";
+
+    ERR_LOCK_MODE_UNCHANGED = 2027,
+    LOCK_MODE_UNCHANGED_ERR_MSG = "Token is already in the requested lock mode";
+
+    // MultiTransferEsdt
+
+    ERR_NO_REMAINING_TRANSFERS = 3001,
+    NO_REMAINING_TRANSFERS_ERR_MSG = "No remaining transfers for batch";
+
+    ERR_TRANSFER_NOT_FAILED = 3002,
+    TRANSFER_NOT_FAILED_ERR_MSG = "Transfer did not fail or was already refunded";
+
+    ERR_TRANSFER_MISMATCH = 3003,
+    TRANSFER_MISMATCH_ERR_MSG = "Transfer does not match the failed one";
+
+    ERR_INVALID_WRAPPING_CONTRACT = 3004,
+    INVALID_WRAPPING_CONTRACT_ERR_MSG = "Invalid unwrapping contract address";
+
+    ERR_INVALID_EGLD_SWAP_CONTRACT = 3005,
+    INVALID_EGLD_SWAP_CONTRACT_ERR_MSG = "Invalid EGLD swap contract address";
+
+    ERR_INVALID_TOKEN_ID = 3006,
+    INVALID_TOKEN_ID_ERR_MSG = "Invalid token ID";
+
+    ERR_TRANSFER_NOT_QUARANTINED = 3007,
+    TRANSFER_NOT_QUARANTINED_ERR_MSG = "Transfer is not quarantined";
+
+    ERR_INVALID_REPLAY_PROTECTION_CONTRACT = 3008,
+    INVALID_REPLAY_PROTECTION_CONTRACT_ERR_MSG = "Invalid replay protection contract address";
+
+    ERR_TRANSFER_WHILE_PAUSED = 3009,
+    TRANSFER_WHILE_PAUSED_ERR_MSG = "Cannot execute transfers while paused";
+
+    ERR_TRANSFER_NOT_DEFERRED = 3010,
+    TRANSFER_NOT_DEFERRED_ERR_MSG = "Transfer is not deferred";
+
+    ERR_RESERVE_BUFFER_BREACHED = 3011,
+    RESERVE_BUFFER_BREACHED_ERR_MSG = "Not enough liquidity above the reserve buffer";
+
+    ERR_TOKEN_NOT_IN_UNLOCK_MODE = 3012,
+    TOKEN_NOT_IN_UNLOCK_MODE_ERR_MSG = "Token is not in unlock mode";
+
+    ERR_UNLOCK_MODE_UNCHANGED = 3013,
+    UNLOCK_MODE_UNCHANGED_ERR_MSG = "Token is already in the requested unlock mode";
+
+    // Shared modules
+
+    ERR_INVALID_ETH_PUBLIC_KEY = 4001,
+    INVALID_ETH_PUBLIC_KEY_ERR_MSG = "Invalid Ethereum public key";
+
+    ERR_EXECUTION_IN_PROGRESS = 4002,
+    EXECUTION_IN_PROGRESS_ERR_MSG = "Execution already in progress";
+
+    ERR_DEPOSIT_OVER_MAX_AMOUNT = 4003,
+    DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG = "Deposit over max amount";
+
+    ERR_STORAGE_DOWNGRADE = 4004,
+    STORAGE_DOWNGRADE_ERR_MSG = "Cannot downgrade storage version";
+
+    ERR_INVALID_NEW_OWNER = 4005,
+    INVALID_NEW_OWNER_ERR_MSG = "Invalid new owner";
+
+    ERR_NOT_PROPOSED_OWNER = 4006,
+    NOT_PROPOSED_OWNER_ERR_MSG = "Only the proposed owner may accept ownership";
+
+    ERR_NOT_OWNER_OR_ADMIN = 4007,
+    NOT_OWNER_OR_ADMIN_ERR_MSG = "Only owner or admin may call this function";
+
+    ERR_PAGE_SIZE_TOO_LARGE = 4008,
+    PAGE_SIZE_TOO_LARGE_ERR_MSG = "Page size cannot exceed 100 entries";
+
+    ERR_TOKEN_NOT_WHITELISTED = 4009,
+    TOKEN_NOT_WHITELISTED_ERR_MSG = "Token not in whitelist";
+
+    ERR_LOCAL_ROLE_NOT_SET = 4010,
+    LOCAL_ROLE_NOT_SET_ERR_MSG = "Must set local role first";
+
+    ERR_INVALID_PERCENTAGE_SUM = 4011,
+    INVALID_PERCENTAGE_SUM_ERR_MSG = "Percentages do not add up to 100%";
+
+    ERR_INVALID_MAX_TX_BATCH_SIZE = 4012,
+    INVALID_MAX_TX_BATCH_SIZE_ERR_MSG = "Max tx batch size must be more than 0";
+
+    ERR_INVALID_MAX_TX_BATCH_BLOCK_DURATION = 4013,
+    INVALID_MAX_TX_BATCH_BLOCK_DURATION_ERR_MSG = "Max tx batch block duration must be more than 0";
+
+    ERR_EMPTY_VEC = 4014,
+    EMPTY_VEC_ERR_MSG = "Empty vec";
+
+    ERR_SCREENING_CONTRACT_NOT_SC = 4015,
+    SCREENING_CONTRACT_NOT_SC_ERR_MSG = "Screening contract must be a smart contract";
+
+    ERR_BRIDGE_DOMAIN_ALREADY_SET = 4016,
+    BRIDGE_DOMAIN_ALREADY_SET_ERR_MSG = "Bridge domain already set";
+
+    ERR_BRIDGE_DOMAIN_NOT_SET = 4017,
+    BRIDGE_DOMAIN_NOT_SET_ERR_MSG = "Bridge domain not set";
+
+    ERR_CONFIG_KEY_ENCODING_FAILED = 4018,
+    CONFIG_KEY_ENCODING_FAILED_ERR_MSG = "Config key could not be encoded";
+
+    ERR_INVALID_MIN_BLOCKS_FOR_FINALITY = 4019,
+    INVALID_MIN_BLOCKS_FOR_FINALITY_ERR_MSG = "Min blocks for finality must be more than 0";
+
+    ERR_EVM_DOMAIN_ALREADY_SET = 4020,
+    EVM_DOMAIN_ALREADY_SET_ERR_MSG = "EVM domain already set";
+
+    ERR_EVM_DOMAIN_NOT_SET = 4021,
+    EVM_DOMAIN_NOT_SET_ERR_MSG = "EVM domain not set";
+
+    ERR_OWNERSHIP_NOT_ACCEPTED = 4022,
+    OWNERSHIP_NOT_ACCEPTED_ERR_MSG = "No ownership proposal was accepted";
+
+    // ReplayProtection
+
+    ERR_NOT_REGISTRAR = 5001,
+    NOT_REGISTRAR_ERR_MSG = "Only registrars may record executed transfers";
+
+    ERR_TRANSFER_ALREADY_EXECUTED = 5002,
+    TRANSFER_ALREADY_EXECUTED_ERR_MSG = "Transfer was already executed";
+}
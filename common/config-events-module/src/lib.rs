@@ -0,0 +1,56 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::CONFIG_KEY_ENCODING_FAILED_ERR_MSG;
+use multiversx_sc::storage::StorageKey;
+
+/// Action ID reported for changes that were not made by a multisig action.
+///
+/// The child contracts always report it, as they only see calls from their owner, the multisig.
+/// Their changes are matched to the multisig action through the multisig's own events
+/// in the same transaction.
+pub const NO_ACTION_ID: usize = 0;
+
+/// Emits the same `configChanged` event from every bridge contract
+/// whenever one of its governed parameters is modified.
+///
+/// The parameter key is the storage key of the parameter, i.e. the storage mapper name,
+/// followed by the nested-encoded mapper arguments, if any (see `config_key`).
+/// Old and new values are the raw storage values, so an empty value means the parameter was not set.
+#[multiversx_sc::module]
+pub trait ConfigEventsModule {
+    /// Expects the old value to have been read with `config_value` before the change.
+    fn emit_config_changed(
+        &self,
+        parameter_key: ManagedBuffer,
+        old_value: ManagedBuffer,
+        action_id: usize,
+    ) {
+        let new_value = self.config_value(&parameter_key);
+        self.config_changed_event(&parameter_key, &old_value, &new_value, action_id);
+    }
+
+    fn config_value(&self, parameter_key: &ManagedBuffer) -> ManagedBuffer {
+        SingleValueMapper::new(StorageKey::from(parameter_key.clone())).get()
+    }
+
+    /// Key of a parameter kept in a storage mapper that takes an argument, e.g. a token ID.
+    fn config_key<K: NestedEncode>(&self, name: &[u8], mapper_arg: &K) -> ManagedBuffer {
+        let mut key = ManagedBuffer::new_from_bytes(name);
+        if mapper_arg.dep_encode(&mut key).is_err() {
+            sc_panic!(CONFIG_KEY_ENCODING_FAILED_ERR_MSG);
+        }
+
+        key
+    }
+
+    #[event("configChanged")]
+    fn config_changed_event(
+        &self,
+        #[indexed] parameter_key: &ManagedBuffer,
+        #[indexed] old_value: &ManagedBuffer,
+        #[indexed] new_value: &ManagedBuffer,
+        #[indexed] action_id: usize,
+    );
+}
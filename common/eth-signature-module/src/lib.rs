@@ -0,0 +1,95 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::INVALID_ETH_PUBLIC_KEY_ERR_MSG;
+use core::convert::TryFrom;
+
+use eth_address::{EthAddress, ETH_ADDRESS_LEN};
+
+pub const ETH_SIGNATURE_LEN: usize = 65;
+pub const ETH_PUBLIC_KEY_LEN: usize = 65;
+pub const HASH_LEN: usize = 32;
+
+const UNCOMPRESSED_KEY_PREFIX: u8 = 0x04;
+const SIGNATURE_COMPONENT_LEN: usize = 32;
+static ETH_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+pub type Hash<M> = ManagedByteArray<M, HASH_LEN>;
+
+/// Verifies secp256k1 signatures produced by Ethereum keys (`eth_sign` / `personal_sign`).
+///
+/// Signatures are expected in the usual 65-byte `r || s || v` format,
+/// and public keys in the 65-byte uncompressed format (`0x04 || X || Y`).
+/// The signed message is always a 32-byte hash, prefixed as per EIP-191.
+#[multiversx_sc::module]
+pub trait EthSignatureModule {
+    fn verify_eth_signature(
+        &self,
+        public_key: &ManagedBuffer,
+        message_hash: &Hash<Self::Api>,
+        signature: &ManagedBuffer,
+    ) -> bool {
+        if !self.is_valid_eth_public_key(public_key) || signature.len() != ETH_SIGNATURE_LEN {
+            return false;
+        }
+
+        let opt_r = signature.copy_slice(0, SIGNATURE_COMPONENT_LEN);
+        let opt_s = signature.copy_slice(SIGNATURE_COMPONENT_LEN, SIGNATURE_COMPONENT_LEN);
+        let (r, s) = match (opt_r, opt_s) {
+            (Some(r), Some(s)) => (r, s),
+            _ => return false,
+        };
+
+        let der_signature = self.crypto().encode_secp256k1_der_signature(&r, &s);
+        let prefixed_message = self.eth_signed_message(message_hash);
+
+        self.crypto().verify_custom_secp256k1(
+            public_key,
+            &prefixed_message,
+            &der_signature,
+            MessageHashType::ECDSAKeccak256,
+        )
+    }
+
+    /// The Ethereum address is the last 20 bytes of the keccak256 hash
+    /// of the public key, without the uncompressed key prefix.
+    fn eth_address_from_public_key(&self, public_key: &ManagedBuffer) -> EthAddress<Self::Api> {
+        require!(
+            self.is_valid_eth_public_key(public_key),
+            INVALID_ETH_PUBLIC_KEY_ERR_MSG
+        );
+
+        let raw_key = public_key
+            .copy_slice(1, ETH_PUBLIC_KEY_LEN - 1)
+            .unwrap_or_else(|| sc_panic!(INVALID_ETH_PUBLIC_KEY_ERR_MSG));
+        let key_hash = self.crypto().keccak256(&raw_key);
+        let raw_addr = key_hash
+            .as_managed_buffer()
+            .copy_slice(HASH_LEN - ETH_ADDRESS_LEN, ETH_ADDRESS_LEN)
+            .unwrap_or_else(|| sc_panic!(INVALID_ETH_PUBLIC_KEY_ERR_MSG));
+
+        EthAddress {
+            raw_addr: ManagedByteArray::try_from(raw_addr)
+                .unwrap_or_else(|_| sc_panic!(INVALID_ETH_PUBLIC_KEY_ERR_MSG)),
+        }
+    }
+
+    fn is_valid_eth_public_key(&self, public_key: &ManagedBuffer) -> bool {
+        if public_key.len() != ETH_PUBLIC_KEY_LEN {
+            return false;
+        }
+
+        let mut prefix = [0u8; 1];
+        let _ = public_key.load_slice(0, &mut prefix);
+
+        prefix[0] == UNCOMPRESSED_KEY_PREFIX
+    }
+
+    fn eth_signed_message(&self, message_hash: &Hash<Self::Api>) -> ManagedBuffer {
+        let mut prefixed_message = ManagedBuffer::new_from_bytes(ETH_SIGNED_MESSAGE_PREFIX);
+        prefixed_message.append(message_hash.as_managed_buffer());
+
+        prefixed_message
+    }
+}
@@ -0,0 +1,77 @@
+use eth_signature_module::{EthSignatureModule, Hash};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::DebugApi;
+use std::convert::TryFrom;
+
+mod eth_signature_test_sc {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait EthSignatureTestSc: eth_signature_module::EthSignatureModule {
+        #[init]
+        fn init(&self) {}
+    }
+}
+
+// key pair of the private key 1, and a signature with it over `MESSAGE_HASH`, as made by `personal_sign`
+const PUBLIC_KEY: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+const ETH_ADDRESS: &str = "7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+const MESSAGE_HASH: &str = "fe8e198606eb2537cc72b64dcd2c918a4edccdb6f553f868424474efa16c2758";
+const SIGNATURE: &str = "79ea76552da0ead69946ea6132c9505d317c231e7239b8403f03dd38d1e4d3ed2469875e0b0639fa5c598bbc966fa9f35a346295fd555e6e608984a7cef7a01a1b";
+
+fn buffer(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+fn message_hash() -> Hash<DebugApi> {
+    Hash::try_from(buffer(MESSAGE_HASH)).unwrap()
+}
+
+#[test]
+fn eth_address_from_public_key_test() {
+    let _ = DebugApi::dummy();
+    let sc = eth_signature_test_sc::contract_obj::<DebugApi>();
+
+    let eth_address = sc.eth_address_from_public_key(&buffer(PUBLIC_KEY));
+    assert!(eth_address.as_managed_buffer() == &buffer(ETH_ADDRESS));
+}
+
+#[test]
+fn eth_signed_message_test() {
+    let _ = DebugApi::dummy();
+    let sc = eth_signature_test_sc::contract_obj::<DebugApi>();
+
+    let mut expected = ManagedBuffer::new_from_bytes(b"\x19Ethereum Signed Message:\n32");
+    expected.append(&buffer(MESSAGE_HASH));
+    assert!(sc.eth_signed_message(&message_hash()) == expected);
+}
+
+#[test]
+fn invalid_public_key_test() {
+    let _ = DebugApi::dummy();
+    let sc = eth_signature_test_sc::contract_obj::<DebugApi>();
+
+    assert!(sc.is_valid_eth_public_key(&buffer(PUBLIC_KEY)));
+
+    let compressed_key =
+        buffer("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+    assert!(!sc.is_valid_eth_public_key(&compressed_key));
+
+    let mut wrong_prefix_key = buffer("05");
+    wrong_prefix_key.append(&buffer(&PUBLIC_KEY[2..]));
+    assert!(!sc.is_valid_eth_public_key(&wrong_prefix_key));
+    assert!(!sc.verify_eth_signature(&wrong_prefix_key, &message_hash(), &buffer(SIGNATURE)));
+}
+
+#[test]
+fn invalid_signature_length_test() {
+    let _ = DebugApi::dummy();
+    let sc = eth_signature_test_sc::contract_obj::<DebugApi>();
+
+    let without_recovery_id = buffer(&SIGNATURE[..128]);
+    assert!(!sc.verify_eth_signature(&buffer(PUBLIC_KEY), &message_hash(), &without_recovery_id));
+
+    let mut too_long = buffer(SIGNATURE);
+    too_long.append(&buffer("00"));
+    assert!(!sc.verify_eth_signature(&buffer(PUBLIC_KEY), &message_hash(), &too_long));
+}
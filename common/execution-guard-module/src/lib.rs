@@ -0,0 +1,31 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::EXECUTION_IN_PROGRESS_ERR_MSG;
+
+/// Marks that an execution is in progress, so a nested or re-entrant call
+/// into the same execution path fails instead of working on half-updated state.
+///
+/// The flag must only be set for the duration of a single transaction.
+/// Contracts making asynchronous calls end the execution once the call is registered,
+/// and track the pending call separately (e.g. per action, in the Multisig),
+/// so a callback that never arrives does not block all the other executions.
+#[multiversx_sc::module]
+pub trait ExecutionGuardModule {
+    fn start_execution(&self) {
+        require!(
+            !self.execution_in_progress().get(),
+            EXECUTION_IN_PROGRESS_ERR_MSG
+        );
+
+        self.execution_in_progress().set(true);
+    }
+
+    fn end_execution(&self) {
+        self.execution_in_progress().clear();
+    }
+
+    #[storage_mapper("executionInProgress")]
+    fn execution_in_progress(&self) -> SingleValueMapper<bool>;
+}
@@ -2,21 +2,29 @@
 
 multiversx_sc::imports!();
 
+use config_events_module::NO_ACTION_ID;
+
 mod aggregator_proxy;
 pub use aggregator_proxy::*;
 
 #[multiversx_sc::module]
-pub trait FeeEstimatorModule {
+pub trait FeeEstimatorModule: config_events_module::ConfigEventsModule {
     #[only_owner]
     #[endpoint(setFeeEstimatorContractAddress)]
     fn set_fee_estimator_contract_address(&self, new_address: ManagedAddress) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"feeEstimatorContractAddress");
+        let old_value = self.config_value(&parameter_key);
         self.fee_estimator_contract_address().set(&new_address);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     #[only_owner]
     #[endpoint(setEthTxGasLimit)]
     fn set_eth_tx_gas_limit(&self, new_limit: BigUint) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"ethTxGasLimit");
+        let old_value = self.config_value(&parameter_key);
         self.eth_tx_gas_limit().set(&new_limit);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     /// Default price being used if the aggregator lacks a mapping for this token
@@ -28,15 +36,21 @@ pub trait FeeEstimatorModule {
         token_id: TokenIdentifier,
         default_price_per_gas_unit: BigUint,
     ) {
+        let parameter_key = self.config_key(b"defaultPricePerGasUnit", &token_id);
+        let old_value = self.config_value(&parameter_key);
         self.default_price_per_gas_unit(&token_id)
             .set(&default_price_per_gas_unit);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     /// Token ticker being used when querying the aggregator for GWEI prices
     #[only_owner]
     #[endpoint(setTokenTicker)]
     fn set_token_ticker(&self, token_id: TokenIdentifier, ticker: ManagedBuffer) {
+        let parameter_key = self.config_key(b"tokenTicker", &token_id);
+        let old_value = self.config_value(&parameter_key);
         self.token_ticker(&token_id).set(&ticker);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     /// Returns the fee for the given token ID (the fee amount is in the given token)
@@ -51,7 +65,14 @@ pub trait FeeEstimatorModule {
     fn get_price_per_gas_unit(&self, token_id: &TokenIdentifier) -> BigUint {
         let opt_price = self.get_aggregator_mapping(&TokenIdentifier::from(GWEI_STRING), token_id);
 
-        opt_price.unwrap_or_else(|| self.default_price_per_gas_unit(token_id).get())
+        opt_price.unwrap_or_else(|| {
+            let default_price_mapper = self.default_price_per_gas_unit(token_id);
+            if default_price_mapper.is_empty() {
+                self.fallback_price_per_gas_unit().get()
+            } else {
+                default_price_mapper.get()
+            }
+        })
     }
 
     fn get_aggregator_mapping(
@@ -59,22 +80,29 @@ pub trait FeeEstimatorModule {
         from: &TokenIdentifier,
         to: &TokenIdentifier,
     ) -> Option<BigUint> {
+        let from_ticker = self.token_ticker(from).get();
+        let to_ticker = self.token_ticker(to).get();
+
+        self.get_aggregator_price(from_ticker, to_ticker)
+            .map(|result| result.price)
+    }
+
+    fn get_aggregator_price(
+        &self,
+        from_ticker: ManagedBuffer,
+        to_ticker: ManagedBuffer,
+    ) -> Option<AggregatorResult<Self::Api>> {
         let fee_estimator_sc_address = self.fee_estimator_contract_address().get();
         if fee_estimator_sc_address.is_zero() {
             return None;
         }
 
-        let from_ticker = self.token_ticker(from).get();
-        let to_ticker = self.token_ticker(to).get();
-
         let result: OptionalValue<AggregatorResultAsMultiValue<Self::Api>> = self
             .aggregator_proxy(fee_estimator_sc_address)
             .latest_price_feed_optional(from_ticker, to_ticker)
             .execute_on_dest_context();
 
-        result
-            .into_option()
-            .map(|multi_result| AggregatorResult::from(multi_result).price)
+        result.into_option().map(AggregatorResult::from)
     }
 
     // proxies
@@ -92,6 +120,11 @@ pub trait FeeEstimatorModule {
     #[storage_mapper("defaultPricePerGasUnit")]
     fn default_price_per_gas_unit(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
+    /// Used for tokens without a default price of their own. Only set on deployment.
+    #[view(getFallbackPricePerGasUnit)]
+    #[storage_mapper("fallbackPricePerGasUnit")]
+    fn fallback_price_per_gas_unit(&self) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("tokenTicker")]
     fn token_ticker(&self, token_id: &TokenIdentifier) -> SingleValueMapper<ManagedBuffer>;
 
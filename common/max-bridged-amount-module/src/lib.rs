@@ -2,12 +2,18 @@
 
 multiversx_sc::imports!();
 
+use bridge_errors::DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG;
+use config_events_module::NO_ACTION_ID;
+
 #[multiversx_sc::module]
-pub trait MaxBridgedAmountModule {
+pub trait MaxBridgedAmountModule: config_events_module::ConfigEventsModule {
     #[only_owner]
     #[endpoint(setMaxBridgedAmount)]
     fn set_max_bridged_amount(&self, token_id: TokenIdentifier, max_amount: BigUint) {
+        let parameter_key = self.config_key(b"maxBridgedAmount", &token_id);
+        let old_value = self.config_value(&parameter_key);
         self.max_bridged_amount(&token_id).set(&max_amount);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     fn is_above_max_amount(&self, token_id: &TokenIdentifier, amount: &BigUint) -> bool {
@@ -22,7 +28,7 @@ pub trait MaxBridgedAmountModule {
     fn require_below_max_amount(&self, token_id: &TokenIdentifier, amount: &BigUint) {
         require!(
             !self.is_above_max_amount(token_id, amount),
-            "Deposit over max amount"
+            DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG
         );
     }
 
@@ -0,0 +1,43 @@
+multiversx_sc::derive_imports!();
+
+/// Semantic version of a contract's interface: the major version changes on breaking changes,
+/// the minor one when endpoints or fields are added, and the patch one for fixes.
+pub type InterfaceVersion = (u32, u32, u32);
+
+/// Outgoing transactions can go to a chain other than Ethereum, with a raw destination address.
+pub const TRANSFER_FIELD_DESTINATION_CHAIN: u64 = 1 << 0;
+/// Outgoing transactions can set a tip aside for the relayer that sets their status.
+pub const TRANSFER_FIELD_RELAYER_TIP: u64 = 1 << 1;
+/// Incoming WEGLD transfers can be unwrapped to EGLD, given their tx nonces along with the batch.
+pub const TRANSFER_FIELD_UNWRAP_EGLD: u64 = 1 << 2;
+/// Transfers carry an NFT nonce and attributes.
+pub const TRANSFER_FIELD_NFT_ATTRIBUTES: u64 = 1 << 3;
+
+/// Returned by the `getBridgeVersion` view of each bridge contract.
+///
+/// Contracts are upgraded one at a time, so relayers and the remote chain's contracts
+/// should check what each of them supports, instead of assuming they all run the same release.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct BridgeVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// Bit `i` is set if the multisig action with discriminant `i` can be proposed.
+    /// 0 for the other contracts.
+    pub supported_actions: u64,
+    /// The `TRANSFER_FIELD_*` flags of the transfer fields the contract handles.
+    pub transfer_fields: u64,
+}
+
+impl BridgeVersion {
+    pub fn new(version: InterfaceVersion, supported_actions: u64, transfer_fields: u64) -> Self {
+        let (major, minor, patch) = version;
+        BridgeVersion {
+            major,
+            minor,
+            patch,
+            supported_actions,
+            transfer_fields,
+        }
+    }
+}
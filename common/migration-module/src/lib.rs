@@ -0,0 +1,40 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod bridge_version;
+
+use bridge_errors::STORAGE_DOWNGRADE_ERR_MSG;
+
+pub type StorageVersion = u32;
+
+/// Keeps track of the storage layout version of a contract.
+///
+/// Contracts call `migrate_storage` from their init function, which also runs on upgrade,
+/// passing the storage version of the new code and one migration step per version.
+/// Steps are run in order, for every version above the stored one.
+///
+/// Contracts deployed before versioning was introduced have no stored version,
+/// which is considered to be version 0. The same goes for fresh deploys,
+/// so migration steps must also work on empty storage.
+#[multiversx_sc::module]
+pub trait MigrationModule {
+    fn migrate_storage<F: Fn(StorageVersion)>(
+        &self,
+        latest_version: StorageVersion,
+        migration_step: F,
+    ) {
+        let current_version = self.storage_version().get();
+        require!(current_version <= latest_version, STORAGE_DOWNGRADE_ERR_MSG);
+
+        for version in (current_version + 1)..=latest_version {
+            migration_step(version);
+        }
+
+        self.storage_version().set(latest_version);
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<StorageVersion>;
+}
@@ -0,0 +1,95 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    INVALID_NEW_OWNER_ERR_MSG, NOT_OWNER_OR_ADMIN_ERR_MSG, NOT_PROPOSED_OWNER_ERR_MSG,
+};
+
+/// Ownership is transferred in two steps: the current owner proposes a new owner,
+/// who then has to accept it. A typo'd address can never accept,
+/// so the proposal can simply be replaced.
+///
+/// A contract cannot change its own owner, so accepting only records the new owner.
+/// The current owner then completes the transfer by calling the `ChangeOwnerAddress`
+/// built-in function on the contract, with the address from `getAcceptedOwner`.
+/// For the contracts owned by the Multisig, this is done through its `changeChildContractOwner`.
+///
+/// The admin is an operational role, set by the owner,
+/// which may call some of the configuration endpoints without being the owner.
+#[multiversx_sc::module]
+pub trait OwnershipModule {
+    #[only_owner]
+    #[endpoint(proposeOwnership)]
+    fn propose_ownership(&self, new_owner: ManagedAddress) {
+        require!(!new_owner.is_zero(), INVALID_NEW_OWNER_ERR_MSG);
+
+        self.pending_owner().set(&new_owner);
+        self.accepted_owner().clear();
+    }
+
+    #[only_owner]
+    #[endpoint(cancelOwnershipProposal)]
+    fn cancel_ownership_proposal(&self) {
+        self.pending_owner().clear();
+        self.accepted_owner().clear();
+    }
+
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending_owner_mapper = self.pending_owner();
+        require!(
+            !pending_owner_mapper.is_empty() && pending_owner_mapper.get() == caller,
+            NOT_PROPOSED_OWNER_ERR_MSG
+        );
+
+        pending_owner_mapper.clear();
+        self.accepted_owner().set(&caller);
+    }
+
+    /// The proposed owner who accepted, which the current owner can now transfer ownership to.
+    #[view(getAcceptedOwner)]
+    fn get_accepted_owner(&self) -> OptionalValue<ManagedAddress> {
+        let accepted_owner_mapper = self.accepted_owner();
+        let current_owner = self.blockchain().get_owner_address();
+        if accepted_owner_mapper.is_empty() || accepted_owner_mapper.get() == current_owner {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(accepted_owner_mapper.get())
+    }
+
+    #[only_owner]
+    #[endpoint(setAdmin)]
+    fn set_admin(&self, opt_admin: OptionalValue<ManagedAddress>) {
+        match opt_admin {
+            OptionalValue::Some(admin) => self.admin().set(&admin),
+            OptionalValue::None => self.admin().clear(),
+        }
+    }
+
+    fn require_caller_owner_or_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_mapper = self.admin();
+        require!(
+            !admin_mapper.is_empty() && admin_mapper.get() == caller,
+            NOT_OWNER_OR_ADMIN_ERR_MSG
+        );
+    }
+
+    #[view(getPendingOwner)]
+    #[storage_mapper("pendingOwner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("acceptedOwner")]
+    fn accepted_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAdmin)]
+    #[storage_mapper("admin")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
+}
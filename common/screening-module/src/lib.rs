@@ -0,0 +1,85 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::SCREENING_CONTRACT_NOT_SC_ERR_MSG;
+use config_events_module::NO_ACTION_ID;
+use eth_address::EthAddress;
+
+pub mod screening_hook;
+
+/// Lets an external contract accept or reject transfers, see `screening_hook::ScreeningHook`.
+/// Without a screening contract, all transfers are allowed.
+#[multiversx_sc::module]
+pub trait ScreeningModule: config_events_module::ConfigEventsModule {
+    /// Sets the screening contract, or removes it if no address is given.
+    #[only_owner]
+    #[endpoint(setScreeningContract)]
+    fn set_screening_contract(&self, opt_screening_contract: OptionalValue<ManagedAddress>) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"screeningContract");
+        let old_value = self.config_value(&parameter_key);
+        match opt_screening_contract {
+            OptionalValue::Some(screening_contract) => {
+                require!(
+                    self.blockchain().is_smart_contract(&screening_contract),
+                    SCREENING_CONTRACT_NOT_SC_ERR_MSG
+                );
+
+                self.screening_contract().set(&screening_contract);
+            }
+            OptionalValue::None => self.screening_contract().clear(),
+        }
+
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[view(getScreeningContract)]
+    fn get_screening_contract(&self) -> OptionalValue<ManagedAddress> {
+        let screening_contract_mapper = self.screening_contract();
+        if screening_contract_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(screening_contract_mapper.get())
+    }
+
+    fn passes_deposit_screening(
+        &self,
+        sender: &ManagedAddress,
+        destination: &ManagedBuffer,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+    ) -> bool {
+        let screening_contract_mapper = self.screening_contract();
+        if screening_contract_mapper.is_empty() {
+            return true;
+        }
+
+        self.screening_hook_proxy(screening_contract_mapper.get())
+            .is_deposit_allowed(sender, destination, token_id, amount)
+            .execute_on_dest_context()
+    }
+
+    fn passes_transfer_screening(
+        &self,
+        origin: &EthAddress<Self::Api>,
+        receiver: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+    ) -> bool {
+        let screening_contract_mapper = self.screening_contract();
+        if screening_contract_mapper.is_empty() {
+            return true;
+        }
+
+        self.screening_hook_proxy(screening_contract_mapper.get())
+            .is_transfer_allowed(origin, receiver, token_id, amount)
+            .execute_on_dest_context()
+    }
+
+    #[proxy]
+    fn screening_hook_proxy(&self, sc_address: ManagedAddress) -> screening_hook::Proxy<Self::Api>;
+
+    #[storage_mapper("screeningContract")]
+    fn screening_contract(&self) -> SingleValueMapper<ManagedAddress>;
+}
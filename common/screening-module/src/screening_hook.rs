@@ -0,0 +1,29 @@
+multiversx_sc::imports!();
+
+use eth_address::EthAddress;
+
+/// The interface a screening contract must implement.
+/// Both views return `true` if the transfer may go through.
+#[multiversx_sc::proxy]
+pub trait ScreeningHook {
+    /// Checked by EsdtSafe before accepting a deposit.
+    /// The destination is the address on the other chain, as given by the sender.
+    #[view(isDepositAllowed)]
+    fn is_deposit_allowed(
+        &self,
+        sender: ManagedAddress,
+        destination: ManagedBuffer,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+    ) -> bool;
+
+    /// Checked by MultiTransferEsdt before delivering an incoming transfer.
+    #[view(isTransferAllowed)]
+    fn is_transfer_allowed(
+        &self,
+        origin: EthAddress<Self::Api>,
+        receiver: ManagedAddress,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+    ) -> bool;
+}
@@ -0,0 +1,43 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::PAGE_SIZE_TOO_LARGE_ERR_MSG;
+use multiversx_sc::storage::StorageKey;
+
+/// Maximum number of entries returned by a single `getRawStorage` call.
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Debug views for incident response, only included in the devnet builds (label "devnet").
+///
+/// Contracts can't list their storage keys, so the inspection works on indexed entries,
+/// i.e. keys made of a prefix followed by a u32 index, like the items of a `VecMapper`
+/// (e.g. "action_data.item") or the user IDs of a `UserMapper`.
+#[multiversx_sc::module]
+pub trait StorageInspectionModule {
+    /// Returns the raw (key, value) pairs for the keys `prefix | u32:index`,
+    /// for `count` indexes starting with `from_index`. Empty entries are skipped.
+    #[label("devnet")]
+    #[view(getRawStorage)]
+    fn get_raw_storage(
+        &self,
+        prefix: ManagedBuffer,
+        from_index: u32,
+        count: u32,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        require!(count as usize <= MAX_PAGE_SIZE, PAGE_SIZE_TOO_LARGE_ERR_MSG);
+
+        let mut result = MultiValueEncoded::new();
+        for index in from_index..from_index.saturating_add(count) {
+            let mut key = prefix.clone();
+            key.append_bytes(&index.to_be_bytes());
+
+            let value: ManagedBuffer = SingleValueMapper::new(StorageKey::from(key.clone())).get();
+            if !value.is_empty() {
+                result.push((key, value).into());
+            }
+        }
+
+        result
+    }
+}
@@ -3,8 +3,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use bridge_errors::{
+    INVALID_PERCENTAGE_SUM_ERR_MSG, LOCAL_ROLE_NOT_SET_ERR_MSG, TOKEN_NOT_WHITELISTED_ERR_MSG,
+};
+
 pub const PERCENTAGE_TOTAL: u32 = 10_000; // precision of 2 decimals
-pub static INVALID_PERCENTAGE_SUM_OVER_ERR_MSG: &[u8] = b"Percentages do not add up to 100%";
 
 #[derive(NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
 pub struct AddressPercentagePair<M: ManagedTypeApi> {
@@ -12,8 +15,18 @@ pub struct AddressPercentagePair<M: ManagedTypeApi> {
     pub percentage: u32,
 }
 
+/// Display information about a bridged token, as it is known on the remote chain.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct TokenMetadata<M: ManagedTypeApi> {
+    pub remote_ticker: ManagedBuffer<M>,
+    pub remote_decimals: u8,
+    pub logo_uri_hash: ManagedByteArray<M, 32>,
+}
+
 #[multiversx_sc::module]
-pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
+pub trait TokenModule:
+    fee_estimator_module::FeeEstimatorModule + config_events_module::ConfigEventsModule
+{
     // endpoints - owner-only
 
     /// Distributes the accumulated fees to the given addresses.
@@ -33,7 +46,7 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
         }
         require!(
             percentage_sum == PERCENTAGE_TOTAL as u64,
-            INVALID_PERCENTAGE_SUM_OVER_ERR_MSG
+            INVALID_PERCENTAGE_SUM_ERR_MSG
         );
 
         for token_id in self.token_whitelist().iter() {
@@ -84,23 +97,64 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
     fn remove_token_from_whitelist(&self, token_id: TokenIdentifier) {
         self.token_ticker(&token_id).clear();
         self.default_price_per_gas_unit(&token_id).clear();
+        self.token_metadata(&token_id).clear();
 
         let _ = self.token_whitelist().swap_remove(&token_id);
     }
 
+    /// Sets the display metadata for a whitelisted token,
+    /// i.e. its ticker and number of decimals on the remote chain, and the hash of its logo URI.
+    #[only_owner]
+    #[endpoint(setTokenMetadata)]
+    fn set_token_metadata(
+        &self,
+        token_id: TokenIdentifier,
+        remote_ticker: ManagedBuffer,
+        remote_decimals: u8,
+        logo_uri_hash: ManagedByteArray<32>,
+    ) {
+        self.require_token_in_whitelist(&token_id);
+
+        self.token_metadata(&token_id).set(TokenMetadata {
+            remote_ticker,
+            remote_decimals,
+            logo_uri_hash,
+        });
+    }
+
+    // views
+
+    /// Lists the whitelisted tokens that have display metadata set,
+    /// as pairs of (token ID, metadata).
+    #[view(getAllKnownTokensWithMetadata)]
+    #[label("queries")]
+    fn get_all_known_tokens_with_metadata(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<TokenIdentifier, TokenMetadata<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.token_whitelist().iter() {
+            let metadata_mapper = self.token_metadata(&token_id);
+            if !metadata_mapper.is_empty() {
+                result.push((token_id, metadata_mapper.get()).into());
+            }
+        }
+
+        result
+    }
+
     // private
 
     fn require_token_in_whitelist(&self, token_id: &TokenIdentifier) {
         require!(
             self.token_whitelist().contains(token_id),
-            "Token not in whitelist"
+            TOKEN_NOT_WHITELISTED_ERR_MSG
         );
     }
 
     fn require_local_role_set(&self, token_id: &TokenIdentifier, role: &EsdtLocalRole) {
         require!(
             self.is_local_role_set(token_id, role),
-            "Must set local role first"
+            LOCAL_ROLE_NOT_SET_ERR_MSG
         );
     }
 
@@ -116,6 +170,13 @@ pub trait TokenModule: fee_estimator_module::FeeEstimatorModule {
     #[storage_mapper("tokenWhitelist")]
     fn token_whitelist(&self) -> UnorderedSetMapper<TokenIdentifier>;
 
+    #[view(getTokenMetadata)]
+    #[storage_mapper("tokenMetadata")]
+    fn token_metadata(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<TokenMetadata<Self::Api>>;
+
     #[view(getAccumulatedTransactionFees)]
     #[storage_mapper("accumulatedTransactionFees")]
     fn accumulated_transaction_fees(
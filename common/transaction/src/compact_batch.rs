@@ -0,0 +1,117 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::Transaction;
+
+/// A transaction batch encoded as a single buffer, as returned by the `*Compact` batch views.
+/// Token IDs are listed once per batch, and each transaction refers to its token by index.
+///
+/// Layout (all integers big-endian):
+///
+/// | field             | encoding                           |
+/// |-------------------|------------------------------------|
+/// | batch ID          | u64                                |
+/// | token ID count    | u32                                |
+/// | token IDs         | each as u32 length + bytes         |
+/// | transaction count | u32                                |
+/// | transactions      | each as described below            |
+///
+/// Transaction:
+///
+/// | field             | encoding                           |
+/// |-------------------|------------------------------------|
+/// | block nonce       | u64                                |
+/// | tx nonce          | u64                                |
+/// | sender            | u32 length + bytes                 |
+/// | receiver          | u32 length + bytes                 |
+/// | token index       | u32, position in the token ID list |
+/// | amount            | u32 length + bytes                 |
+///
+/// As with the split fields, `is_refund_tx` is not part of the encoding.
+pub type CompactTxBatch<M> = ManagedBuffer<M>;
+
+#[derive(TopEncode, TopDecode)]
+struct CompactBatchFields<M: ManagedTypeApi> {
+    batch_id: u64,
+    token_ids: ManagedVec<M, TokenIdentifier<M>>,
+    transactions: ManagedVec<M, CompactTransaction<M>>,
+}
+
+#[derive(NestedEncode, NestedDecode, ManagedVecItem)]
+struct CompactTransaction<M: ManagedTypeApi> {
+    block_nonce: u64,
+    nonce: u64,
+    from: ManagedBuffer<M>,
+    to: ManagedBuffer<M>,
+    token_index: u32,
+    amount: BigUint<M>,
+}
+
+pub fn encode_compact_batch<M, I>(batch_id: u64, transactions: I) -> CompactTxBatch<M>
+where
+    M: ManagedTypeApi,
+    I: IntoIterator<Item = Transaction<M>>,
+{
+    let mut fields = CompactBatchFields {
+        batch_id,
+        token_ids: ManagedVec::new(),
+        transactions: ManagedVec::new(),
+    };
+    for tx in transactions {
+        let token_index = match fields
+            .token_ids
+            .iter()
+            .position(|token_id| *token_id == tx.token_identifier)
+        {
+            Some(index) => index,
+            None => {
+                fields.token_ids.push(tx.token_identifier);
+                fields.token_ids.len() - 1
+            }
+        };
+
+        fields.transactions.push(CompactTransaction {
+            block_nonce: tx.block_nonce,
+            nonce: tx.nonce,
+            from: tx.from,
+            to: tx.to,
+            token_index: token_index as u32,
+            amount: tx.amount,
+        });
+    }
+
+    let mut encoded = ManagedBuffer::new();
+    if fields.top_encode(&mut encoded).is_err() {
+        M::error_api_impl().signal_error(b"Could not encode compact transaction batch");
+    }
+
+    encoded
+}
+
+/// The reverse of `encode_compact_batch`.
+pub fn decode_compact_batch<M: ManagedTypeApi>(
+    encoded: &CompactTxBatch<M>,
+) -> (u64, ManagedVec<M, Transaction<M>>) {
+    let fields = CompactBatchFields::<M>::top_decode(encoded.clone())
+        .unwrap_or_else(|_| M::error_api_impl().signal_error(b"Invalid compact transaction batch"));
+
+    let mut transactions = ManagedVec::new();
+    for tx in fields.transactions.iter() {
+        let token_identifier = match fields.token_ids.try_get(tx.token_index as usize) {
+            Some(token_id) => (*token_id).clone(),
+            None => M::error_api_impl().signal_error(b"Invalid token index in compact batch"),
+        };
+
+        transactions.push(Transaction {
+            block_nonce: tx.block_nonce,
+            nonce: tx.nonce,
+            from: tx.from,
+            to: tx.to,
+            token_identifier,
+            amount: tx.amount,
+            is_refund_tx: false,
+        });
+    }
+
+    (fields.batch_id, transactions)
+}
@@ -0,0 +1,253 @@
+use multiversx_sc::{
+    api::{CryptoApi, ErrorApiImpl, ManagedTypeApi},
+    contract_base::CryptoWrapper,
+    types::{BigUint, ManagedAddress, ManagedBuffer, ManagedByteArray, ManagedVec},
+};
+
+multiversx_sc::derive_imports!();
+
+use eth_address::EthAddress;
+
+use crate::Transaction;
+
+pub const EIP712_HASH_LEN: usize = 32;
+const ABI_WORD_LEN: usize = 32;
+
+static EIP712_PREFIX: &[u8] = b"\x19\x01";
+static DOMAIN_TYPE: &[u8] =
+    b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+static TRANSACTION_TYPE: &[u8] = b"Transaction(uint64 blockNonce,uint64 nonce,bytes from,bytes to,string tokenIdentifier,uint256 amount)";
+static BATCH_TYPE: &[u8] = b"Batch(bytes32 bridgeDomain,uint64 batchId,Transaction[] transactions)Transaction(uint64 blockNonce,uint64 nonce,bytes from,bytes to,string tokenIdentifier,uint256 amount)";
+static BRIDGE_DOMAIN_TYPE: &[u8] = b"BridgeDomain(bytes chainId,uint64 bridgeInstanceId)";
+static KEY_REGISTRATION_TAG: &[u8] = b"registerEthPublicKey";
+
+pub type Eip712Hash<M> = ManagedByteArray<M, EIP712_HASH_LEN>;
+
+/// The EIP-712 domain of the Ethereum-side bridge contract.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Eip712Domain<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub version: ManagedBuffer<M>,
+    pub chain_id: BigUint<M>,
+    pub verifying_contract: EthAddress<M>,
+}
+
+impl<M: CryptoApi> Eip712Domain<M> {
+    pub fn separator(&self) -> Eip712Hash<M> {
+        let mut encoded = ManagedBuffer::new();
+        encoded.append(
+            keccak256::<M>(&ManagedBuffer::new_from_bytes(DOMAIN_TYPE)).as_managed_buffer(),
+        );
+        encoded.append(keccak256(&self.name).as_managed_buffer());
+        encoded.append(keccak256(&self.version).as_managed_buffer());
+        encoded.append(&encode_uint(&self.chain_id));
+        encoded.append(&encode_address(&self.verifying_contract));
+
+        keccak256(&encoded)
+    }
+}
+
+/// Identifies a bridge deployment: the MultiversX chain it runs on (e.g. "1" for mainnet, "D" for devnet),
+/// and the bridge instance on that chain.
+///
+/// Its separator is mixed into every batch hash and attestation digest,
+/// so signatures made for one deployment, like a testnet or a fork, are never valid for another.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq)]
+pub struct BridgeDomain<M: ManagedTypeApi> {
+    pub chain_id: ManagedBuffer<M>,
+    pub bridge_instance_id: u64,
+}
+
+impl<M: CryptoApi> BridgeDomain<M> {
+    /// `hashStruct(BridgeDomain(bytes chainId,uint64 bridgeInstanceId))`
+    pub fn separator(&self) -> Eip712Hash<M> {
+        let mut encoded = ManagedBuffer::new();
+        encoded.append(
+            keccak256::<M>(&ManagedBuffer::new_from_bytes(BRIDGE_DOMAIN_TYPE)).as_managed_buffer(),
+        );
+        encoded.append(keccak256(&self.chain_id).as_managed_buffer());
+        encoded.append(&encode_u64(self.bridge_instance_id));
+
+        keccak256(&encoded)
+    }
+}
+
+/// `hashStruct` of a single transaction, as per EIP-712:
+///
+/// `Transaction(uint64 blockNonce,uint64 nonce,bytes from,bytes to,string tokenIdentifier,uint256 amount)`
+pub fn hash_transaction<M: CryptoApi>(tx: &Transaction<M>) -> Eip712Hash<M> {
+    let mut encoded = ManagedBuffer::new();
+    encoded.append(
+        keccak256::<M>(&ManagedBuffer::new_from_bytes(TRANSACTION_TYPE)).as_managed_buffer(),
+    );
+    encoded.append(&encode_u64(tx.block_nonce));
+    encoded.append(&encode_u64(tx.nonce));
+    encoded.append(keccak256(&tx.from).as_managed_buffer());
+    encoded.append(keccak256(&tx.to).as_managed_buffer());
+    encoded.append(keccak256(tx.token_identifier.as_managed_buffer()).as_managed_buffer());
+    encoded.append(&encode_uint(&tx.amount));
+
+    keccak256(&encoded)
+}
+
+/// `hashStruct` of a whole batch. The transactions array is encoded
+/// as the hash of the concatenated transaction struct hashes,
+/// and the bridge domain as its separator.
+pub fn hash_batch<M: CryptoApi>(
+    bridge_domain: &BridgeDomain<M>,
+    batch_id: u64,
+    transactions: &ManagedVec<M, Transaction<M>>,
+) -> Eip712Hash<M> {
+    let mut tx_hashes = ManagedBuffer::new();
+    for tx in transactions {
+        tx_hashes.append(hash_transaction(&tx).as_managed_buffer());
+    }
+
+    let mut encoded = ManagedBuffer::new();
+    encoded.append(keccak256::<M>(&ManagedBuffer::new_from_bytes(BATCH_TYPE)).as_managed_buffer());
+    encoded.append(bridge_domain.separator().as_managed_buffer());
+    encoded.append(&encode_u64(batch_id));
+    encoded.append(keccak256(&tx_hashes).as_managed_buffer());
+
+    keccak256(&encoded)
+}
+
+/// The final digest signed by relayers on the EVM side:
+///
+/// `keccak256("\x19\x01" || domainSeparator || hashStruct(batch))`
+pub fn batch_digest<M: CryptoApi>(
+    domain: &Eip712Domain<M>,
+    bridge_domain: &BridgeDomain<M>,
+    batch_id: u64,
+    transactions: &ManagedVec<M, Transaction<M>>,
+) -> Eip712Hash<M> {
+    typed_data_digest(
+        &domain.separator(),
+        &hash_batch(bridge_domain, batch_id, transactions),
+    )
+}
+
+/// A batch together with the digest relayers sign for the EVM side, and the parts it is made of:
+/// `digest = keccak256("\x19\x01" || domain_separator || batch_root)`,
+/// where `batch_root` is the batch's `hashStruct`.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct BatchAttestation<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub batch_root: Eip712Hash<M>,
+    pub domain_separator: Eip712Hash<M>,
+    pub digest: Eip712Hash<M>,
+}
+
+pub fn attest_batch<M: CryptoApi>(
+    domain: &Eip712Domain<M>,
+    bridge_domain: &BridgeDomain<M>,
+    batch_id: u64,
+    transactions: &ManagedVec<M, Transaction<M>>,
+) -> BatchAttestation<M> {
+    let batch_root = hash_batch(bridge_domain, batch_id, transactions);
+    let domain_separator = domain.separator();
+    let digest = typed_data_digest(&domain_separator, &batch_root);
+
+    BatchAttestation {
+        batch_id,
+        batch_root,
+        domain_separator,
+        digest,
+    }
+}
+
+pub fn typed_data_digest<M: CryptoApi>(
+    domain_separator: &Eip712Hash<M>,
+    struct_hash: &Eip712Hash<M>,
+) -> Eip712Hash<M> {
+    let mut encoded = ManagedBuffer::new_from_bytes(EIP712_PREFIX);
+    encoded.append(domain_separator.as_managed_buffer());
+    encoded.append(struct_hash.as_managed_buffer());
+
+    keccak256(&encoded)
+}
+
+/// Digest attested by relayers for an arbitrary payload, e.g. a serialized multisig action:
+///
+/// `keccak256(bridgeDomainSeparator || payload)`
+pub fn attestation_digest<M: CryptoApi>(
+    bridge_domain: &BridgeDomain<M>,
+    payload: &ManagedBuffer<M>,
+) -> Eip712Hash<M> {
+    let mut encoded = ManagedBuffer::new();
+    encoded.append(bridge_domain.separator().as_managed_buffer());
+    encoded.append(payload);
+
+    keccak256(&encoded)
+}
+
+/// Digest attested by relayers for a proposed multisig action:
+///
+/// `keccak256(bridgeDomainSeparator || multisigAddress || uint256(actionId) || action)`
+///
+/// The action ID ties the signature to a single proposal, so it is not valid
+/// for a later identical one, e.g. proposed again once the first was discarded.
+pub fn action_attestation_digest<M: CryptoApi>(
+    bridge_domain: &BridgeDomain<M>,
+    multisig_address: &ManagedAddress<M>,
+    action_id: usize,
+    serialized_action: &ManagedBuffer<M>,
+) -> Eip712Hash<M> {
+    let mut payload = ManagedBuffer::new();
+    payload.append(multisig_address.as_managed_buffer());
+    payload.append(&encode_u64(action_id as u64));
+    payload.append(serialized_action);
+
+    attestation_digest(bridge_domain, &payload)
+}
+
+/// Digest a board member signs with their Ethereum key when registering it, as proof they own the key:
+///
+/// `keccak256(bridgeDomainSeparator || "registerEthPublicKey" || multisigAddress || boardMember)`
+pub fn key_registration_digest<M: CryptoApi>(
+    bridge_domain: &BridgeDomain<M>,
+    multisig_address: &ManagedAddress<M>,
+    board_member: &ManagedAddress<M>,
+) -> Eip712Hash<M> {
+    let mut payload = ManagedBuffer::new_from_bytes(KEY_REGISTRATION_TAG);
+    payload.append(multisig_address.as_managed_buffer());
+    payload.append(board_member.as_managed_buffer());
+
+    attestation_digest(bridge_domain, &payload)
+}
+
+fn keccak256<M: CryptoApi>(data: &ManagedBuffer<M>) -> Eip712Hash<M> {
+    CryptoWrapper::<M>::new().keccak256(data)
+}
+
+fn encode_u64<M: CryptoApi>(value: u64) -> ManagedBuffer<M> {
+    let mut word = [0u8; ABI_WORD_LEN];
+    word[ABI_WORD_LEN - 8..].copy_from_slice(&value.to_be_bytes());
+
+    ManagedBuffer::new_from_bytes(&word)
+}
+
+fn encode_uint<M: CryptoApi>(value: &BigUint<M>) -> ManagedBuffer<M> {
+    left_pad(&value.to_bytes_be_buffer())
+}
+
+fn encode_address<M: CryptoApi>(address: &EthAddress<M>) -> ManagedBuffer<M> {
+    left_pad(address.as_managed_buffer())
+}
+
+/// Pads a value to a 32-byte ABI word. Longer values do not fit in a word,
+/// so they are rejected instead of producing a non-standard encoding.
+fn left_pad<M: CryptoApi>(value: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    let value_len = value.len();
+    if value_len > ABI_WORD_LEN {
+        M::error_api_impl().signal_error(b"Value does not fit in an ABI word");
+    }
+    if value_len == ABI_WORD_LEN {
+        return value.clone();
+    }
+
+    let mut word = ManagedBuffer::new_from_bytes(&[0u8; ABI_WORD_LEN][..ABI_WORD_LEN - value_len]);
+    word.append(value);
+
+    word
+}
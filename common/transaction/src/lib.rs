@@ -5,12 +5,17 @@ multiversx_sc::derive_imports!();
 
 use eth_address::EthAddress;
 
+pub mod compact_batch;
+pub mod eip712;
+pub mod nft_transaction;
 pub mod transaction_status;
 
 // revert protection
 pub const MIN_BLOCKS_FOR_FINALITY: u64 = 10;
 pub const TX_MULTIRESULT_NR_FIELDS: usize = 6;
+pub const ETHEREUM_CHAIN_ID: ChainId = 1;
 
+pub type ChainId = u32;
 pub type TxNonce = u64;
 pub type BlockNonce = u64;
 pub type SenderAddressRaw<M> = ManagedBuffer<M>;
@@ -38,6 +43,9 @@ pub struct EthTransaction<M: ManagedTypeApi> {
 pub type EthTxAsMultiValue<M> =
     MultiValue5<EthAddress<M>, ManagedAddress<M>, TokenIdentifier<M>, BigUint<M>, TxNonce>;
 
+/// Destination addresses are kept as raw (length-prefixed) buffers,
+/// so each destination chain can use its own address format.
+/// Which formats are accepted is decided by the chain's `AddressRule`.
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
 pub struct Transaction<M: ManagedTypeApi> {
     pub block_nonce: BlockNonce,
@@ -79,3 +87,63 @@ impl<M: ManagedTypeApi> Transaction<M> {
             .into()
     }
 }
+
+/// Splits a batch into the fields returned by the batch views, which is the format
+/// the relayers read. `is_refund_tx` is not part of the fields, so it does not survive
+/// a split followed by a `join_batch`.
+pub fn split_batch<M, I>(batch_id: u64, transactions: I) -> TxBatchSplitInFields<M>
+where
+    M: ManagedTypeApi,
+    I: IntoIterator<Item = Transaction<M>>,
+{
+    let mut tx_fields = MultiValueEncoded::new();
+    for tx in transactions {
+        tx_fields.push(tx.into_multiresult());
+    }
+
+    (batch_id, tx_fields).into()
+}
+
+/// The reverse of `split_batch`.
+pub fn join_batch<M: ManagedTypeApi>(
+    batch: TxBatchSplitInFields<M>,
+) -> (u64, ManagedVec<M, Transaction<M>>) {
+    let (batch_id, all_tx_fields) = batch.into_tuple();
+    let mut transactions = ManagedVec::new();
+    for tx_fields in all_tx_fields {
+        transactions.push(Transaction::from(tx_fields));
+    }
+
+    (batch_id, transactions)
+}
+
+/// Validation rule for raw destination addresses on a given chain.
+/// For example, Ethereum addresses are always 20 bytes long.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone, PartialEq,
+)]
+pub struct AddressRule {
+    pub min_len: u32,
+    pub max_len: u32,
+}
+
+impl AddressRule {
+    pub fn fixed_len(len: u32) -> Self {
+        AddressRule {
+            min_len: len,
+            max_len: len,
+        }
+    }
+
+    pub fn is_valid<M: ManagedTypeApi>(&self, address: &ManagedBuffer<M>) -> bool {
+        let address_len = address.len() as u32;
+
+        address_len >= self.min_len && address_len <= self.max_len
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
+pub struct ChainAddressRule {
+    pub chain_id: ChainId,
+    pub rule: AddressRule,
+}
@@ -0,0 +1,120 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use eth_address::EthAddress;
+
+use crate::{BlockNonce, ReceiverAddressRaw, SenderAddressRaw, Transaction, TxNonce};
+
+pub const NFT_TX_MULTIRESULT_NR_FIELDS: usize = 7;
+
+pub type NftTxAsMultiValue<M> = MultiValue7<
+    BlockNonce,
+    TxNonce,
+    SenderAddressRaw<M>,
+    ReceiverAddressRaw<M>,
+    TokenIdentifier<M>,
+    u64,
+    ManagedBuffer<M>,
+>;
+pub type NftTxBatchSplitInFields<M> = MultiValue2<u64, MultiValueEncoded<M, NftTxAsMultiValue<M>>>;
+
+/// Elrond -> Ethereum NFT transfer.
+/// The NFT itself is held by the NftSafe, so only its nonce and attributes are recorded.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
+pub struct NftTransaction<M: ManagedTypeApi> {
+    pub block_nonce: BlockNonce,
+    pub nonce: TxNonce,
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+    pub token_identifier: TokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub attributes: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> NftTransaction<M> {
+    pub fn new(tx: Transaction<M>, data: NftTransactionData<M>) -> Self {
+        NftTransaction {
+            block_nonce: tx.block_nonce,
+            nonce: tx.nonce,
+            from: tx.from,
+            to: tx.to,
+            token_identifier: tx.token_identifier,
+            token_nonce: data.token_nonce,
+            attributes: data.attributes,
+        }
+    }
+
+    pub fn into_multiresult(self) -> NftTxAsMultiValue<M> {
+        (
+            self.block_nonce,
+            self.nonce,
+            self.from,
+            self.to,
+            self.token_identifier,
+            self.token_nonce,
+            self.attributes,
+        )
+            .into()
+    }
+}
+
+impl<M: ManagedTypeApi> From<NftTxAsMultiValue<M>> for NftTransaction<M> {
+    fn from(tx_as_multiresult: NftTxAsMultiValue<M>) -> Self {
+        let (block_nonce, nonce, from, to, token_identifier, token_nonce, attributes) =
+            tx_as_multiresult.into_tuple();
+
+        NftTransaction {
+            block_nonce,
+            nonce,
+            from,
+            to,
+            token_identifier,
+            token_nonce,
+            attributes,
+        }
+    }
+}
+
+/// The reverse of splitting an NFT batch in fields, as returned by the NftSafe and NftTransfer views.
+pub fn join_nft_batch<M: ManagedTypeApi>(
+    batch: NftTxBatchSplitInFields<M>,
+) -> (u64, ManagedVec<M, NftTransaction<M>>) {
+    let (batch_id, all_tx_fields) = batch.into_tuple();
+    let mut transactions = ManagedVec::new();
+    for tx_fields in all_tx_fields {
+        transactions.push(NftTransaction::from(tx_fields));
+    }
+
+    (batch_id, transactions)
+}
+
+/// The NFT-specific fields of an `NftTransaction`.
+///
+/// NFT transactions are batched as regular `Transaction`s of a single unit,
+/// so these fields are stored separately, by transaction nonce.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct NftTransactionData<M: ManagedTypeApi> {
+    pub token_nonce: u64,
+    pub attributes: ManagedBuffer<M>,
+}
+
+/// Ethereum -> Elrond NFT transfer.
+/// `token_nonce` is the nonce of the NFT on the Elrond side, if it is locked in the NftSafe, or 0 otherwise.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
+pub struct EthNftTransaction<M: ManagedTypeApi> {
+    pub from: EthAddress<M>,
+    pub to: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub attributes: ManagedBuffer<M>,
+    pub tx_nonce: TxNonce,
+}
+
+pub type EthNftTxAsMultiValue<M> = MultiValue6<
+    EthAddress<M>,
+    ManagedAddress<M>,
+    TokenIdentifier<M>,
+    u64,
+    ManagedBuffer<M>,
+    TxNonce,
+>;
@@ -8,6 +8,8 @@ multiversx_sc::derive_imports!();
     NestedDecode,
     TypeAbi,
     PartialEq,
+    Eq,
+    Debug,
     Clone,
     Copy,
     ManagedVecItem,
@@ -19,3 +21,98 @@ pub enum TransactionStatus {
     Executed,
     Rejected,
 }
+
+/// Statuses of the transactions of one EsdtSafe batch, in the batch's order.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
+pub struct BatchStatuses<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub tx_statuses: ManagedVec<M, TransactionStatus>,
+}
+
+/// `TransactionStatus` list, packed on `STATUS_BITS` bits per status.
+/// Each status is stored as its discriminant + 1, so trailing zero bits mark the end of the list.
+pub type PackedTransactionStatuses<M> = ManagedBuffer<M>;
+
+const STATUS_BITS: u32 = 3;
+const STATUS_MASK: u32 = (1 << STATUS_BITS) - 1;
+const BYTE_BITS: u32 = 8;
+
+impl TransactionStatus {
+    fn from_discriminant(discriminant: u32) -> Option<Self> {
+        match discriminant {
+            0 => Some(TransactionStatus::None),
+            1 => Some(TransactionStatus::Pending),
+            2 => Some(TransactionStatus::InProgress),
+            3 => Some(TransactionStatus::Executed),
+            4 => Some(TransactionStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+pub fn pack_statuses<M: ManagedTypeApi>(
+    statuses: &ManagedVec<M, TransactionStatus>,
+) -> PackedTransactionStatuses<M> {
+    let mut packed = ManagedBuffer::new();
+    let mut bits = 0u32;
+    let mut nr_bits = 0u32;
+    for status in statuses.iter() {
+        bits = (bits << STATUS_BITS) | (status as u32 + 1);
+        nr_bits += STATUS_BITS;
+
+        if nr_bits >= BYTE_BITS {
+            nr_bits -= BYTE_BITS;
+            packed.append_bytes(&[(bits >> nr_bits) as u8]);
+            bits &= (1 << nr_bits) - 1;
+        }
+    }
+
+    if nr_bits > 0 {
+        packed.append_bytes(&[(bits << (BYTE_BITS - nr_bits)) as u8]);
+    }
+
+    packed
+}
+
+/// Packs the statuses of all the batches, in order, as a single list.
+/// For a single batch, this is the same as packing its statuses.
+pub fn pack_batch_statuses<M: ManagedTypeApi>(
+    batch_statuses: &ManagedVec<M, BatchStatuses<M>>,
+) -> PackedTransactionStatuses<M> {
+    let mut all_statuses = ManagedVec::new();
+    for batch in batch_statuses.iter() {
+        all_statuses.append_vec(batch.tx_statuses);
+    }
+
+    pack_statuses(&all_statuses)
+}
+
+pub fn unpack_statuses<M: ManagedTypeApi>(
+    packed: &PackedTransactionStatuses<M>,
+) -> ManagedVec<M, TransactionStatus> {
+    let mut statuses = ManagedVec::new();
+    let mut bits = 0u32;
+    let mut nr_bits = 0u32;
+    for i in 0..packed.len() {
+        let mut byte = [0u8; 1];
+        let _ = packed.load_slice(i, &mut byte);
+        bits = (bits << BYTE_BITS) | byte[0] as u32;
+        nr_bits += BYTE_BITS;
+
+        while nr_bits >= STATUS_BITS {
+            nr_bits -= STATUS_BITS;
+            let packed_status = (bits >> nr_bits) & STATUS_MASK;
+            bits &= (1 << nr_bits) - 1;
+            if packed_status == 0 {
+                return statuses;
+            }
+
+            match TransactionStatus::from_discriminant(packed_status - 1) {
+                Some(status) => statuses.push(status),
+                None => M::error_api_impl().signal_error(b"Invalid packed transaction status"),
+            }
+        }
+    }
+
+    statuses
+}
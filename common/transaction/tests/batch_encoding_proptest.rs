@@ -0,0 +1,264 @@
+use eth_address::{EthAddress, ETH_ADDRESS_LEN};
+use multiversx_sc::codec::{
+    top_encode_to_vec_u8_or_panic, TopDecode, TopDecodeMulti, TopEncodeMulti,
+};
+use multiversx_sc::types::{
+    BigUint, ManagedAddress, ManagedBuffer, ManagedByteArray, ManagedVec, TokenIdentifier,
+};
+use multiversx_sc_scenario::DebugApi;
+use proptest::prelude::*;
+use transaction::{
+    compact_batch::{decode_compact_batch, encode_compact_batch},
+    eip712::{hash_batch, BridgeDomain},
+    join_batch, split_batch,
+    transaction_status::{pack_statuses, unpack_statuses, TransactionStatus},
+    EthTransaction, Transaction, TxBatchSplitInFields, TX_MULTIRESULT_NR_FIELDS,
+};
+
+#[derive(Clone, Debug)]
+struct TxFields {
+    block_nonce: u64,
+    nonce: u64,
+    from: Vec<u8>,
+    to: Vec<u8>,
+    token_id: Vec<u8>,
+    amount: Vec<u8>,
+    is_refund_tx: bool,
+}
+
+impl TxFields {
+    fn to_transaction(&self) -> Transaction<DebugApi> {
+        Transaction {
+            block_nonce: self.block_nonce,
+            nonce: self.nonce,
+            from: ManagedBuffer::from(self.from.as_slice()),
+            to: ManagedBuffer::from(self.to.as_slice()),
+            token_identifier: TokenIdentifier::from(self.token_id.as_slice()),
+            amount: BigUint::from_bytes_be(&self.amount),
+            is_refund_tx: self.is_refund_tx,
+        }
+    }
+}
+
+fn bridge_domain(chain_id: &[u8], bridge_instance_id: u64) -> BridgeDomain<DebugApi> {
+    BridgeDomain {
+        chain_id: ManagedBuffer::from(chain_id),
+        bridge_instance_id,
+    }
+}
+
+fn tx_fields() -> impl Strategy<Value = TxFields> {
+    (
+        any::<u64>(),
+        any::<u64>(),
+        prop::collection::vec(any::<u8>(), 0..64),
+        prop::collection::vec(any::<u8>(), 0..64),
+        "[A-Z]{3,10}-[0-9a-f]{6}",
+        prop::collection::vec(any::<u8>(), 0..40),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(block_nonce, nonce, from, to, token_id, amount, is_refund_tx)| TxFields {
+                block_nonce,
+                nonce,
+                from,
+                to,
+                token_id: token_id.into_bytes(),
+                amount,
+                is_refund_tx,
+            },
+        )
+}
+
+fn batch_fields() -> impl Strategy<Value = Vec<TxFields>> {
+    prop::collection::vec(tx_fields(), 0..20)
+}
+
+fn to_transactions(batch: &[TxFields]) -> ManagedVec<DebugApi, Transaction<DebugApi>> {
+    let mut transactions = ManagedVec::new();
+    for fields in batch {
+        transactions.push(fields.to_transaction());
+    }
+
+    transactions
+}
+
+/// Refund flags are not part of the batch views, so only non-refund batches round-trip through them.
+fn without_refund_flags(batch: &[TxFields]) -> Vec<TxFields> {
+    batch
+        .iter()
+        .cloned()
+        .map(|fields| TxFields {
+            is_refund_tx: false,
+            ..fields
+        })
+        .collect()
+}
+
+fn status() -> impl Strategy<Value = TransactionStatus> {
+    prop_oneof![
+        Just(TransactionStatus::None),
+        Just(TransactionStatus::Pending),
+        Just(TransactionStatus::InProgress),
+        Just(TransactionStatus::Executed),
+        Just(TransactionStatus::Rejected),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn transaction_top_encoding_round_trips(fields in tx_fields()) {
+        let _ = DebugApi::dummy();
+        let tx = fields.to_transaction();
+
+        let encoded = top_encode_to_vec_u8_or_panic(&tx);
+        let decoded = Transaction::<DebugApi>::top_decode(encoded.as_slice()).unwrap();
+        prop_assert_eq!(top_encode_to_vec_u8_or_panic(&decoded), encoded);
+        prop_assert_eq!(decoded.is_refund_tx, fields.is_refund_tx);
+    }
+
+    #[test]
+    fn eth_transaction_top_encoding_round_trips(
+        from in prop::array::uniform20(any::<u8>()),
+        to in prop::array::uniform32(any::<u8>()),
+        token_id in "[A-Z]{3,10}-[0-9a-f]{6}",
+        amount in prop::collection::vec(any::<u8>(), 0..40),
+        tx_nonce in any::<u64>(),
+    ) {
+        let _ = DebugApi::dummy();
+        let eth_tx = EthTransaction::<DebugApi> {
+            from: EthAddress {
+                raw_addr: ManagedByteArray::<DebugApi, ETH_ADDRESS_LEN>::new_from_bytes(&from),
+            },
+            to: ManagedAddress::from(to),
+            token_id: TokenIdentifier::from(token_id.as_str()),
+            amount: BigUint::from_bytes_be(&amount),
+            tx_nonce,
+        };
+
+        let encoded = top_encode_to_vec_u8_or_panic(&eth_tx);
+        let decoded = EthTransaction::<DebugApi>::top_decode(encoded.as_slice()).unwrap();
+        prop_assert_eq!(top_encode_to_vec_u8_or_panic(&decoded), encoded);
+        prop_assert_eq!(decoded.tx_nonce, tx_nonce);
+    }
+
+    #[test]
+    fn batch_split_join_round_trips(batch_id in any::<u64>(), batch in batch_fields()) {
+        let _ = DebugApi::dummy();
+        let batch = without_refund_flags(&batch);
+        let transactions = to_transactions(&batch);
+
+        let (joined_batch_id, joined) = join_batch(split_batch(batch_id, transactions.iter()));
+        prop_assert_eq!(joined_batch_id, batch_id);
+        prop_assert_eq!(
+            top_encode_to_vec_u8_or_panic(&joined),
+            top_encode_to_vec_u8_or_panic(&transactions)
+        );
+    }
+
+    #[test]
+    fn batch_view_results_round_trip(batch_id in any::<u64>(), batch in batch_fields()) {
+        let _ = DebugApi::dummy();
+        let batch = without_refund_flags(&batch);
+        let transactions = to_transactions(&batch);
+
+        let mut raw_results = Vec::<Vec<u8>>::new();
+        split_batch(batch_id, transactions.iter())
+            .multi_encode(&mut raw_results)
+            .unwrap();
+        prop_assert_eq!(raw_results.len(), 1 + batch.len() * TX_MULTIRESULT_NR_FIELDS);
+
+        let decoded = TxBatchSplitInFields::<DebugApi>::multi_decode(&mut raw_results).unwrap();
+        let (decoded_batch_id, decoded_transactions) = join_batch(decoded);
+        prop_assert_eq!(decoded_batch_id, batch_id);
+        prop_assert_eq!(
+            top_encode_to_vec_u8_or_panic(&decoded_transactions),
+            top_encode_to_vec_u8_or_panic(&transactions)
+        );
+    }
+
+    #[test]
+    fn compact_batch_round_trips(
+        batch_id in any::<u64>(),
+        batch in batch_fields(),
+        shared_token_id in "[A-Z]{3,10}-[0-9a-f]{6}",
+        share_token_id in any::<bool>(),
+    ) {
+        let _ = DebugApi::dummy();
+        let mut batch = without_refund_flags(&batch);
+        if share_token_id {
+            for fields in batch.iter_mut() {
+                fields.token_id = shared_token_id.clone().into_bytes();
+            }
+        }
+        let transactions = to_transactions(&batch);
+
+        let encoded = encode_compact_batch(batch_id, transactions.iter());
+        let (decoded_batch_id, decoded_transactions) = decode_compact_batch(&encoded);
+        prop_assert_eq!(decoded_batch_id, batch_id);
+        prop_assert_eq!(
+            top_encode_to_vec_u8_or_panic(&decoded_transactions),
+            top_encode_to_vec_u8_or_panic(&transactions)
+        );
+    }
+
+    #[test]
+    fn batch_hash_survives_split_join(batch_id in any::<u64>(), batch in batch_fields()) {
+        let _ = DebugApi::dummy();
+        let domain = bridge_domain(b"1", 0);
+        let transactions = to_transactions(&batch);
+        let expected_hash = hash_batch(&domain, batch_id, &transactions);
+
+        let (_, joined) = join_batch(split_batch(batch_id, transactions.iter()));
+        prop_assert_eq!(hash_batch(&domain, batch_id, &joined).to_byte_array(), expected_hash.to_byte_array());
+    }
+
+    #[test]
+    fn batch_hash_changes_with_any_amount(
+        batch_id in any::<u64>(),
+        batch in prop::collection::vec(tx_fields(), 1..20),
+        index in any::<prop::sample::Index>(),
+    ) {
+        let _ = DebugApi::dummy();
+        let domain = bridge_domain(b"1", 0);
+        let expected_hash = hash_batch(&domain, batch_id, &to_transactions(&batch)).to_byte_array();
+
+        let mut changed_batch = batch.clone();
+        let changed_tx = &mut changed_batch[index.index(batch.len())];
+        let amount = BigUint::<DebugApi>::from_bytes_be(&changed_tx.amount) + 1u32;
+        changed_tx.amount = amount.to_bytes_be().as_slice().to_vec();
+
+        prop_assert_ne!(hash_batch(&domain, batch_id, &to_transactions(&changed_batch)).to_byte_array(), expected_hash);
+        prop_assert_ne!(hash_batch(&domain, batch_id.wrapping_add(1), &to_transactions(&batch)).to_byte_array(), expected_hash);
+    }
+
+    #[test]
+    fn batch_hash_changes_with_bridge_domain(
+        batch_id in any::<u64>(),
+        batch in batch_fields(),
+        bridge_instance_id in any::<u64>(),
+    ) {
+        let _ = DebugApi::dummy();
+        let transactions = to_transactions(&batch);
+        let expected_hash = hash_batch(&bridge_domain(b"1", bridge_instance_id), batch_id, &transactions).to_byte_array();
+
+        prop_assert_ne!(hash_batch(&bridge_domain(b"D", bridge_instance_id), batch_id, &transactions).to_byte_array(), expected_hash);
+        prop_assert_ne!(hash_batch(&bridge_domain(b"1", bridge_instance_id.wrapping_add(1)), batch_id, &transactions).to_byte_array(), expected_hash);
+    }
+
+    #[test]
+    fn packed_statuses_round_trip(statuses in prop::collection::vec(status(), 0..200)) {
+        let _ = DebugApi::dummy();
+        let mut status_vec = ManagedVec::<DebugApi, TransactionStatus>::new();
+        for status in &statuses {
+            status_vec.push(*status);
+        }
+
+        let packed = pack_statuses(&status_vec);
+        let nr_status_bits = statuses.len() * 3;
+        prop_assert!(packed.len() * 8 >= nr_status_bits && packed.len() * 8 < nr_status_bits + 8);
+
+        let unpacked: Vec<TransactionStatus> = unpack_statuses(&packed).iter().collect();
+        prop_assert_eq!(unpacked, statuses);
+    }
+}
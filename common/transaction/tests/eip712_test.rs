@@ -0,0 +1,131 @@
+use eth_address::{EthAddress, ETH_ADDRESS_LEN};
+use multiversx_sc::types::{BigUint, ManagedBuffer, ManagedByteArray, ManagedVec, TokenIdentifier};
+use multiversx_sc_scenario::DebugApi;
+use transaction::{
+    eip712::{
+        attest_batch, batch_digest, hash_transaction, BridgeDomain, Eip712Domain, Eip712Hash,
+    },
+    Transaction,
+};
+
+// Expected values were computed off-chain with a reference `eth_signTypedData_v4` encoder,
+// for the types `Batch(bytes32 bridgeDomain,uint64 batchId,Transaction[] transactions)`
+// and `BridgeDomain(bytes chainId,uint64 bridgeInstanceId)`.
+const BRIDGE_DOMAIN_SEPARATOR: &str =
+    "d9b37445e55b7a652e81230d02be33fc99c50e4007f5833fcb9ff6b5dab2ede0";
+const FIRST_TX_HASH: &str = "b06f4680dedd3f5eceedb37bffcb70b3e55a4859e6fc425b5b11734dba5b3390";
+const DOMAIN_SEPARATOR: &str = "aa7d74fab24ecd420c74413c6ea3ed26a6a86c7770f8f19f58561ffb894d290a";
+const BATCH_ROOT: &str = "110a2b14b10e57a229dda4b13ba681960101a158f51623ff1e471fd77067b2a0";
+const BATCH_DIGEST: &str = "6a088364ac749e470e5dc8d2f4ea91ae12c958e2dec06803bdbd7f1653f9368a";
+
+const USER_ADDRESS: &[u8; 32] = b"user____________________________";
+
+fn eth_address(hex_address: &str) -> EthAddress<DebugApi> {
+    let mut raw_addr = [0u8; ETH_ADDRESS_LEN];
+    raw_addr.copy_from_slice(&hex::decode(hex_address).unwrap());
+
+    EthAddress {
+        raw_addr: ManagedByteArray::new_from_bytes(&raw_addr),
+    }
+}
+
+fn evm_domain() -> Eip712Domain<DebugApi> {
+    Eip712Domain {
+        name: ManagedBuffer::from(&b"MultiversX Bridge"[..]),
+        version: ManagedBuffer::from(&b"1"[..]),
+        chain_id: BigUint::from(1u64),
+        verifying_contract: eth_address("5fbdb2315678afecb367f032d93f642f64180aa3"),
+    }
+}
+
+fn bridge_domain() -> BridgeDomain<DebugApi> {
+    BridgeDomain {
+        chain_id: ManagedBuffer::from(&b"1"[..]),
+        bridge_instance_id: 1,
+    }
+}
+
+fn transaction(
+    nonce: u64,
+    to: &str,
+    token_id: &[u8],
+    amount: BigUint<DebugApi>,
+) -> Transaction<DebugApi> {
+    Transaction {
+        block_nonce: nonce,
+        nonce,
+        from: ManagedBuffer::from(&USER_ADDRESS[..]),
+        to: eth_address(to).as_managed_buffer().clone(),
+        token_identifier: TokenIdentifier::from(token_id),
+        amount,
+        is_refund_tx: false,
+    }
+}
+
+fn sample_batch() -> ManagedVec<DebugApi, Transaction<DebugApi>> {
+    let mut transactions = ManagedVec::new();
+    transactions.push(transaction(
+        1,
+        "f39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+        b"WEGLD-123456",
+        BigUint::from(1_000_000_000_000_000_000u64),
+    ));
+    transactions.push(transaction(
+        2,
+        "70997970c51812dc3a010c7d01b50e0d17dc79c8",
+        b"USDC-abcdef",
+        BigUint::from(2_500u64),
+    ));
+
+    transactions
+}
+
+fn to_hex(hash: &Eip712Hash<DebugApi>) -> String {
+    hex::encode(hash.to_byte_array())
+}
+
+#[test]
+fn test_transaction_known_hash() {
+    let _ = DebugApi::dummy();
+    let transactions = sample_batch();
+
+    assert_eq!(
+        to_hex(&hash_transaction(&transactions.get(0))),
+        FIRST_TX_HASH
+    );
+}
+
+#[test]
+fn test_batch_known_digest() {
+    let _ = DebugApi::dummy();
+    let transactions = sample_batch();
+
+    assert_eq!(
+        to_hex(&bridge_domain().separator()),
+        BRIDGE_DOMAIN_SEPARATOR
+    );
+    assert_eq!(
+        to_hex(&batch_digest(
+            &evm_domain(),
+            &bridge_domain(),
+            1,
+            &transactions
+        )),
+        BATCH_DIGEST
+    );
+
+    let attestation = attest_batch(&evm_domain(), &bridge_domain(), 1, &transactions);
+    assert_eq!(to_hex(&attestation.domain_separator), DOMAIN_SEPARATOR);
+    assert_eq!(to_hex(&attestation.batch_root), BATCH_ROOT);
+    assert_eq!(to_hex(&attestation.digest), BATCH_DIGEST);
+}
+
+#[test]
+#[should_panic]
+fn test_value_wider_than_abi_word_rejected() {
+    let _ = DebugApi::dummy();
+    let mut domain = evm_domain();
+    domain.chain_id = BigUint::from_bytes_be(&[1u8; 33]);
+
+    let _ = domain.separator();
+}
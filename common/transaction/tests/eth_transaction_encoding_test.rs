@@ -0,0 +1,29 @@
+use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopDecode};
+use multiversx_sc::types::{ManagedAddress, TokenIdentifier};
+use multiversx_sc_scenario::DebugApi;
+use transaction::EthTransaction;
+
+/// Encoding of an `EthTransaction` saved before the upgrade, e.g. in a pending multisig action,
+/// or in MultiTransferEsdt's remaining and failed transfers.
+const STORED_ETH_TX: &str = concat!(
+    "0102030405060708091011121314151617181920",
+    "2222222222222222222222222222222222222222222222222222222222222222",
+    "0000000d4252494447452d313233343536",
+    "000000020190",
+    "0000000000000007",
+);
+
+#[test]
+fn test_decode_stored_eth_transaction() {
+    let _ = DebugApi::dummy();
+    let stored = hex::decode(STORED_ETH_TX).unwrap();
+
+    let eth_tx = EthTransaction::<DebugApi>::top_decode(stored.as_slice()).unwrap();
+    assert_eq!(eth_tx.from.raw_addr.to_byte_array()[0], 0x01);
+    assert!(eth_tx.to == ManagedAddress::from([0x22u8; 32]));
+    assert!(eth_tx.token_id == TokenIdentifier::from("BRIDGE-123456"));
+    assert!(eth_tx.amount == 400u32);
+    assert_eq!(eth_tx.tx_nonce, 7);
+
+    assert_eq!(top_encode_to_vec_u8_or_panic(&eth_tx), stored);
+}
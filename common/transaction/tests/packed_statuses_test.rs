@@ -0,0 +1,30 @@
+use multiversx_sc::types::ManagedVec;
+use multiversx_sc_scenario::DebugApi;
+use transaction::transaction_status::{pack_statuses, unpack_statuses, TransactionStatus};
+
+#[test]
+fn test_pack_unpack_statuses() {
+    let _ = DebugApi::dummy();
+    let mut statuses = ManagedVec::<DebugApi, TransactionStatus>::new();
+    for i in 0..10 {
+        if i % 3 == 0 {
+            statuses.push(TransactionStatus::Rejected);
+        } else {
+            statuses.push(TransactionStatus::Executed);
+        }
+    }
+
+    let packed = pack_statuses(&statuses);
+    assert_eq!(packed.len(), 4);
+    assert!(unpack_statuses(&packed) == statuses);
+}
+
+#[test]
+fn test_pack_empty_statuses() {
+    let _ = DebugApi::dummy();
+    let statuses = ManagedVec::<DebugApi, TransactionStatus>::new();
+
+    let packed = pack_statuses(&statuses);
+    assert!(packed.is_empty());
+    assert!(unpack_statuses(&packed).is_empty());
+}
@@ -0,0 +1,10 @@
+multiversx_sc::derive_imports!();
+
+/// Settings of the default batch stream which can be chosen when deploying a contract.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct BatchConfig {
+    pub max_tx_batch_size: usize,
+    pub max_tx_batch_block_duration: u64,
+    /// A batch is final once this many blocks passed since its last transaction.
+    pub min_blocks_for_finality: u64,
+}
@@ -3,95 +3,234 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use bridge_errors::{
+    INVALID_MAX_TX_BATCH_BLOCK_DURATION_ERR_MSG, INVALID_MAX_TX_BATCH_SIZE_ERR_MSG,
+    INVALID_MIN_BLOCKS_FOR_FINALITY_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
+
+pub use batch_config::BatchConfig;
 pub use batch_status::BatchStatus;
-use transaction::{Transaction, TxBatchSplitInFields, MIN_BLOCKS_FOR_FINALITY};
+use transaction::{
+    compact_batch::{encode_compact_batch, CompactTxBatch},
+    split_batch, ChainId, Transaction, TxBatchSplitInFields, ETHEREUM_CHAIN_ID,
+    MIN_BLOCKS_FOR_FINALITY,
+};
 use tx_batch_mapper::TxBatchMapper;
 
+pub mod batch_config;
 pub mod batch_status;
 pub mod tx_batch_mapper;
 
+/// Batches are kept in separate streams, one per destination chain.
+/// Each stream has its own batch IDs and its own size/duration settings.
+///
+/// The Ethereum stream is the default one, and uses the original storage keys.
+/// All `opt_chain_id` arguments default to the Ethereum chain ID if missing.
+///
+/// Chains without explicit size/duration settings use the Ethereum stream's settings.
 #[multiversx_sc::module]
-pub trait TxBatchModule {
+pub trait TxBatchModule: config_events_module::ConfigEventsModule {
     // endpoints - owner-only
 
     #[only_owner]
     #[endpoint(setMaxTxBatchSize)]
-    fn set_max_tx_batch_size(&self, new_max_tx_batch_size: usize) {
-        require!(
-            new_max_tx_batch_size > 0,
-            "Max tx batch size must be more than 0"
-        );
-
-        self.max_tx_batch_size().set(new_max_tx_batch_size);
+    fn set_max_tx_batch_size(
+        &self,
+        new_max_tx_batch_size: usize,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
+        require!(new_max_tx_batch_size > 0, INVALID_MAX_TX_BATCH_SIZE_ERR_MSG);
+
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let parameter_key =
+            self.chain_config_key(b"maxTxBatchSize", b"chainMaxTxBatchSize", chain_id);
+        let old_value = self.config_value(&parameter_key);
+        self.max_tx_batch_size_mapper(chain_id)
+            .set(new_max_tx_batch_size);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     #[only_owner]
     #[endpoint(setMaxTxBatchBlockDuration)]
-    fn set_max_tx_batch_block_duration(&self, new_max_tx_batch_block_duration: u64) {
+    fn set_max_tx_batch_block_duration(
+        &self,
+        new_max_tx_batch_block_duration: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
         require!(
             new_max_tx_batch_block_duration > 0,
-            "Max tx batch block duration must be more than 0"
+            INVALID_MAX_TX_BATCH_BLOCK_DURATION_ERR_MSG
         );
 
-        self.max_tx_batch_block_duration()
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let parameter_key = self.chain_config_key(
+            b"maxTxBatchBlockDuration",
+            b"chainMaxTxBatchBlockDuration",
+            chain_id,
+        );
+        let old_value = self.config_value(&parameter_key);
+        self.max_tx_batch_block_duration_mapper(chain_id)
             .set(new_max_tx_batch_block_duration);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the number of blocks a deposit of the given token is held for before its batch can be relayed,
+    /// giving monitoring systems a window to flag it. A hold period of 0 removes it.
+    ///
+    /// Transactions are still added to batches as usual, but a batch is not final
+    /// until the hold period of each of its deposits elapsed.
+    #[only_owner]
+    #[endpoint(setDepositHoldPeriod)]
+    fn set_deposit_hold_period(&self, token_id: TokenIdentifier, hold_period_blocks: u64) {
+        let parameter_key = self.config_key(b"depositHoldPeriod", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.deposit_hold_period(&token_id).set(hold_period_blocks);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     // views
 
     #[view(getCurrentTxBatch)]
-    fn get_current_tx_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
-        let first_batch_id = self.first_batch_id().get();
-        let first_batch = self.pending_batches(first_batch_id);
-
-        if self.is_batch_full(&first_batch, first_batch_id, first_batch_id)
-            && self.is_batch_final(&first_batch)
-        {
-            let mut result_vec = MultiValueEncoded::new();
-            for tx in first_batch.iter() {
-                result_vec.push(tx.into_multiresult());
-            }
-
-            return OptionalValue::Some((first_batch_id, result_vec).into());
+    fn get_current_tx_batch(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        match self.get_current_tx_batch_id(chain_id) {
+            Some(batch_id) => OptionalValue::Some(split_batch(
+                batch_id,
+                self.pending_batches_mapper(chain_id, batch_id).iter(),
+            )),
+            None => OptionalValue::None,
         }
+    }
 
-        OptionalValue::None
+    /// Same as `getCurrentTxBatch`, but with the batch encoded as a single buffer.
+    /// See `CompactTxBatch` in the transaction crate for the layout and the decoder.
+    #[view(getCurrentTxBatchCompact)]
+    fn get_current_tx_batch_compact(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<CompactTxBatch<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        match self.get_current_tx_batch_id(chain_id) {
+            Some(batch_id) => OptionalValue::Some(encode_compact_batch(
+                batch_id,
+                self.pending_batches_mapper(chain_id, batch_id).iter(),
+            )),
+            None => OptionalValue::None,
+        }
     }
 
     #[view(getFirstBatchAnyStatus)]
-    fn get_first_batch_any_status(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
-        let first_batch_id = self.first_batch_id().get();
-        self.get_batch(first_batch_id)
+    fn get_first_batch_any_status(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        self.get_batch(first_batch_id, OptionalValue::Some(chain_id))
     }
 
     #[view(getBatch)]
-    fn get_batch(&self, batch_id: u64) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
-        let tx_batch = self.pending_batches(batch_id);
+    fn get_batch(
+        &self,
+        batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let tx_batch = self.pending_batches_mapper(chain_id, batch_id);
         if tx_batch.is_empty() {
             return OptionalValue::None;
         }
 
-        let mut result_vec = MultiValueEncoded::new();
-        for tx in tx_batch.iter() {
-            result_vec.push(tx.into_multiresult());
+        OptionalValue::Some(split_batch(batch_id, tx_batch.iter()))
+    }
+
+    /// Same as `getBatch`, but with the batch encoded as a single buffer.
+    #[view(getBatchCompact)]
+    fn get_batch_compact(
+        &self,
+        batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<CompactTxBatch<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let tx_batch = self.pending_batches_mapper(chain_id, batch_id);
+        if tx_batch.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(encode_compact_batch(batch_id, tx_batch.iter()))
+    }
+
+    /// Lists all the batches whose statuses were not set yet, as pairs of (batch ID, number of transactions).
+    #[view(getPendingBatchSizes)]
+    #[label("queries")]
+    fn get_pending_batch_sizes(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValueEncoded<MultiValue2<u64, usize>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
+
+        let mut result = MultiValueEncoded::new();
+        for batch_id in first_batch_id..=last_batch_id {
+            let tx_batch = self.pending_batches_mapper(chain_id, batch_id);
+            if !tx_batch.is_empty() {
+                result.push((batch_id, tx_batch.len()).into());
+            }
         }
 
-        OptionalValue::Some((batch_id, result_vec).into())
+        result
+    }
+
+    /// Returns where the batch stream currently is, as (last created batch ID, last settled batch ID, first unsettled batch ID).
+    /// A batch is settled once its transaction statuses were set. IDs are 0 if there is no such batch yet.
+    ///
+    /// The first unsettled batch may not exist yet, in which case it will be the next batch to be created.
+    #[view(getBatchWatermarks)]
+    fn get_batch_watermarks(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValue3<u64, u64, u64> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
+
+        // the last batch ID is bumped in advance when the last existing batch is settled
+        let last_created_batch_id = if self
+            .pending_batches_mapper(chain_id, last_batch_id)
+            .is_empty()
+        {
+            last_batch_id.saturating_sub(1)
+        } else {
+            last_batch_id
+        };
+        let last_settled_batch_id = first_batch_id.saturating_sub(1);
+
+        (last_created_batch_id, last_settled_batch_id, first_batch_id).into()
     }
 
     #[view(getBatchStatus)]
-    fn get_batch_status(&self, batch_id: u64) -> BatchStatus<Self::Api> {
-        let first_batch_id = self.first_batch_id().get();
+    fn get_batch_status(
+        &self,
+        batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> BatchStatus<Self::Api> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
         if batch_id < first_batch_id {
             return BatchStatus::AlreadyProcessed;
         }
 
-        let tx_batch = self.pending_batches(batch_id);
+        let tx_batch = self.pending_batches_mapper(chain_id, batch_id);
         if tx_batch.is_empty() {
             return BatchStatus::Empty;
         }
 
-        if self.is_batch_full(&tx_batch, batch_id, first_batch_id) {
+        if self.is_batch_full(chain_id, &tx_batch, batch_id, first_batch_id) {
             if batch_id == first_batch_id {
                 return BatchStatus::WaitingForSignatures;
             } else {
@@ -104,7 +243,7 @@ pub trait TxBatchModule {
             tx_ids.push(tx.nonce);
         }
 
-        let max_tx_batch_block_duration = self.max_tx_batch_block_duration().get();
+        let max_tx_batch_block_duration = self.get_max_tx_batch_block_duration(chain_id);
         let first_tx_in_batch_block_nonce = tx_batch.get_first_tx().block_nonce;
 
         BatchStatus::PartiallyFull {
@@ -115,13 +254,63 @@ pub trait TxBatchModule {
 
     // private
 
-    fn add_to_batch(&self, transaction: Transaction<Self::Api>) -> u64 {
-        let first_batch_id = self.first_batch_id().get();
-        let last_batch_id = self.last_batch_id().get();
-        let mut last_batch = self.pending_batches(last_batch_id);
+    /// Overwrites the default stream's settings, unlike the defaults set on deployment.
+    fn set_batch_config(&self, config: &BatchConfig) {
+        require!(
+            config.max_tx_batch_size > 0,
+            INVALID_MAX_TX_BATCH_SIZE_ERR_MSG
+        );
+        require!(
+            config.max_tx_batch_block_duration > 0,
+            INVALID_MAX_TX_BATCH_BLOCK_DURATION_ERR_MSG
+        );
+        require!(
+            config.min_blocks_for_finality > 0,
+            INVALID_MIN_BLOCKS_FOR_FINALITY_ERR_MSG
+        );
 
-        if self.is_batch_full(&last_batch, last_batch_id, first_batch_id) {
-            let (new_batch_id, _) = self.create_new_batch(transaction);
+        self.max_tx_batch_size().set(config.max_tx_batch_size);
+        self.max_tx_batch_block_duration()
+            .set(config.max_tx_batch_block_duration);
+        self.min_blocks_for_finality()
+            .set(config.min_blocks_for_finality);
+    }
+
+    fn get_batch_config(&self) -> BatchConfig {
+        BatchConfig {
+            max_tx_batch_size: self.max_tx_batch_size().get(),
+            max_tx_batch_block_duration: self.max_tx_batch_block_duration().get(),
+            min_blocks_for_finality: self.get_min_blocks_for_finality(),
+        }
+    }
+
+    /// Contracts deployed without a batch config use the original finality threshold.
+    fn get_min_blocks_for_finality(&self) -> u64 {
+        let min_blocks_mapper = self.min_blocks_for_finality();
+        if min_blocks_mapper.is_empty() {
+            MIN_BLOCKS_FOR_FINALITY
+        } else {
+            min_blocks_mapper.get()
+        }
+    }
+
+    fn init_batch_stream(&self, chain_id: ChainId) {
+        // batch ID 0 is considered invalid
+        self.first_batch_id_mapper(chain_id).set_if_empty(1);
+        self.last_batch_id_mapper(chain_id).set_if_empty(1);
+    }
+
+    fn chain_id_or_default(&self, opt_chain_id: OptionalValue<ChainId>) -> ChainId {
+        opt_chain_id.into_option().unwrap_or(ETHEREUM_CHAIN_ID)
+    }
+
+    fn add_to_batch(&self, chain_id: ChainId, transaction: Transaction<Self::Api>) -> u64 {
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
+        let mut last_batch = self.pending_batches_mapper(chain_id, last_batch_id);
+
+        if self.is_batch_full(chain_id, &last_batch, last_batch_id, first_batch_id) {
+            let (new_batch_id, _) = self.create_new_batch(chain_id, transaction);
 
             new_batch_id
         } else {
@@ -134,20 +323,21 @@ pub trait TxBatchModule {
     // optimized to prevent reading/storing the batch over and over
     fn add_multiple_tx_to_batch(
         &self,
+        chain_id: ChainId,
         transactions: &ManagedVec<Transaction<Self::Api>>,
     ) -> ManagedVec<u64> {
         if transactions.is_empty() {
             return ManagedVec::new();
         }
 
-        let first_batch_id = self.first_batch_id().get();
-        let mut last_batch_id = self.last_batch_id().get();
-        let mut last_batch = self.pending_batches(last_batch_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let mut last_batch_id = self.last_batch_id_mapper(chain_id).get();
+        let mut last_batch = self.pending_batches_mapper(chain_id, last_batch_id);
         let mut batch_ids = ManagedVec::new();
 
         for tx in transactions {
-            if self.is_batch_full(&last_batch, last_batch_id, first_batch_id) {
-                (last_batch_id, last_batch) = self.create_new_batch(tx);
+            if self.is_batch_full(chain_id, &last_batch, last_batch_id, first_batch_id) {
+                (last_batch_id, last_batch) = self.create_new_batch(chain_id, tx);
             } else {
                 last_batch.push(tx);
             }
@@ -160,21 +350,23 @@ pub trait TxBatchModule {
 
     fn create_new_batch(
         &self,
+        chain_id: ChainId,
         transaction: Transaction<Self::Api>,
     ) -> (u64, TxBatchMapper<Self::Api>) {
-        let last_batch_id = self.last_batch_id().get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
         let new_batch_id = last_batch_id + 1;
 
-        let mut new_batch = self.pending_batches(new_batch_id);
+        let mut new_batch = self.pending_batches_mapper(chain_id, new_batch_id);
         new_batch.push(transaction);
 
-        self.last_batch_id().set(new_batch_id);
+        self.last_batch_id_mapper(chain_id).set(new_batch_id);
 
         (new_batch_id, new_batch)
     }
 
     fn is_batch_full(
         &self,
+        chain_id: ChainId,
         tx_batch: &TxBatchMapper<Self::Api>,
         batch_id: u64,
         first_batch_id: u64,
@@ -183,17 +375,30 @@ pub trait TxBatchModule {
             return false;
         }
 
-        let max_batch_size = self.max_tx_batch_size().get();
+        let max_batch_size = self.get_max_tx_batch_size(chain_id);
         if tx_batch.len() == max_batch_size {
             return true;
         }
 
+        // closed explicitly, see close_expired_batch
+        if batch_id <= self.closed_batch_id(chain_id).get() {
+            return true;
+        }
+
         // if this is not the first batch, we ignore the timestamp checks
         // we only check for max len
         if batch_id > first_batch_id {
             return false;
         }
 
+        self.is_batch_duration_elapsed(chain_id, tx_batch)
+    }
+
+    fn is_batch_duration_elapsed(
+        &self,
+        chain_id: ChainId,
+        tx_batch: &TxBatchMapper<Self::Api>,
+    ) -> bool {
         let current_block_nonce = self.blockchain().get_block_nonce();
         let first_tx_in_batch_block_nonce = tx_batch.get_first_tx().block_nonce;
 
@@ -203,7 +408,7 @@ pub trait TxBatchModule {
         }
 
         let block_diff = current_block_nonce - first_tx_in_batch_block_nonce;
-        let max_tx_batch_block_duration = self.max_tx_batch_block_duration().get();
+        let max_tx_batch_block_duration = self.get_max_tx_batch_block_duration(chain_id);
 
         block_diff >= max_tx_batch_block_duration
     }
@@ -222,26 +427,78 @@ pub trait TxBatchModule {
         }
 
         let block_diff = current_block - last_tx_in_batch.block_nonce;
+        if block_diff <= self.get_min_blocks_for_finality() {
+            return false;
+        }
+
+        self.are_hold_periods_elapsed(tx_batch, current_block)
+    }
+
+    /// Refund transactions are not deposits, so they are never held.
+    fn are_hold_periods_elapsed(
+        &self,
+        tx_batch: &TxBatchMapper<Self::Api>,
+        current_block: u64,
+    ) -> bool {
+        tx_batch.iter().all(|tx| {
+            if tx.is_refund_tx {
+                return true;
+            }
 
-        block_diff > MIN_BLOCKS_FOR_FINALITY
+            let hold_period = self.deposit_hold_period(&tx.token_identifier).get();
+            current_block >= tx.block_nonce + hold_period
+        })
     }
 
-    fn clear_first_batch(&self, mapper: &mut TxBatchMapper<Self::Api>) {
-        let first_batch_id = self.first_batch_id().get();
+    fn clear_first_batch(&self, chain_id: ChainId, mapper: &mut TxBatchMapper<Self::Api>) {
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
         let new_first_batch_id = first_batch_id + 1;
 
         // for the case when the last existing batch was processed
         // otherwise, we'd create a batch with the same ID again
-        self.last_batch_id().update(|last_batch_id| {
+        self.last_batch_id_mapper(chain_id).update(|last_batch_id| {
             if *last_batch_id == first_batch_id {
                 *last_batch_id = new_first_batch_id;
             }
         });
-        self.first_batch_id().set(new_first_batch_id);
+        self.first_batch_id_mapper(chain_id).set(new_first_batch_id);
 
         mapper.clear();
     }
 
+    /// Closes the last batch if its block duration elapsed, so the next transaction starts a new batch.
+    /// The first batch's duration is always checked, but later batches otherwise only close once full.
+    ///
+    /// All the other pending batches are already closed, as a newer batch exists.
+    fn close_expired_batch(&self, chain_id: ChainId) -> Option<u64> {
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
+        let last_batch = self.pending_batches_mapper(chain_id, last_batch_id);
+        if last_batch.is_empty()
+            || self.is_batch_full(chain_id, &last_batch, last_batch_id, first_batch_id)
+            || !self.is_batch_duration_elapsed(chain_id, &last_batch)
+        {
+            return None;
+        }
+
+        self.closed_batch_id(chain_id).set(last_batch_id);
+
+        Some(last_batch_id)
+    }
+
+    /// The first batch of the chain, if it is ready to be relayed.
+    fn get_current_tx_batch_id(&self, chain_id: ChainId) -> Option<u64> {
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let first_batch = self.pending_batches_mapper(chain_id, first_batch_id);
+        if self.is_batch_full(chain_id, &first_batch, first_batch_id, first_batch_id)
+            && self.is_batch_final(&first_batch)
+        {
+            return Some(first_batch_id);
+        }
+
+        None
+    }
+
     fn get_and_save_next_tx_id(&self) -> u64 {
         self.last_tx_nonce().update(|last_tx_nonce| {
             *last_tx_nonce += 1;
@@ -249,6 +506,80 @@ pub trait TxBatchModule {
         })
     }
 
+    fn get_max_tx_batch_size(&self, chain_id: ChainId) -> usize {
+        let mapper = self.max_tx_batch_size_mapper(chain_id);
+        if mapper.is_empty() {
+            self.max_tx_batch_size().get()
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_max_tx_batch_block_duration(&self, chain_id: ChainId) -> u64 {
+        let mapper = self.max_tx_batch_block_duration_mapper(chain_id);
+        if mapper.is_empty() {
+            self.max_tx_batch_block_duration().get()
+        } else {
+            mapper.get()
+        }
+    }
+
+    // storage - per-chain mapper selection
+
+    fn first_batch_id_mapper(&self, chain_id: ChainId) -> SingleValueMapper<u64> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.first_batch_id()
+        } else {
+            self.chain_first_batch_id(chain_id)
+        }
+    }
+
+    fn last_batch_id_mapper(&self, chain_id: ChainId) -> SingleValueMapper<u64> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.last_batch_id()
+        } else {
+            self.chain_last_batch_id(chain_id)
+        }
+    }
+
+    fn pending_batches_mapper(&self, chain_id: ChainId, batch_id: u64) -> TxBatchMapper<Self::Api> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.pending_batches(batch_id)
+        } else {
+            self.chain_pending_batches(chain_id, batch_id)
+        }
+    }
+
+    fn max_tx_batch_size_mapper(&self, chain_id: ChainId) -> SingleValueMapper<usize> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.max_tx_batch_size()
+        } else {
+            self.chain_max_tx_batch_size(chain_id)
+        }
+    }
+
+    fn max_tx_batch_block_duration_mapper(&self, chain_id: ChainId) -> SingleValueMapper<u64> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.max_tx_batch_block_duration()
+        } else {
+            self.chain_max_tx_batch_block_duration(chain_id)
+        }
+    }
+
+    /// The Ethereum stream's settings use the original keys, without the chain ID.
+    fn chain_config_key(
+        &self,
+        ethereum_name: &[u8],
+        chain_name: &[u8],
+        chain_id: ChainId,
+    ) -> ManagedBuffer {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            ManagedBuffer::new_from_bytes(ethereum_name)
+        } else {
+            self.config_key(chain_name, &chain_id)
+        }
+    }
+
     // storage
 
     #[view(getFirstBatchId)]
@@ -262,6 +593,14 @@ pub trait TxBatchModule {
     #[storage_mapper("pendingBatches")]
     fn pending_batches(&self, batch_id: u64) -> TxBatchMapper<Self::Api>;
 
+    /// The highest batch ID that was closed before filling up, per chain.
+    #[storage_mapper("closedBatchId")]
+    fn closed_batch_id(&self, chain_id: ChainId) -> SingleValueMapper<u64>;
+
+    #[view(getDepositHoldPeriod)]
+    #[storage_mapper("depositHoldPeriod")]
+    fn deposit_hold_period(&self, token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
     #[storage_mapper("lastTxNonce")]
     fn last_tx_nonce(&self) -> SingleValueMapper<u64>;
 
@@ -272,4 +611,26 @@ pub trait TxBatchModule {
 
     #[storage_mapper("maxTxBatchBlockDuration")]
     fn max_tx_batch_block_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("minBlocksForFinality")]
+    fn min_blocks_for_finality(&self) -> SingleValueMapper<u64>;
+
+    // storage - non-default chains
+
+    #[view(getChainFirstBatchId)]
+    #[storage_mapper("chainFirstBatchId")]
+    fn chain_first_batch_id(&self, chain_id: ChainId) -> SingleValueMapper<u64>;
+
+    #[view(getChainLastBatchId)]
+    #[storage_mapper("chainLastBatchId")]
+    fn chain_last_batch_id(&self, chain_id: ChainId) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("chainPendingBatches")]
+    fn chain_pending_batches(&self, chain_id: ChainId, batch_id: u64) -> TxBatchMapper<Self::Api>;
+
+    #[storage_mapper("chainMaxTxBatchSize")]
+    fn chain_max_tx_batch_size(&self, chain_id: ChainId) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("chainMaxTxBatchBlockDuration")]
+    fn chain_max_tx_batch_block_duration(&self, chain_id: ChainId) -> SingleValueMapper<u64>;
 }
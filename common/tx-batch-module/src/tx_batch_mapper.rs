@@ -1,3 +1,4 @@
+use bridge_errors::EMPTY_VEC_ERR_MSG;
 use multiversx_sc::{
     api::{ErrorApiImpl, StorageMapperApi},
     storage::{
@@ -7,8 +8,6 @@ use multiversx_sc::{
 };
 use transaction::Transaction;
 
-static EMPTY_VEC_ERR_MSG: &[u8] = b"Empty vec";
-
 pub struct TxBatchMapper<SA>
 where
     SA: StorageMapperApi,
@@ -67,7 +66,7 @@ where
 
     pub fn get_first_tx(&self) -> &Transaction<SA> {
         if self.is_empty() {
-            SA::error_api_impl().signal_error(EMPTY_VEC_ERR_MSG);
+            SA::error_api_impl().signal_error(EMPTY_VEC_ERR_MSG.as_bytes());
         }
 
         unsafe { self.first_tx.as_ref().unwrap_unchecked() }
@@ -75,7 +74,7 @@ where
 
     pub fn get_last_tx(&self) -> &Transaction<SA> {
         if self.is_empty() {
-            SA::error_api_impl().signal_error(EMPTY_VEC_ERR_MSG);
+            SA::error_api_impl().signal_error(EMPTY_VEC_ERR_MSG.as_bytes());
         }
 
         unsafe { self.last_tx.as_ref().unwrap_unchecked() }
@@ -0,0 +1,93 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{DAILY_LIMIT_EXCEEDED_ERR_MSG, NO_USD_PRICE_ERR_MSG};
+use config_events_module::NO_ACTION_ID;
+
+const USD_TICKER: &[u8] = b"USD";
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Global cap on the USD value of all the outgoing transfers in a day, across all tokens.
+/// It is meant as a final backstop, on top of the per-token limits.
+///
+/// The USD value of a transfer is `amount * price / 10^decimals`,
+/// using the aggregator price of the token's ticker in "USD",
+/// so the limit has to be expressed in the same denomination.
+/// While the limit is set, tokens without a USD price cannot be bridged.
+#[multiversx_sc::module]
+pub trait DailyLimitModule:
+    fee_estimator_module::FeeEstimatorModule + config_events_module::ConfigEventsModule
+{
+    /// Sets the daily USD limit. 0 disables the limit.
+    #[only_owner]
+    #[endpoint(setDailyUsdLimit)]
+    fn set_daily_usd_limit(&self, daily_usd_limit: BigUint) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"dailyUsdLimit");
+        let old_value = self.config_value(&parameter_key);
+        self.daily_usd_limit().set(&daily_usd_limit);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Clears the volume of the current day, allowing a full new limit of transfers.
+    #[only_owner]
+    #[endpoint(resetDailyUsdVolume)]
+    fn reset_daily_usd_volume(&self) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"dailyUsdVolume");
+        let old_value = self.config_value(&parameter_key);
+        self.daily_usd_volume().clear();
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// USD value of all the transfers created since the start of the current day (UTC).
+    #[view(getCurrentDailyUsdVolume)]
+    fn get_current_daily_usd_volume(&self) -> BigUint {
+        let daily_usd_volume_mapper = self.daily_usd_volume();
+        if daily_usd_volume_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let (day, volume) = daily_usd_volume_mapper.get();
+        if day == self.get_current_day() {
+            volume
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    fn add_to_daily_usd_volume(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        let daily_usd_limit = self.daily_usd_limit().get();
+        if daily_usd_limit == 0 {
+            return;
+        }
+
+        let new_volume = self.get_current_daily_usd_volume() + self.get_usd_value(token_id, amount);
+        require!(new_volume <= daily_usd_limit, DAILY_LIMIT_EXCEEDED_ERR_MSG);
+
+        self.daily_usd_volume()
+            .set((self.get_current_day(), new_volume));
+    }
+
+    fn get_usd_value(&self, token_id: &TokenIdentifier, amount: &BigUint) -> BigUint {
+        let opt_price_result = self.get_aggregator_price(
+            self.token_ticker(token_id).get(),
+            ManagedBuffer::from(USD_TICKER),
+        );
+        let price_result = match opt_price_result {
+            Some(price_result) => price_result,
+            None => sc_panic!(NO_USD_PRICE_ERR_MSG),
+        };
+
+        amount * &price_result.price / BigUint::from(10u32).pow(price_result.decimals as u32)
+    }
+
+    fn get_current_day(&self) -> u64 {
+        self.blockchain().get_block_timestamp() / SECONDS_PER_DAY
+    }
+
+    #[view(getDailyUsdLimit)]
+    #[storage_mapper("dailyUsdLimit")]
+    fn daily_usd_limit(&self) -> SingleValueMapper<BigUint>;
+
+    /// Pair of (day, USD volume of that day)
+    #[storage_mapper("dailyUsdVolume")]
+    fn daily_usd_volume(&self) -> SingleValueMapper<(u64, BigUint)>;
+}
@@ -0,0 +1,21 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use transaction::ChainAddressRule;
+use tx_batch_module::BatchConfig;
+
+/// Optional init argument, replacing the defaults this contract is otherwise deployed with:
+/// the default batch settings, no pending batches limit, no fallback fee price,
+/// 20-byte addresses for Ethereum as the only destination, and starting paused.
+///
+/// The `getDeploymentConfig` view returns the same structure, filled with the current values.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct DeploymentConfig<M: ManagedTypeApi> {
+    pub batch: BatchConfig,
+    /// 0 means no limit.
+    pub max_pending_batches: u64,
+    pub fallback_price_per_gas_unit: BigUint<M>,
+    /// The accepted destination chains. A batch stream is started for each of them.
+    pub address_rules: ManagedVec<M, ChainAddressRule>,
+    pub paused: bool,
+}
@@ -0,0 +1,71 @@
+multiversx_sc::imports!();
+
+use bridge_errors::DESTINATION_LIMIT_EXCEEDED_ERR_MSG;
+use config_events_module::NO_ACTION_ID;
+
+/// Per-token cap on the amount that can be sent to the same destination address in one epoch,
+/// so a single compromised account cannot drain the bridge's liquidity towards one exit address.
+/// Destination addresses are compared as raw buffers, the same way they are stored in the transactions.
+#[multiversx_sc::module]
+pub trait DestinationLimitModule: config_events_module::ConfigEventsModule {
+    /// Sets the maximum amount of the token that can be sent to any single destination address in one epoch.
+    /// 0 disables the limit.
+    #[only_owner]
+    #[endpoint(setDestinationEpochLimit)]
+    fn set_destination_epoch_limit(&self, token_id: TokenIdentifier, limit: BigUint) {
+        let parameter_key = self.config_key(b"destinationEpochLimit", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.destination_epoch_limit(&token_id).set(&limit);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Amount of the token sent to the destination address in the current epoch.
+    #[view(getDestinationEpochVolume)]
+    fn get_destination_epoch_volume(
+        &self,
+        to: ManagedBuffer,
+        token_id: TokenIdentifier,
+    ) -> BigUint {
+        let volume_mapper = self.destination_epoch_volume(&to, &token_id);
+        if volume_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let (epoch, volume) = volume_mapper.get();
+        if epoch == self.blockchain().get_block_epoch() {
+            volume
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    fn add_to_destination_epoch_volume(
+        &self,
+        to: &ManagedBuffer,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let limit = self.destination_epoch_limit(token_id).get();
+        if limit == 0 {
+            return;
+        }
+
+        let new_volume = self.get_destination_epoch_volume(to.clone(), token_id.clone()) + amount;
+        require!(new_volume <= limit, DESTINATION_LIMIT_EXCEEDED_ERR_MSG);
+
+        self.destination_epoch_volume(to, token_id)
+            .set((self.blockchain().get_block_epoch(), new_volume));
+    }
+
+    #[view(getDestinationEpochLimit)]
+    #[storage_mapper("destinationEpochLimit")]
+    fn destination_epoch_limit(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Pair of (epoch, amount sent in that epoch)
+    #[storage_mapper("destinationEpochVolume")]
+    fn destination_epoch_volume(
+        &self,
+        to: &ManagedBuffer,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<(u64, BigUint)>;
+}
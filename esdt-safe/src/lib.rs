@@ -4,39 +4,85 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use bridge_errors::{
+    BATCHES_OUT_OF_ORDER_ERR_MSG, BATCH_DOES_NOT_EXIST_ERR_MSG, BATCH_NOT_READY_ERR_MSG,
+    CREATE_TX_WHILE_PAUSED_ERR_MSG, DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG,
+    DEPOSIT_REJECTED_BY_SCREENING_ERR_MSG, DESTINATION_CHAIN_NOT_SUPPORTED_ERR_MSG,
+    FEES_OVER_AMOUNT_ERR_MSG, FEE_PAYMENT_TOO_LOW_ERR_MSG, INVALID_ADDRESS_LENGTH_BOUNDS_ERR_MSG,
+    INVALID_DESTINATION_ADDRESS_ERR_MSG, INVALID_FEE_PAYMENT_ERR_MSG,
+    INVALID_NUMBER_OF_STATUSES_ERR_MSG, INVALID_TX_STATUS_ERR_MSG, LOCK_MODE_UNCHANGED_ERR_MSG,
+    NOTHING_TO_REFUND_ERR_MSG, NOT_ENOUGH_FEES_FOR_CLAIM_BOUNTY_ERR_MSG,
+    NO_EXPIRED_REFUNDS_ERR_MSG, PAGE_SIZE_TOO_LARGE_ERR_MSG, REFUND_EXPIRED_ERR_MSG,
+    REFUND_NOT_EXPIRED_ERR_MSG, RELAYED_REFUND_CLAIMS_DISABLED_ERR_MSG,
+    RELAYER_TIP_OVER_AMOUNT_ERR_MSG, TOO_MANY_PENDING_BATCHES_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
 use core::convert::TryFrom;
 
+use deployment_config::DeploymentConfig;
 use eth_address::*;
 use fee_estimator_module::GWEI_STRING;
-use transaction::{transaction_status::TransactionStatus, Transaction};
+use migration_module::bridge_version::{
+    BridgeVersion, InterfaceVersion, TRANSFER_FIELD_DESTINATION_CHAIN, TRANSFER_FIELD_RELAYER_TIP,
+};
+use migration_module::StorageVersion;
+use reconciliation::TokenReconciliation;
+use settlement_receipt::{BatchStatusesRecord, SettledTransaction, SettlementReceipt};
+use transaction::eip712::{attest_batch, hash_batch, BatchAttestation, Eip712Hash};
+use transaction::{
+    transaction_status::{BatchStatuses, TransactionStatus},
+    AddressRule, ChainAddressRule, ChainId, Transaction, TxAsMultiValue, ETHEREUM_CHAIN_ID,
+};
+
+pub mod daily_limit;
+pub mod deployment_config;
+pub mod destination_limit;
+pub mod reconciliation;
+pub mod settlement_receipt;
+pub mod trusted_forwarders;
 
 const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
 const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = 100; // ~10 minutes
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+const MAX_STATUSES_HISTORY_PAGE_SIZE: u64 = 100;
 
 #[multiversx_sc::contract]
 pub trait EsdtSafe:
     fee_estimator_module::FeeEstimatorModule
+    + daily_limit::DailyLimitModule
+    + destination_limit::DestinationLimitModule
+    + trusted_forwarders::TrustedForwardersModule
     + token_module::TokenModule
     + tx_batch_module::TxBatchModule
     + max_bridged_amount_module::MaxBridgedAmountModule
+    + screening_module::ScreeningModule
+    + bridge_domain_module::BridgeDomainModule
+    + ownership_module::OwnershipModule
+    + migration_module::MigrationModule
     + multiversx_sc_modules::pause::PauseModule
+    + storage_inspection_module::StorageInspectionModule
+    + config_events_module::ConfigEventsModule
 {
     /// fee_estimator_contract_address - The address of a Price Aggregator contract,
     /// which will get the price of token A in token B
     ///
     /// eth_tx_gas_limit - The gas limit that will be used for transactions on the ETH side.
     /// Will be used to compute the fees for the transfer
+    ///
+    /// opt_config - Deployment parameters replacing the defaults, see `DeploymentConfig`.
+    /// Unlike the defaults, they overwrite the current values when upgrading.
     #[init]
-    fn init(&self, fee_estimator_contract_address: ManagedAddress, eth_tx_gas_limit: BigUint) {
+    fn init(
+        &self,
+        fee_estimator_contract_address: ManagedAddress,
+        eth_tx_gas_limit: BigUint,
+        opt_config: OptionalValue<DeploymentConfig<Self::Api>>,
+    ) {
         self.fee_estimator_contract_address()
             .set(&fee_estimator_contract_address);
         self.eth_tx_gas_limit().set(&eth_tx_gas_limit);
 
-        self.max_tx_batch_size()
-            .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
-        self.max_tx_batch_block_duration()
-            .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
-
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
         self.last_batch_id().set_if_empty(1);
@@ -46,61 +92,107 @@ pub trait EsdtSafe:
         self.token_ticker(&gwei_token_id)
             .set(gwei_token_id.as_managed_buffer());
 
-        self.set_paused(true);
+        match opt_config.into_option() {
+            Some(config) => self.apply_deployment_config(config),
+            None => self.apply_default_deployment_config(),
+        }
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(
+            INTERFACE_VERSION,
+            0,
+            TRANSFER_FIELD_DESTINATION_CHAIN | TRANSFER_FIELD_RELAYER_TIP,
+        )
     }
 
     /// Sets the statuses for the transactions, after they were executed on the Ethereum side.
     ///
     /// Only TransactionStatus::Executed (3) and TransactionStatus::Rejected (4) values are allowed.
     /// Number of provided statuses must be equal to number of transactions in the batch.
+    ///
+    /// action_id - The ID of the multisig action that sets the statuses,
+    /// recorded in the settlement receipts. 0 if not set through a multisig action.
+    ///
+    /// relayer - Receives the relayer tips of the executed transactions.
+    /// The multisig passes the relayer who performed the action.
     #[only_owner]
     #[endpoint(setTransactionBatchStatus)]
     fn set_transaction_batch_status(
         &self,
         batch_id: u64,
+        action_id: usize,
+        relayer: ManagedAddress,
         tx_statuses: MultiValueEncoded<TransactionStatus>,
     ) {
-        let first_batch_id = self.first_batch_id().get();
-        require!(
-            batch_id == first_batch_id,
-            "Batches must be processed in order"
+        self.set_batch_status_for_chain(
+            ETHEREUM_CHAIN_ID,
+            batch_id,
+            action_id,
+            &relayer,
+            tx_statuses.to_vec(),
         );
+    }
 
-        let mut tx_batch = self.pending_batches(batch_id);
-        require!(
-            tx_batch.len() == tx_statuses.len(),
-            "Invalid number of statuses provided"
+    /// Same as `setTransactionBatchStatus`, but for the batches of the given destination chain.
+    #[only_owner]
+    #[endpoint(setChainTransactionBatchStatus)]
+    fn set_chain_transaction_batch_status(
+        &self,
+        chain_id: ChainId,
+        batch_id: u64,
+        action_id: usize,
+        relayer: ManagedAddress,
+        tx_statuses: MultiValueEncoded<TransactionStatus>,
+    ) {
+        self.set_batch_status_for_chain(
+            chain_id,
+            batch_id,
+            action_id,
+            &relayer,
+            tx_statuses.to_vec(),
         );
+    }
 
-        for (tx, tx_status) in tx_batch.iter().zip(tx_statuses.to_vec().iter()) {
-            // Since tokens don't exist in the EsdtSafe in the case of a refund transaction
-            // we have no tokens to burn, nor to refund
-            if tx.is_refund_tx {
-                continue;
-            }
+    /// Sets the statuses of several consecutive batches of the given destination chain, starting with the first one.
+    ///
+    /// Each batch must be ready for its statuses to be set by the time the previous one is settled,
+    /// same as when it is returned by `getCurrentTxBatch`.
+    #[only_owner]
+    #[endpoint(setMultipleTransactionBatchStatuses)]
+    fn set_multiple_transaction_batch_statuses(
+        &self,
+        chain_id: ChainId,
+        action_id: usize,
+        relayer: ManagedAddress,
+        batch_statuses: MultiValueEncoded<BatchStatuses<Self::Api>>,
+    ) {
+        for batch in batch_statuses {
+            let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+            require!(
+                batch.batch_id == first_batch_id,
+                BATCHES_OUT_OF_ORDER_ERR_MSG
+            );
 
-            match tx_status {
-                TransactionStatus::Executed => {
-                    // local burn role might be removed while tx is executed
-                    // tokens will remain locked forever in that case
-                    // otherwise, the whole batch would fail
-                    if self.is_local_role_set(&tx.token_identifier, &EsdtLocalRole::Burn) {
-                        self.burn_esdt_token(&tx.token_identifier, &tx.amount);
-                    }
-                }
-                TransactionStatus::Rejected => {
-                    let addr = ManagedAddress::try_from(tx.from).unwrap();
-                    self.mark_refund(&addr, &tx.token_identifier, &tx.amount);
-                }
-                _ => {
-                    sc_panic!("Transaction status may only be set to Executed or Rejected");
-                }
-            }
+            let tx_batch = self.pending_batches_mapper(chain_id, batch.batch_id);
+            require!(
+                self.is_batch_full(chain_id, &tx_batch, batch.batch_id, first_batch_id)
+                    && self.is_batch_final(&tx_batch),
+                BATCH_NOT_READY_ERR_MSG
+            );
 
-            self.set_status_event(batch_id, tx.nonce, tx_status);
+            self.set_batch_status_for_chain(
+                chain_id,
+                batch.batch_id,
+                action_id,
+                &relayer,
+                batch.tx_statuses,
+            );
         }
-
-        self.clear_first_batch(&mut tx_batch);
     }
 
     /// Converts failed Ethereum -> Elrond transactions to Elrond -> Ethereum transaction.
@@ -153,7 +245,8 @@ pub trait EsdtSafe:
             original_tx_nonces.push(refund_tx.nonce);
         }
 
-        let batch_ids = self.add_multiple_tx_to_batch(&new_transactions);
+        // refunds always go back to Ethereum
+        let batch_ids = self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &new_transactions);
         for (i, tx) in new_transactions.iter().enumerate() {
             let batch_id = batch_ids.get(i);
             let original_tx_nonce = original_tx_nonces.get(i);
@@ -162,49 +255,274 @@ pub trait EsdtSafe:
         }
     }
 
+    /// Sets the accepted destination address format for the given chain.
+    /// Transactions towards chains without a rule are rejected.
+    #[endpoint(setAddressRule)]
+    fn set_address_rule(&self, chain_id: ChainId, min_len: u32, max_len: u32) {
+        self.require_caller_owner_or_admin();
+
+        let parameter_key = self.config_key(b"addressRule", &chain_id);
+        let old_value = self.config_value(&parameter_key);
+        self.set_chain_address_rule(chain_id, AddressRule { min_len, max_len });
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[endpoint(clearAddressRule)]
+    fn clear_address_rule(&self, chain_id: ChainId) {
+        self.require_caller_owner_or_admin();
+        let parameter_key = self.config_key(b"addressRule", &chain_id);
+        let old_value = self.config_value(&parameter_key);
+        self.address_rule(chain_id).clear();
+        self.address_rule_chains().swap_remove(&chain_id);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
     // endpoints
 
     /// Create an Elrond -> Ethereum transaction. Only fungible tokens are accepted.
     ///
+    /// The transaction is added to the batch stream of the destination chain,
+    /// which is Ethereum if no chain ID is provided.
+    ///
     /// Every transfer will have a part of the tokens subtracted as fees.
     /// The fee amount depends on the global eth_tx_gas_limit
     /// and the current GWEI price, respective to the bridged token
     ///
     /// fee_amount = price_per_gas_unit * eth_tx_gas_limit
+    ///
+    /// For tokens with the fee paid on top (see `setFeePaidOnTop`), the whole deposit is bridged instead,
+    /// and the fee is paid as a second payment of the same token. Any excess is sent back.
+    ///
+    /// The bridged (net) amount and the fee are emitted in a `transactionAmountsEvent`.
+    ///
+    /// Deposits above the token's max bridged amount are rejected, unless deposit splitting
+    /// is enabled for the token (see `setDepositSplitting`). In that case, the deposit is split
+    /// into as many transactions as needed to keep each of them under the max amount.
+    /// Each part pays its own fee.
     #[payable("*")]
     #[endpoint(createTransaction)]
-    fn create_transaction(&self, to: EthAddress<Self::Api>) {
-        require!(self.not_paused(), "Cannot create transaction while paused");
+    fn create_transaction(&self, to: ManagedBuffer, opt_chain_id: OptionalValue<ChainId>) {
+        let caller = self.blockchain().get_caller();
+        let _ = self.create_transaction_for(caller, to, BigUint::zero(), opt_chain_id);
+    }
+
+    /// Same as `createTransaction`, but `tip` tokens out of the payment are set aside
+    /// for the relayer who performs the action setting the transaction's status,
+    /// as an incentive to process its batch sooner. The rest is bridged as usual.
+    ///
+    /// The tip is paid when the transaction is executed, and refunded along with it if rejected.
+    /// For split deposits, the tip goes with the first transaction.
+    #[payable("*")]
+    #[endpoint(createTransactionWithRelayerTip)]
+    fn create_transaction_with_relayer_tip(
+        &self,
+        to: ManagedBuffer,
+        tip: BigUint,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let _ = self.create_transaction_for(caller, to, tip, opt_chain_id);
+    }
+
+    /// Same as `createTransaction`, but called by a trusted forwarder contract
+    /// on behalf of `original_sender`, who is recorded as the sender and receives any refund.
+    /// For split deposits, only the first transaction is reported as forwarded.
+    #[payable("*")]
+    #[endpoint(createTransactionOnBehalf)]
+    fn create_transaction_on_behalf(
+        &self,
+        original_sender: ManagedAddress,
+        to: ManagedBuffer,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
+        let forwarder = self.blockchain().get_caller();
+        self.require_trusted_forwarder(&forwarder);
+
+        let tx_nonce =
+            self.create_transaction_for(original_sender, to, BigUint::zero(), opt_chain_id);
+        self.forwarded_transaction_event(tx_nonce, &forwarder);
+    }
+
+    fn create_transaction_for(
+        &self,
+        sender: ManagedAddress,
+        to: ManagedBuffer,
+        relayer_tip: BigUint,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> u64 {
+        require!(self.not_paused(), CREATE_TX_WHILE_PAUSED_ERR_MSG);
+
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        self.require_valid_destination_address(chain_id, &to);
 
-        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
+        let (payment_token, total_payment_amount, fee_payment_amount) = self.get_deposit_payments();
         self.require_token_in_whitelist(&payment_token);
+        require!(
+            relayer_tip < total_payment_amount,
+            RELAYER_TIP_OVER_AMOUNT_ERR_MSG
+        );
+        let payment_amount = total_payment_amount - &relayer_tip;
 
+        let part_count = self.get_deposit_part_count(&payment_token, &payment_amount);
+        let part_count_biguint = BigUint::from(part_count);
+        let min_part_amount = &payment_amount / &part_count_biguint;
+        let parts_with_extra_unit = (&payment_amount % &part_count_biguint)
+            .to_u64()
+            .unwrap_or_default();
+
+        let fee_paid_on_top = self.fee_paid_on_top(&payment_token).get();
         let required_fee = self.calculate_required_fee(&payment_token);
+        if fee_paid_on_top {
+            self.take_fee_paid_on_top(
+                &payment_token,
+                fee_payment_amount,
+                &required_fee * &part_count_biguint,
+            );
+        } else {
+            require!(required_fee < min_part_amount, FEES_OVER_AMOUNT_ERR_MSG);
+        }
         require!(
-            required_fee < payment_amount,
-            "Transaction fees cost more than the entire bridged amount"
+            self.passes_deposit_screening(&sender, &to, &payment_token, &payment_amount),
+            DEPOSIT_REJECTED_BY_SCREENING_ERR_MSG
         );
+        self.require_batch_stream_not_stalled(chain_id);
 
-        self.require_below_max_amount(&payment_token, &payment_amount);
+        let mut origin_tx_nonce = 0;
+        for part_index in 0..part_count {
+            let mut part_amount = min_part_amount.clone();
+            if part_index < parts_with_extra_unit {
+                part_amount += 1u32;
+            }
+            if !fee_paid_on_top {
+                part_amount -= &required_fee;
+            }
 
-        self.accumulated_transaction_fees(&payment_token)
-            .update(|fees| *fees += &required_fee);
+            let tx_nonce = self.add_deposit_transaction(
+                chain_id,
+                &sender,
+                &to,
+                &payment_token,
+                part_amount,
+                &required_fee,
+            );
+            if part_index == 0 {
+                origin_tx_nonce = tx_nonce;
+            }
+            if part_count > 1 {
+                self.split_deposit_origin(tx_nonce).set(origin_tx_nonce);
+            }
+        }
+
+        if part_count > 1 {
+            self.deposit_split_event(origin_tx_nonce, part_count);
+        }
+        if relayer_tip > 0 {
+            self.total_pending_relayer_tips(&payment_token)
+                .update(|tips| *tips += &relayer_tip);
+            self.relayer_tip(origin_tx_nonce).set(&relayer_tip);
+            self.relayer_tip_added_event(origin_tx_nonce, &relayer_tip);
+        }
+
+        origin_tx_nonce
+    }
+
+    /// Adds a single transaction to the batch stream,
+    /// out of a deposit, or a part of a split deposit, bridging `bridged_amount` tokens.
+    fn add_deposit_transaction(
+        &self,
+        chain_id: ChainId,
+        sender: &ManagedAddress,
+        to: &ManagedBuffer,
+        token_id: &TokenIdentifier,
+        bridged_amount: BigUint,
+        required_fee: &BigUint,
+    ) -> u64 {
+        self.accumulated_transaction_fees(token_id)
+            .update(|fees| *fees += required_fee);
+
+        self.add_to_daily_usd_volume(token_id, &bridged_amount);
+        self.add_to_destination_epoch_volume(to, token_id, &bridged_amount);
+        self.total_pending_amount(token_id)
+            .update(|pending| *pending += &bridged_amount);
 
-        let actual_bridged_amount = payment_amount - required_fee;
-        let caller = self.blockchain().get_caller();
         let tx_nonce = self.get_and_save_next_tx_id();
+        self.transaction_fee(tx_nonce).set(required_fee);
         let tx = Transaction {
             block_nonce: self.blockchain().get_block_nonce(),
             nonce: tx_nonce,
-            from: caller.as_managed_buffer().clone(),
-            to: to.as_managed_buffer().clone(),
-            token_identifier: payment_token,
-            amount: actual_bridged_amount,
+            from: sender.as_managed_buffer().clone(),
+            to: to.clone(),
+            token_identifier: token_id.clone(),
+            amount: bridged_amount.clone(),
             is_refund_tx: false,
         };
 
-        let batch_id = self.add_to_batch(tx);
-        self.create_transaction_event(batch_id, tx_nonce);
+        let batch_id = self.add_to_batch(chain_id, tx);
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.create_transaction_event(batch_id, tx_nonce);
+        } else {
+            self.create_chain_transaction_event(chain_id, batch_id, tx_nonce);
+        }
+        self.transaction_amounts_event(tx_nonce, &bridged_amount, required_fee);
+
+        tx_nonce
+    }
+
+    /// The deposited token and amount, along with the amount of the fee payment, if any.
+    /// A fee payment is only accepted for tokens with the fee paid on top.
+    fn get_deposit_payments(&self) -> (TokenIdentifier, BigUint, BigUint) {
+        let payments = self.call_value().all_esdt_transfers();
+        if payments.len() != 2 {
+            let (token_id, amount) = self.call_value().single_fungible_esdt();
+            return (token_id, amount, BigUint::zero());
+        }
+
+        let deposit = payments.get(0);
+        let fee_payment = payments.get(1);
+        require!(
+            deposit.token_nonce == 0
+                && fee_payment.token_nonce == 0
+                && fee_payment.token_identifier == deposit.token_identifier
+                && self.fee_paid_on_top(&deposit.token_identifier).get(),
+            INVALID_FEE_PAYMENT_ERR_MSG
+        );
+
+        (deposit.token_identifier, deposit.amount, fee_payment.amount)
+    }
+
+    /// Keeps `total_fee` out of the fee payment, and sends the rest back to the caller.
+    fn take_fee_paid_on_top(
+        &self,
+        token_id: &TokenIdentifier,
+        fee_payment_amount: BigUint,
+        total_fee: BigUint,
+    ) {
+        require!(fee_payment_amount >= total_fee, FEE_PAYMENT_TOO_LOW_ERR_MSG);
+
+        let excess = fee_payment_amount - total_fee;
+        if excess > 0 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct_esdt(&caller, token_id, 0, &excess);
+        }
+    }
+
+    /// Deposits over the max bridged amount are split into equal parts, give or take a unit,
+    /// if splitting is enabled for the token. Otherwise, they are rejected.
+    fn get_deposit_part_count(&self, token_id: &TokenIdentifier, amount: &BigUint) -> u64 {
+        if !self.is_above_max_amount(token_id, amount) {
+            return 1;
+        }
+
+        require!(
+            self.deposit_splitting_enabled(token_id).get(),
+            DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG
+        );
+
+        let max_amount = self.max_bridged_amount(token_id).get();
+        let part_count = (amount + &max_amount - 1u32) / max_amount;
+        part_count
+            .to_u64()
+            .unwrap_or_else(|| sc_panic!(DEPOSIT_OVER_MAX_AMOUNT_ERR_MSG))
     }
 
     /// Claim funds for failed Elrond -> Ethereum transactions.
@@ -213,16 +531,202 @@ pub trait EsdtSafe:
     #[endpoint(claimRefund)]
     fn claim_refund(&self, token_id: TokenIdentifier) -> EsdtTokenPayment<Self::Api> {
         let caller = self.blockchain().get_caller();
-        let refund_amount = self.refund_amount(&caller, &token_id).get();
-        require!(refund_amount > 0, "Nothing to refund");
-
-        self.refund_amount(&caller, &token_id).clear();
-        self.send()
-            .direct_esdt(&caller, &token_id, 0, &refund_amount);
+        let refund_amount = self.pay_refund(&caller, &token_id);
 
         EsdtTokenPayment::new(token_id, 0, refund_amount)
     }
 
+    /// Claims the refund of the given address on its behalf. The refund is still sent to its owner,
+    /// while the caller is paid the token's claim bounty out of the accumulated fees,
+    /// so keepers can deliver refunds that are too small to be worth claiming.
+    ///
+    /// Returns the bounty paid to the caller.
+    #[endpoint(claimRefundFor)]
+    fn claim_refund_for(
+        &self,
+        address: ManagedAddress,
+        token_id: TokenIdentifier,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let bounty = self.refund_claim_bounty(&token_id).get();
+        require!(bounty > 0, RELAYED_REFUND_CLAIMS_DISABLED_ERR_MSG);
+
+        let accumulated_fees_mapper = self.accumulated_transaction_fees(&token_id);
+        require!(
+            accumulated_fees_mapper.get() >= bounty,
+            NOT_ENOUGH_FEES_FOR_CLAIM_BOUNTY_ERR_MSG
+        );
+        accumulated_fees_mapper.update(|accumulated_fees| *accumulated_fees -= &bounty);
+
+        let refund_amount = self.pay_refund(&address, &token_id);
+        let caller = self.blockchain().get_caller();
+        self.send().direct_esdt(&caller, &token_id, 0, &bounty);
+        self.refund_claimed_for_event(&address, &token_id, &refund_amount, &caller, &bounty);
+
+        EsdtTokenPayment::new(token_id, 0, bounty)
+    }
+
+    /// Bounty paid out of the accumulated fees of the token for each refund claimed through `claimRefundFor`.
+    /// 0 disables relayed claims for the token.
+    #[only_owner]
+    #[endpoint(setRefundClaimBounty)]
+    fn set_refund_claim_bounty(&self, token_id: TokenIdentifier, bounty: BigUint) {
+        let parameter_key = self.config_key(b"refundClaimBounty", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.refund_claim_bounty(&token_id).set(bounty);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// If enabled, deposits of the token above its max bridged amount are split
+    /// into several transactions instead of being rejected.
+    #[only_owner]
+    #[endpoint(setDepositSplitting)]
+    fn set_deposit_splitting(&self, token_id: TokenIdentifier, enabled: bool) {
+        let parameter_key = self.config_key(b"depositSplittingEnabled", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.deposit_splitting_enabled(&token_id).set(enabled);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// If enabled, deposits of the token are bridged in full,
+    /// and the fee is paid on top, as a second payment of the same token.
+    /// Otherwise, the fee is deducted from the deposit.
+    #[only_owner]
+    #[endpoint(setFeePaidOnTop)]
+    fn set_fee_paid_on_top(&self, token_id: TokenIdentifier, enabled: bool) {
+        let parameter_key = self.config_key(b"feePaidOnTop", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.fee_paid_on_top(&token_id).set(enabled);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Refunds left unclaimed for longer than this period (in seconds) expire,
+    /// and can no longer be claimed. 0 means refunds never expire.
+    #[only_owner]
+    #[endpoint(setRefundExpiryPeriod)]
+    fn set_refund_expiry_period(&self, period: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"refundExpiryPeriod");
+        let old_value = self.config_value(&parameter_key);
+        self.refund_expiry_period().set(period);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Once this many batches are waiting for their statuses, no new batches are created,
+    /// so new transactions are rejected until the relayers catch up. 0 means no limit.
+    #[only_owner]
+    #[endpoint(setMaxPendingBatches)]
+    fn set_max_pending_batches(&self, max_pending_batches: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"maxPendingBatches");
+        let old_value = self.config_value(&parameter_key);
+        self.max_pending_batches().set(max_pending_batches);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// If set, the fee of a rejected transaction is returned to the user, along with the bridged amount.
+    #[only_owner]
+    #[endpoint(setRefundFeesOnRejection)]
+    fn set_refund_fees_on_rejection(&self, refund_fees: bool) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"refundFeesOnRejection");
+        let old_value = self.config_value(&parameter_key);
+        self.refund_fees_on_rejection().set(refund_fees);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Moves expired refunds out of the users' claimable amounts,
+    /// into the expired refunds of each token, which can then be swept by the owner.
+    /// Can be called by anyone.
+    #[endpoint(expireRefunds)]
+    fn expire_refunds(
+        &self,
+        refunds: MultiValueEncoded<MultiValue2<ManagedAddress, TokenIdentifier>>,
+    ) {
+        for refund in refunds {
+            let (address, token_id) = refund.into_tuple();
+            require!(
+                self.is_refund_expired(&address, &token_id),
+                REFUND_NOT_EXPIRED_ERR_MSG
+            );
+
+            let amount = self.refund_amount(&address, &token_id).take();
+            self.refund_timestamp(&address, &token_id).clear();
+            self.decrease_tracked_amount(self.total_unclaimed_refunds(&token_id), &amount);
+            self.expired_refunds(&token_id)
+                .update(|expired| *expired += &amount);
+
+            self.refund_expired_event(&address, &token_id, &amount);
+        }
+    }
+
+    /// Closes the last batch of the given chain's stream if its block duration elapsed,
+    /// without waiting for a new deposit to do it. Can be called by anyone.
+    ///
+    /// Returns the ID of the closed batch, if any.
+    #[endpoint(progressBatches)]
+    fn progress_batches(&self, opt_chain_id: OptionalValue<ChainId>) -> OptionalValue<u64> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let opt_closed_batch_id = self.close_expired_batch(chain_id);
+        if let Some(batch_id) = opt_closed_batch_id {
+            self.batch_closed_event(chain_id, batch_id);
+        }
+
+        opt_closed_batch_id.into()
+    }
+
+    /// Sends all the expired refunds of the given token to the treasury.
+    #[only_owner]
+    #[endpoint(sweepExpiredRefunds)]
+    fn sweep_expired_refunds(&self, token_id: TokenIdentifier, treasury: ManagedAddress) {
+        let amount = self.expired_refunds(&token_id).take();
+        require!(amount > 0, NO_EXPIRED_REFUNDS_ERR_MSG);
+
+        self.send().direct_esdt(&treasury, &token_id, 0, &amount);
+        self.expired_refunds_swept_event(&token_id, &treasury, &amount);
+    }
+
+    /// Switches the token between lock mode, where the tokens of executed transfers are kept,
+    /// and burn mode (the default), where they are burned.
+    /// When leaving lock mode, the locked tokens are sent to the custodian, or burned if none is given.
+    ///
+    /// Called by the multisig's `MigrateTokenMode` action, along with MultiTransferEsdt's `migrateTokenMode`.
+    #[only_owner]
+    #[endpoint(migrateTokenMode)]
+    fn migrate_token_mode(
+        &self,
+        token_id: TokenIdentifier,
+        lock_mode: bool,
+        opt_custodian: OptionalValue<ManagedAddress>,
+    ) {
+        require!(
+            self.lock_mode(&token_id).get() != lock_mode,
+            LOCK_MODE_UNCHANGED_ERR_MSG
+        );
+
+        let parameter_key = self.config_key(b"lockMode", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.lock_mode(&token_id).set(lock_mode);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+
+        if lock_mode {
+            return;
+        }
+
+        let locked_amount = self.total_locked(&token_id).take();
+        if locked_amount == 0 {
+            return;
+        }
+
+        match opt_custodian {
+            OptionalValue::Some(custodian) => {
+                self.send()
+                    .direct_esdt(&custodian, &token_id, 0, &locked_amount);
+                self.locked_balance_sent_event(&token_id, &custodian, &locked_amount);
+            }
+            OptionalValue::None => {
+                self.burn_esdt_token(&token_id, &locked_amount);
+                self.locked_balance_burned_event(&token_id, &locked_amount);
+            }
+        }
+    }
+
     /// Query function that lists all refund amounts for a user.
     /// Useful for knowing which token IDs to pass to the claimRefund endpoint.
     #[view(getRefundAmounts)]
@@ -241,15 +745,437 @@ pub trait EsdtSafe:
         refund_amounts
     }
 
+    /// Lists the user's transactions that were not processed yet,
+    /// from the batches of the given destination chain (Ethereum by default).
+    /// The result format is the same as for the transactions in `getBatch`.
+    #[view(getPendingTransactions)]
+    #[label("queries")]
+    fn get_pending_transactions(
+        &self,
+        address: ManagedAddress,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValueEncoded<TxAsMultiValue<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        let last_batch_id = self.last_batch_id_mapper(chain_id).get();
+        let sender = address.as_managed_buffer();
+
+        let mut pending_transactions = MultiValueEncoded::new();
+        for batch_id in first_batch_id..=last_batch_id {
+            for tx in self.pending_batches_mapper(chain_id, batch_id).iter() {
+                if !tx.is_refund_tx && &tx.from == sender {
+                    pending_transactions.push(tx.into_multiresult());
+                }
+            }
+        }
+
+        pending_transactions
+    }
+
+    /// Solvency check for the given token: compares the tokens held by the contract
+    /// with the amounts it owes, and also returns the total amount burned for executed transfers.
+    /// Also part of the minimal build, as the multisig snapshots it when pausing the EsdtSafe.
+    #[view(getTokenReconciliation)]
+    fn get_token_reconciliation(
+        &self,
+        token_id: TokenIdentifier,
+    ) -> TokenReconciliation<Self::Api> {
+        let locked_balance = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0);
+        let pending_amount = self.total_pending_amount(&token_id).get();
+        let unclaimed_refunds = self.total_unclaimed_refunds(&token_id).get();
+        let expired_refunds = self.expired_refunds(&token_id).get();
+        let accumulated_fees = self.accumulated_transaction_fees(&token_id).get();
+        let pending_relayer_tips = self.total_pending_relayer_tips(&token_id).get();
+        let is_balanced = locked_balance
+            == &(&pending_amount + &unclaimed_refunds)
+                + &(&expired_refunds + &accumulated_fees)
+                + &(&pending_relayer_tips + &self.total_locked(&token_id).get());
+
+        TokenReconciliation {
+            locked_balance,
+            pending_amount,
+            unclaimed_refunds,
+            expired_refunds,
+            accumulated_fees,
+            pending_relayer_tips,
+            total_burned: self.total_burned(&token_id).get(),
+            is_balanced,
+        }
+    }
+
+    /// Returns the settlement receipt of a transaction, i.e. the status it ended up with,
+    /// the block in which the status was set, and the ID of the multisig action that set it.
+    /// Returns nothing if the transaction's batch was not processed yet.
+    #[view(getSettlementReceipt)]
+    #[label("queries")]
+    fn get_settlement_receipt(
+        &self,
+        batch_id: u64,
+        tx_nonce: u64,
+    ) -> OptionalValue<SettlementReceipt> {
+        let receipt_mapper = self.settlement_receipt(batch_id, tx_nonce);
+        if receipt_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(receipt_mapper.get())
+        }
+    }
+
+    /// The statuses recorded for the processed batches of the given chain (Ethereum by default),
+    /// from `first_batch_id` to `last_batch_id` inclusive, at most 100 batches per query.
+    /// Batches that were not processed yet, or that were processed before the history was kept, are skipped.
+    #[view(getBatchStatusesHistory)]
+    #[label("queries")]
+    fn get_batch_statuses_history(
+        &self,
+        first_batch_id: u64,
+        last_batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValueEncoded<BatchStatusesRecord<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let mut records = MultiValueEncoded::new();
+        if first_batch_id > last_batch_id {
+            return records;
+        }
+
+        require!(
+            last_batch_id - first_batch_id < MAX_STATUSES_HISTORY_PAGE_SIZE,
+            PAGE_SIZE_TOO_LARGE_ERR_MSG
+        );
+
+        for batch_id in first_batch_id..=last_batch_id {
+            let history_mapper = self.batch_statuses_history(chain_id, batch_id);
+            if history_mapper.is_empty() {
+                continue;
+            }
+
+            records.push(BatchStatusesRecord {
+                batch_id,
+                transactions: history_mapper.get(),
+            });
+        }
+
+        records
+    }
+
+    /// Canonical hash of a pending batch: the EIP-712 `hashStruct` of the batch,
+    /// the same as computed on the Ethereum side. Requires the bridge domain to be set.
+    /// The Multisig's `computeBatchHash` view returns the same value.
+    #[view(computeBatchHash)]
+    #[label("queries")]
+    fn compute_batch_hash(
+        &self,
+        batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> Eip712Hash<Self::Api> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let tx_batch = self.pending_batches_mapper(chain_id, batch_id);
+        require!(!tx_batch.is_empty(), BATCH_DOES_NOT_EXIST_ERR_MSG);
+
+        let mut transactions = ManagedVec::new();
+        for tx in tx_batch.iter() {
+            transactions.push(tx);
+        }
+
+        hash_batch(
+            &self.get_configured_bridge_domain(),
+            batch_id,
+            &transactions,
+        )
+    }
+
+    /// The current batch, as returned by `getCurrentTxBatch`, along with the digest relayers sign
+    /// for the Ethereum side. Its `batch_root` is the same as `computeBatchHash`, and the digest
+    /// is its EIP-712 digest in the domain set through `setEvmDomain`.
+    /// Requires both the bridge domain and the EVM domain to be set.
+    #[view(getCurrentBatchAttestation)]
+    #[label("queries")]
+    fn get_current_batch_attestation(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<BatchAttestation<Self::Api>> {
+        let chain_id = self.chain_id_or_default(opt_chain_id);
+        let batch_id = match self.get_current_tx_batch_id(chain_id) {
+            Some(batch_id) => batch_id,
+            None => return OptionalValue::None,
+        };
+
+        let mut transactions = ManagedVec::new();
+        for tx in self.pending_batches_mapper(chain_id, batch_id).iter() {
+            transactions.push(tx);
+        }
+
+        OptionalValue::Some(attest_batch(
+            &self.get_configured_evm_domain(),
+            &self.get_configured_bridge_domain(),
+            batch_id,
+            &transactions,
+        ))
+    }
+
+    /// The deployment parameters currently in effect, including later changes through the setters.
+    /// `paused` is the current pause status.
+    #[view(getDeploymentConfig)]
+    #[label("queries")]
+    fn get_deployment_config(&self) -> DeploymentConfig<Self::Api> {
+        let mut address_rules = ManagedVec::new();
+        for chain_id in self.address_rule_chains().iter() {
+            address_rules.push(ChainAddressRule {
+                chain_id,
+                rule: self.address_rule(chain_id).get(),
+            });
+        }
+
+        DeploymentConfig {
+            batch: self.get_batch_config(),
+            max_pending_batches: self.max_pending_batches().get(),
+            fallback_price_per_gas_unit: self.fallback_price_per_gas_unit().get(),
+            address_rules,
+            paused: self.is_paused(),
+        }
+    }
+
     // private
 
+    fn apply_deployment_config(&self, config: DeploymentConfig<Self::Api>) {
+        self.set_batch_config(&config.batch);
+        self.max_pending_batches().set(config.max_pending_batches);
+        self.fallback_price_per_gas_unit()
+            .set(&config.fallback_price_per_gas_unit);
+        for chain_address_rule in config.address_rules.iter() {
+            self.set_chain_address_rule(chain_address_rule.chain_id, chain_address_rule.rule);
+        }
+        self.set_paused(config.paused);
+    }
+
+    fn apply_default_deployment_config(&self) {
+        self.max_tx_batch_size()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
+        self.max_tx_batch_block_duration()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+
+        self.address_rule(ETHEREUM_CHAIN_ID)
+            .set_if_empty(AddressRule::fixed_len(ETH_ADDRESS_LEN as u32));
+        self.address_rule_chains().insert(ETHEREUM_CHAIN_ID);
+
+        self.set_paused(true);
+    }
+
+    fn set_chain_address_rule(&self, chain_id: ChainId, rule: AddressRule) {
+        require!(
+            rule.min_len > 0 && rule.min_len <= rule.max_len,
+            INVALID_ADDRESS_LENGTH_BOUNDS_ERR_MSG
+        );
+
+        self.address_rule(chain_id).set(rule);
+        self.address_rule_chains().insert(chain_id);
+        self.init_batch_stream(chain_id);
+    }
+
+    fn set_batch_status_for_chain(
+        &self,
+        chain_id: ChainId,
+        batch_id: u64,
+        action_id: usize,
+        relayer: &ManagedAddress,
+        tx_statuses: ManagedVec<TransactionStatus>,
+    ) {
+        let first_batch_id = self.first_batch_id_mapper(chain_id).get();
+        require!(batch_id == first_batch_id, BATCHES_OUT_OF_ORDER_ERR_MSG);
+
+        let mut tx_batch = self.pending_batches_mapper(chain_id, batch_id);
+        require!(
+            tx_batch.len() == tx_statuses.len(),
+            INVALID_NUMBER_OF_STATUSES_ERR_MSG
+        );
+
+        let block_nonce = self.blockchain().get_block_nonce();
+        let mut settled_transactions = ManagedVec::new();
+        for (tx, tx_status) in tx_batch.iter().zip(tx_statuses.iter()) {
+            self.settlement_receipt(batch_id, tx.nonce)
+                .set(SettlementReceipt {
+                    tx_status,
+                    block_nonce,
+                    action_id,
+                });
+            settled_transactions.push(SettledTransaction {
+                tx_nonce: tx.nonce,
+                tx_status,
+            });
+
+            // Since tokens don't exist in the EsdtSafe in the case of a refund transaction
+            // we have no tokens to burn, nor to refund
+            if tx.is_refund_tx {
+                continue;
+            }
+
+            self.decrease_tracked_amount(
+                self.total_pending_amount(&tx.token_identifier),
+                &tx.amount,
+            );
+            let fee = self.transaction_fee(tx.nonce).take();
+            let relayer_tip = self.take_relayer_tip(&tx.token_identifier, tx.nonce);
+
+            match tx_status {
+                TransactionStatus::Executed => {
+                    // tokens in lock mode are kept, see `migrateTokenMode`
+                    //
+                    // local burn role might be removed while tx is executed
+                    // tokens will remain locked forever in that case
+                    // otherwise, the whole batch would fail
+                    if self.lock_mode(&tx.token_identifier).get() {
+                        self.total_locked(&tx.token_identifier)
+                            .update(|locked| *locked += &tx.amount);
+                    } else if self.is_local_role_set(&tx.token_identifier, &EsdtLocalRole::Burn) {
+                        self.burn_esdt_token(&tx.token_identifier, &tx.amount);
+                    }
+
+                    if relayer_tip > 0 {
+                        self.send()
+                            .direct_esdt(relayer, &tx.token_identifier, 0, &relayer_tip);
+                        self.relayer_tip_paid_event(batch_id, tx.nonce, relayer, &relayer_tip);
+                    }
+                }
+                TransactionStatus::Rejected => {
+                    let addr = ManagedAddress::try_from(tx.from).unwrap();
+                    let mut refund_amount = tx.amount + relayer_tip;
+                    if self.refund_fees_on_rejection().get() {
+                        refund_amount += self.take_fee_for_refund(&tx.token_identifier, &fee);
+                    }
+
+                    self.mark_refund(&addr, &tx.token_identifier, &refund_amount);
+                }
+                _ => {
+                    sc_panic!(INVALID_TX_STATUS_ERR_MSG);
+                }
+            }
+
+            if chain_id == ETHEREUM_CHAIN_ID {
+                self.set_status_event(batch_id, tx.nonce, tx_status);
+            } else {
+                self.set_chain_status_event(chain_id, batch_id, tx.nonce, tx_status);
+            }
+        }
+
+        self.batch_statuses_history(chain_id, batch_id)
+            .set(settled_transactions);
+        self.clear_first_batch(chain_id, &mut tx_batch);
+    }
+
+    fn require_valid_destination_address(&self, chain_id: ChainId, to: &ManagedBuffer) {
+        let address_rule_mapper = self.address_rule(chain_id);
+        require!(
+            !address_rule_mapper.is_empty(),
+            DESTINATION_CHAIN_NOT_SUPPORTED_ERR_MSG
+        );
+        require!(
+            address_rule_mapper.get().is_valid(to),
+            INVALID_DESTINATION_ADDRESS_ERR_MSG
+        );
+    }
+
+    /// Transactions may still be added to the last batch, as long as it is not full.
+    fn require_batch_stream_not_stalled(&self, chain_id: ChainId) {
+        let max_pending_batches = self.max_pending_batches().get();
+        if max_pending_batches == 0 {
+            return;
+        }
+
+        let (last_created_batch_id, last_settled_batch_id, first_batch_id) = self
+            .get_batch_watermarks(OptionalValue::Some(chain_id))
+            .into_tuple();
+        if last_created_batch_id - last_settled_batch_id < max_pending_batches {
+            return;
+        }
+
+        let last_batch = self.pending_batches_mapper(chain_id, last_created_batch_id);
+        require!(
+            !self.is_batch_full(chain_id, &last_batch, last_created_batch_id, first_batch_id),
+            TOO_MANY_PENDING_BATCHES_ERR_MSG
+        );
+    }
+
     fn burn_esdt_token(&self, token_id: &TokenIdentifier, amount: &BigUint) {
         self.send().esdt_local_burn(token_id, 0, amount);
+        self.total_burned(token_id)
+            .update(|burned| *burned += amount);
+    }
+
+    /// Amounts locked before the tracking was introduced were never added,
+    /// so the totals saturate at zero instead of failing the whole call.
+    fn decrease_tracked_amount(&self, mapper: SingleValueMapper<BigUint>, amount: &BigUint) {
+        mapper.update(|total| {
+            if &*total > amount {
+                *total -= amount;
+            } else {
+                *total = BigUint::zero();
+            }
+        });
+    }
+
+    fn take_relayer_tip(&self, token_id: &TokenIdentifier, tx_nonce: u64) -> BigUint {
+        let relayer_tip = self.relayer_tip(tx_nonce).take();
+        if relayer_tip > 0 {
+            self.decrease_tracked_amount(self.total_pending_relayer_tips(token_id), &relayer_tip);
+        }
+
+        relayer_tip
+    }
+
+    /// Fees might have been distributed in the meantime,
+    /// in which case only what is left of the accumulated fees is returned.
+    fn take_fee_for_refund(&self, token_id: &TokenIdentifier, fee: &BigUint) -> BigUint {
+        let mut refunded_fee = BigUint::zero();
+        self.accumulated_transaction_fees(token_id)
+            .update(|accumulated_fees| {
+                refunded_fee = core::cmp::min(&*accumulated_fees, fee).clone();
+                *accumulated_fees -= &refunded_fee;
+            });
+
+        refunded_fee
+    }
+
+    fn pay_refund(&self, address: &ManagedAddress, token_id: &TokenIdentifier) -> BigUint {
+        let refund_amount = self.refund_amount(address, token_id).get();
+        require!(refund_amount > 0, NOTHING_TO_REFUND_ERR_MSG);
+        require!(
+            !self.is_refund_expired(address, token_id),
+            REFUND_EXPIRED_ERR_MSG
+        );
+
+        self.refund_amount(address, token_id).clear();
+        self.refund_timestamp(address, token_id).clear();
+        self.decrease_tracked_amount(self.total_unclaimed_refunds(token_id), &refund_amount);
+        self.send()
+            .direct_esdt(address, token_id, 0, &refund_amount);
+
+        refund_amount
     }
 
+    /// Refunds credited before the expiry was introduced have no timestamp, and never expire.
+    fn is_refund_expired(&self, address: &ManagedAddress, token_id: &TokenIdentifier) -> bool {
+        let expiry_period = self.refund_expiry_period().get();
+        let refund_timestamp = self.refund_timestamp(address, token_id).get();
+        if expiry_period == 0
+            || refund_timestamp == 0
+            || self.refund_amount(address, token_id).is_empty()
+        {
+            return false;
+        }
+
+        self.blockchain().get_block_timestamp() >= refund_timestamp + expiry_period
+    }
+
+    /// A new refund for the same token restarts the expiry period of the whole amount.
     fn mark_refund(&self, to: &ManagedAddress, token_id: &TokenIdentifier, amount: &BigUint) {
         self.refund_amount(to, token_id)
             .update(|refund| *refund += amount);
+        self.refund_timestamp(to, token_id)
+            .set(self.blockchain().get_block_timestamp());
+        self.total_unclaimed_refunds(token_id)
+            .update(|refunds| *refunds += amount);
     }
 
     // events
@@ -257,6 +1183,45 @@ pub trait EsdtSafe:
     #[event("createTransactionEvent")]
     fn create_transaction_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
 
+    #[event("createChainTransactionEvent")]
+    fn create_chain_transaction_event(
+        &self,
+        #[indexed] chain_id: ChainId,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+    );
+
+    /// Emitted right after the create transaction event, with the bridged (net) amount and the fee.
+    #[event("transactionAmountsEvent")]
+    fn transaction_amounts_event(
+        &self,
+        #[indexed] tx_id: u64,
+        #[indexed] bridged_amount: &BigUint,
+        #[indexed] fee: &BigUint,
+    );
+
+    #[event("depositSplitEvent")]
+    fn deposit_split_event(&self, #[indexed] origin_tx_id: u64, #[indexed] part_count: u64);
+
+    #[event("relayerTipAddedEvent")]
+    fn relayer_tip_added_event(&self, #[indexed] tx_id: u64, #[indexed] tip: &BigUint);
+
+    #[event("relayerTipPaidEvent")]
+    fn relayer_tip_paid_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] relayer: &ManagedAddress,
+        #[indexed] tip: &BigUint,
+    );
+
+    #[event("forwardedTransactionEvent")]
+    fn forwarded_transaction_event(
+        &self,
+        #[indexed] tx_id: u64,
+        #[indexed] forwarder: &ManagedAddress,
+    );
+
     #[event("addRefundTransactionEvent")]
     fn add_refund_transaction_event(
         &self,
@@ -265,6 +1230,50 @@ pub trait EsdtSafe:
         #[indexed] original_tx_id: u64,
     );
 
+    #[event("refundExpiredEvent")]
+    fn refund_expired_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("expiredRefundsSweptEvent")]
+    fn expired_refunds_swept_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] treasury: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("lockedBalanceSentEvent")]
+    fn locked_balance_sent_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] custodian: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("lockedBalanceBurnedEvent")]
+    fn locked_balance_burned_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("refundClaimedForEvent")]
+    fn refund_claimed_for_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] bounty: &BigUint,
+    );
+
+    #[event("batchClosedEvent")]
+    fn batch_closed_event(&self, #[indexed] chain_id: ChainId, #[indexed] batch_id: u64);
+
     #[event("setStatusEvent")]
     fn set_status_event(
         &self,
@@ -273,12 +1282,114 @@ pub trait EsdtSafe:
         #[indexed] tx_status: TransactionStatus,
     );
 
+    #[event("setChainStatusEvent")]
+    fn set_chain_status_event(
+        &self,
+        #[indexed] chain_id: ChainId,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] tx_status: TransactionStatus,
+    );
+
     // storage
 
+    #[view(getAddressRule)]
+    #[storage_mapper("addressRule")]
+    fn address_rule(&self, chain_id: ChainId) -> SingleValueMapper<AddressRule>;
+
+    #[storage_mapper("addressRuleChains")]
+    fn address_rule_chains(&self) -> UnorderedSetMapper<ChainId>;
+
     #[storage_mapper("refundAmount")]
     fn refund_amount(
         &self,
         address: &ManagedAddress,
         token_id: &TokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxPendingBatches)]
+    #[storage_mapper("maxPendingBatches")]
+    fn max_pending_batches(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("transactionFee")]
+    fn transaction_fee(&self, tx_nonce: u64) -> SingleValueMapper<BigUint>;
+
+    /// Tip set aside for the relayer settling the transaction, see `createTransactionWithRelayerTip`.
+    /// Cleared once the transaction's status is set.
+    #[view(getRelayerTip)]
+    #[storage_mapper("relayerTip")]
+    fn relayer_tip(&self, tx_nonce: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalPendingRelayerTips")]
+    fn total_pending_relayer_tips(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getRefundFeesOnRejection)]
+    #[storage_mapper("refundFeesOnRejection")]
+    fn refund_fees_on_rejection(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("refundTimestamp")]
+    fn refund_timestamp(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<u64>;
+
+    #[view(getRefundExpiryPeriod)]
+    #[storage_mapper("refundExpiryPeriod")]
+    fn refund_expiry_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getExpiredRefunds)]
+    #[storage_mapper("expiredRefunds")]
+    fn expired_refunds(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(isDepositSplittingEnabled)]
+    #[storage_mapper("depositSplittingEnabled")]
+    fn deposit_splitting_enabled(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    #[view(isFeePaidOnTop)]
+    #[storage_mapper("feePaidOnTop")]
+    fn fee_paid_on_top(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// For transactions created by splitting a deposit, the ID of the first of them.
+    /// 0 for transactions that are not part of a split deposit.
+    #[view(getSplitDepositOrigin)]
+    #[storage_mapper("splitDepositOrigin")]
+    fn split_deposit_origin(&self, tx_nonce: u64) -> SingleValueMapper<u64>;
+
+    #[view(getRefundClaimBounty)]
+    #[storage_mapper("refundClaimBounty")]
+    fn refund_claim_bounty(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("batchStatusesHistory")]
+    fn batch_statuses_history(
+        &self,
+        chain_id: ChainId,
+        batch_id: u64,
+    ) -> SingleValueMapper<ManagedVec<SettledTransaction>>;
+
+    #[storage_mapper("settlementReceipt")]
+    fn settlement_receipt(
+        &self,
+        batch_id: u64,
+        tx_nonce: u64,
+    ) -> SingleValueMapper<SettlementReceipt>;
+
+    #[storage_mapper("totalPendingAmount")]
+    fn total_pending_amount(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalUnclaimedRefunds")]
+    fn total_unclaimed_refunds(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalBurned)]
+    #[storage_mapper("totalBurned")]
+    fn total_burned(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(isLockMode)]
+    #[storage_mapper("lockMode")]
+    fn lock_mode(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Tokens kept for executed transfers while in lock mode.
+    #[view(getTotalLocked)]
+    #[storage_mapper("totalLocked")]
+    fn total_locked(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 }
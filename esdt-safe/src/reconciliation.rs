@@ -0,0 +1,20 @@
+multiversx_sc::derive_imports!();
+multiversx_sc::imports!();
+
+/// Accounting of a single token in the EsdtSafe.
+///
+/// The locked balance should always be equal to the sum of the amounts
+/// still owed by the contract: pending transfers, unclaimed refunds,
+/// expired refunds not yet swept, undistributed fees and the relayer tips of pending transfers,
+/// plus the tokens kept for executed transfers in lock mode (see `getTotalLocked`).
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct TokenReconciliation<M: ManagedTypeApi> {
+    pub locked_balance: BigUint<M>,
+    pub pending_amount: BigUint<M>,
+    pub unclaimed_refunds: BigUint<M>,
+    pub expired_refunds: BigUint<M>,
+    pub accumulated_fees: BigUint<M>,
+    pub pending_relayer_tips: BigUint<M>,
+    pub total_burned: BigUint<M>,
+    pub is_balanced: bool,
+}
@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use transaction::transaction_status::TransactionStatus;
+
+/// Final outcome of an Elrond -> Ethereum transaction,
+/// stored when the statuses are set for its batch.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct SettlementReceipt {
+    pub tx_status: TransactionStatus,
+    pub block_nonce: u64,
+    /// ID of the multisig action that set the status, 0 if set directly by the owner
+    pub action_id: usize,
+}
+
+/// Entry of the statuses history of a batch.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct SettledTransaction {
+    pub tx_nonce: u64,
+    pub tx_status: TransactionStatus,
+}
+
+/// Statuses recorded for a batch, in the order of its transactions.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct BatchStatusesRecord<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub transactions: ManagedVec<M, SettledTransaction>,
+}
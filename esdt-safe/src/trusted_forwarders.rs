@@ -0,0 +1,48 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{FORWARDER_NOT_SC_ERR_MSG, NOT_TRUSTED_FORWARDER_ERR_MSG};
+use config_events_module::NO_ACTION_ID;
+
+/// Contracts (e.g. DEX aggregators) allowed to create transactions on behalf of users,
+/// through `createTransactionOnBehalf`. Refunds go to the original user, not to the forwarder.
+#[multiversx_sc::module]
+pub trait TrustedForwardersModule: config_events_module::ConfigEventsModule {
+    #[only_owner]
+    #[endpoint(addTrustedForwarder)]
+    fn add_trusted_forwarder(&self, forwarder: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&forwarder),
+            FORWARDER_NOT_SC_ERR_MSG
+        );
+
+        let parameter_key = self.trusted_forwarder_config_key(&forwarder);
+        let old_value = self.config_value(&parameter_key);
+        let _ = self.trusted_forwarders().insert(forwarder);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[only_owner]
+    #[endpoint(removeTrustedForwarder)]
+    fn remove_trusted_forwarder(&self, forwarder: ManagedAddress) {
+        let parameter_key = self.trusted_forwarder_config_key(&forwarder);
+        let old_value = self.config_value(&parameter_key);
+        let _ = self.trusted_forwarders().swap_remove(&forwarder);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Key of the forwarder's index in the set, which is empty while the forwarder is not trusted.
+    fn trusted_forwarder_config_key(&self, forwarder: &ManagedAddress) -> ManagedBuffer {
+        self.config_key(b"trustedForwarders.index", forwarder)
+    }
+
+    fn require_trusted_forwarder(&self, address: &ManagedAddress) {
+        require!(
+            self.trusted_forwarders().contains(address),
+            NOT_TRUSTED_FORWARDER_ERR_MSG
+        );
+    }
+
+    #[view(getTrustedForwarders)]
+    #[storage_mapper("trustedForwarders")]
+    fn trusted_forwarders(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -1,8 +1,23 @@
+#[test]
+fn chain_batches_ethereum_storage_go() {
+    multiversx_sc_scenario::run_go("mandos/chain_batches_ethereum_storage.scen.json");
+}
+
+#[test]
+fn chain_batches_independent_go() {
+    multiversx_sc_scenario::run_go("mandos/chain_batches_independent.scen.json");
+}
+
 #[test]
 fn claim_fees_go() {
     multiversx_sc_scenario::run_go("mandos/distribute_fees.scen.json");
 }
 
+#[test]
+fn config_changed_go() {
+    multiversx_sc_scenario::run_go("mandos/config_changed.scen.json");
+}
+
 #[test]
 fn create_another_tx_ok_go() {
     multiversx_sc_scenario::run_go("mandos/create_another_tx_ok.scen.json");
@@ -13,11 +28,41 @@ fn create_another_tx_too_late_for_batch_go() {
     multiversx_sc_scenario::run_go("mandos/create_another_tx_too_late_for_batch.scen.json");
 }
 
+#[test]
+fn create_transaction_invalid_address_go() {
+    multiversx_sc_scenario::run_go("mandos/create_transaction_invalid_address.scen.json");
+}
+
 #[test]
 fn create_transaction_ok_go() {
     multiversx_sc_scenario::run_go("mandos/create_transaction_ok.scen.json");
 }
 
+#[test]
+fn daily_usd_limit_go() {
+    multiversx_sc_scenario::run_go("mandos/daily_usd_limit.scen.json");
+}
+
+#[test]
+fn deployment_config_go() {
+    multiversx_sc_scenario::run_go("mandos/deployment_config.scen.json");
+}
+
+#[test]
+fn deposit_hold_period_go() {
+    multiversx_sc_scenario::run_go("mandos/deposit_hold_period.scen.json");
+}
+
+#[test]
+fn deposit_splitting_go() {
+    multiversx_sc_scenario::run_go("mandos/deposit_splitting.scen.json");
+}
+
+#[test]
+fn destination_epoch_limit_go() {
+    multiversx_sc_scenario::run_go("mandos/destination_epoch_limit.scen.json");
+}
+
 #[test]
 fn execute_batch_both_rejected_go() {
     multiversx_sc_scenario::run_go("mandos/execute_batch_both_rejected.scen.json");
@@ -43,6 +88,11 @@ fn execute_transaction_success_go() {
     multiversx_sc_scenario::run_go("mandos/execute_transaction_success.scen.json");
 }
 
+#[test]
+fn fee_paid_on_top_go() {
+    multiversx_sc_scenario::run_go("mandos/fee_paid_on_top.scen.json");
+}
+
 #[test]
 fn get_next_pending_tx_go() {
     multiversx_sc_scenario::run_go("mandos/get_next_pending_tx.scen.json");
@@ -58,11 +108,56 @@ fn get_next_tx_batch_too_early_go() {
     multiversx_sc_scenario::run_go("mandos/get_next_tx_batch_too_early.scen.json");
 }
 
+#[test]
+fn lock_mode_go() {
+    multiversx_sc_scenario::run_go("mandos/lock_mode.scen.json");
+}
+
+#[test]
+fn max_pending_batches_go() {
+    multiversx_sc_scenario::run_go("mandos/max_pending_batches.scen.json");
+}
+
+#[test]
+fn progress_batches_go() {
+    multiversx_sc_scenario::run_go("mandos/progress_batches.scen.json");
+}
+
+#[test]
+fn refund_claim_bounty_go() {
+    multiversx_sc_scenario::run_go("mandos/refund_claim_bounty.scen.json");
+}
+
+#[test]
+fn refund_expiry_go() {
+    multiversx_sc_scenario::run_go("mandos/refund_expiry.scen.json");
+}
+
+#[test]
+fn refund_fees_on_rejection_go() {
+    multiversx_sc_scenario::run_go("mandos/refund_fees_on_rejection.scen.json");
+}
+
 #[test]
 fn setup_accounts_go() {
     multiversx_sc_scenario::run_go("mandos/setup_accounts.scen.json");
 }
 
+#[test]
+fn token_metadata_go() {
+    multiversx_sc_scenario::run_go("mandos/token_metadata.scen.json");
+}
+
+#[test]
+fn trusted_forwarder_go() {
+    multiversx_sc_scenario::run_go("mandos/trusted_forwarder.scen.json");
+}
+
+#[test]
+fn two_step_ownership_transfer_go() {
+    multiversx_sc_scenario::run_go("mandos/two_step_ownership_transfer.scen.json");
+}
+
 #[test]
 fn zero_fees_go() {
     multiversx_sc_scenario::run_go("mandos/zero_fees.scen.json");
@@ -0,0 +1,13 @@
+multiversx_sc::derive_imports!();
+
+use tx_batch_module::BatchConfig;
+
+/// Optional init argument, replacing the default settings of the refund batches
+/// and whether the contract starts paused, which it does not by default.
+///
+/// The `getDeploymentConfig` view returns the settings currently in effect.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct DeploymentConfig {
+    pub batch: BatchConfig,
+    pub paused: bool,
+}
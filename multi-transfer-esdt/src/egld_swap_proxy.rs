@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+
+/// The standard EGLD <-> WEGLD swap contract.
+#[multiversx_sc::proxy]
+pub trait EgldEsdtSwap {
+    #[payable("*")]
+    #[endpoint(unwrapEgld)]
+    fn unwrap_egld(&self);
+
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool;
+}
@@ -2,49 +2,564 @@
 
 multiversx_sc::imports!();
 
-use transaction::{EthTransaction, PaymentsVec, Transaction, TxBatchSplitInFields};
+pub mod deployment_config;
+mod egld_swap_proxy;
+pub mod pending_transfers;
+pub mod quarantine;
+pub mod unlock_mode;
+pub mod wegld_reconciliation;
+
+use bridge_errors::{
+    INVALID_EGLD_SWAP_CONTRACT_ERR_MSG, INVALID_REPLAY_PROTECTION_CONTRACT_ERR_MSG,
+    INVALID_TOKEN_ID_ERR_MSG, INVALID_WRAPPING_CONTRACT_ERR_MSG, NO_REMAINING_TRANSFERS_ERR_MSG,
+    TRANSFER_MISMATCH_ERR_MSG, TRANSFER_NOT_FAILED_ERR_MSG, TRANSFER_WHILE_PAUSED_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
+use deployment_config::DeploymentConfig;
+use migration_module::bridge_version::{
+    BridgeVersion, InterfaceVersion, TRANSFER_FIELD_UNWRAP_EGLD,
+};
+use migration_module::StorageVersion;
+use pending_transfers::{PendingIncomingTransfer, PendingTransferReason};
+use transaction::{
+    EthTransaction, PaymentsVec, Transaction, TxBatchSplitInFields, TxNonce, ETHEREUM_CHAIN_ID,
+};
+use wegld_reconciliation::WegldReconciliation;
 
 const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
 const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = u64::MAX;
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
 
 #[multiversx_sc::contract]
 pub trait MultiTransferEsdt:
-    tx_batch_module::TxBatchModule + max_bridged_amount_module::MaxBridgedAmountModule
+    tx_batch_module::TxBatchModule
+    + max_bridged_amount_module::MaxBridgedAmountModule
+    + screening_module::ScreeningModule
+    + quarantine::QuarantineModule
+    + unlock_mode::UnlockModeModule
+    + ownership_module::OwnershipModule
+    + migration_module::MigrationModule
+    + execution_guard_module::ExecutionGuardModule
+    + config_events_module::ConfigEventsModule
+    + multiversx_sc_modules::pause::PauseModule
 {
+    /// opt_config - Deployment parameters replacing the defaults, see `DeploymentConfig`.
+    /// It can only be passed along with the wrapping contract address.
     #[init]
-    fn init(&self, opt_wrapping_contract_address: OptionalValue<ManagedAddress>) {
-        self.max_tx_batch_size()
-            .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
-        self.max_tx_batch_block_duration()
-            .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+    fn init(
+        &self,
+        opt_wrapping_contract_address: OptionalValue<ManagedAddress>,
+        opt_config: OptionalValue<DeploymentConfig>,
+    ) {
+        match opt_config.into_option() {
+            Some(config) => {
+                self.set_batch_config(&config.batch);
+                self.set_paused(config.paused);
+            }
+            None => {
+                self.max_tx_batch_size()
+                    .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
+                self.max_tx_batch_block_duration()
+                    .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+            }
+        }
 
         self.set_wrapping_contract_address(opt_wrapping_contract_address);
 
         // batch ID 0 is considered invalid
         self.first_batch_id().set_if_empty(1);
         self.last_batch_id().set_if_empty(1);
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(INTERFACE_VERSION, 0, TRANSFER_FIELD_UNWRAP_EGLD)
     }
 
+    /// Executes the transfers of an Ethereum batch.
+    ///
+    /// If `max_tx_batch_gas` is not 0, no new transfers are attempted once that much gas was used.
+    /// The remaining transfers are saved, and can be executed through `executeRemainingTransfers`.
+    /// Returns the number of remaining transfers.
+    ///
+    /// If a replay protection contract is set, the transfers are first recorded in it,
+    /// which fails if any of them was already executed.
+    ///
+    /// No transfers are executed while paused. The multisig pauses this SC when it is paused itself.
     #[only_owner]
     #[endpoint(batchTransferEsdtToken)]
     fn batch_transfer_esdt_token(
         &self,
         batch_id: u64,
+        max_tx_batch_gas: u64,
         transfers: MultiValueEncoded<EthTransaction<Self::Api>>,
+    ) -> usize {
+        require!(self.not_paused(), TRANSFER_WHILE_PAUSED_ERR_MSG);
+        self.start_execution();
+
+        let transfers = transfers.to_vec();
+        self.record_executed_transfers(batch_id, &transfers);
+
+        let nr_remaining_transfers = self.execute_transfers(batch_id, max_tx_batch_gas, &transfers);
+
+        self.end_execution();
+
+        nr_remaining_transfers
+    }
+
+    /// Same as `batchTransferEsdtToken`, but the WEGLD transfers with the given tx nonces
+    /// are unwrapped through the EGLD swap contract, so their recipients get native EGLD.
+    /// The other transfers in the list are delivered as usual. See `setEgldSwapContract`.
+    #[only_owner]
+    #[endpoint(batchTransferEsdtTokenWithEgldUnwrap)]
+    fn batch_transfer_esdt_token_with_egld_unwrap(
+        &self,
+        batch_id: u64,
+        max_tx_batch_gas: u64,
+        unwrap_egld_tx_nonces: ManagedVec<TxNonce>,
+        transfers: MultiValueEncoded<EthTransaction<Self::Api>>,
+    ) -> usize {
+        // kept until the transfer is delivered, so it also applies to remaining and re-executed transfers
+        for tx_nonce in &unwrap_egld_tx_nonces {
+            self.unwrap_egld_transfer(tx_nonce).set(true);
+        }
+
+        self.batch_transfer_esdt_token(batch_id, max_tx_batch_gas, transfers)
+    }
+
+    /// Continues the execution of a partially executed batch, with the same rules as `batchTransferEsdtToken`.
+    /// Anyone can call it, since the transfers were already approved by the relayers.
+    #[endpoint(executeRemainingTransfers)]
+    fn execute_remaining_transfers(&self, batch_id: u64, max_tx_batch_gas: u64) -> usize {
+        let remaining_transfers_mapper = self.remaining_transfers(batch_id);
+        require!(
+            !remaining_transfers_mapper.is_empty(),
+            NO_REMAINING_TRANSFERS_ERR_MSG
+        );
+        require!(self.not_paused(), TRANSFER_WHILE_PAUSED_ERR_MSG);
+
+        self.start_execution();
+
+        let transfers = remaining_transfers_mapper.get();
+        remaining_transfers_mapper.clear();
+        let _ = self
+            .batches_with_remaining_transfers()
+            .swap_remove(&batch_id);
+        let nr_remaining_transfers = self.execute_transfers(batch_id, max_tx_batch_gas, &transfers);
+
+        self.end_execution();
+
+        nr_remaining_transfers
+    }
+
+    /// Executes again transfers of an already executed batch, which previously failed.
+    ///
+    /// Only transfers that are still waiting in the refund batches are accepted,
+    /// and they are removed from the refund batch when it is moved out of this SC.
+    /// If a transfer fails again, its original refund transaction is kept.
+    #[only_owner]
+    #[endpoint(reexecuteFailedTransfers)]
+    fn reexecute_failed_transfers(
+        &self,
+        batch_id: u64,
+        transfers: MultiValueEncoded<EthTransaction<Self::Api>>,
+    ) {
+        require!(self.not_paused(), TRANSFER_WHILE_PAUSED_ERR_MSG);
+        self.start_execution();
+
+        let transfers_vec = transfers.to_vec();
+        for eth_tx in &transfers_vec {
+            let failed_transfer_mapper = self.failed_transfer(eth_tx.tx_nonce);
+            require!(
+                !failed_transfer_mapper.is_empty(),
+                TRANSFER_NOT_FAILED_ERR_MSG
+            );
+
+            let (failed_batch_id, failed_tx) = failed_transfer_mapper.get();
+            require!(
+                failed_batch_id == batch_id && self.is_same_transfer(&failed_tx, &eth_tx),
+                TRANSFER_MISMATCH_ERR_MSG
+            );
+
+            failed_transfer_mapper.clear();
+        }
+
+        let _ = self.transfer_tokens(batch_id, 0, &transfers_vec, true);
+
+        // failed transfers are saved again, so their refund is still valid
+        for eth_tx in &transfers_vec {
+            if self.failed_transfer(eth_tx.tx_nonce).is_empty() {
+                self.reexecuted_transfer(eth_tx.tx_nonce).set(true);
+            }
+        }
+
+        self.end_execution();
+    }
+
+    /// Delivers quarantined transfers. They go through the same checks as when first executed,
+    /// except for the quarantine rules, and are refunded if they fail.
+    #[only_owner]
+    #[endpoint(releaseQuarantinedTransfers)]
+    fn release_quarantined_transfers(&self, tx_nonces: MultiValueEncoded<u64>) {
+        require!(self.not_paused(), TRANSFER_WHILE_PAUSED_ERR_MSG);
+        self.start_execution();
+
+        for tx_nonce in tx_nonces {
+            let (batch_id, eth_tx) = self.take_quarantined_transfer(tx_nonce);
+            let mut transfers = ManagedVec::new();
+            transfers.push(eth_tx);
+
+            let (refund_tx_list, _) = self.transfer_tokens(batch_id, 0, &transfers, false);
+            self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &refund_tx_list);
+        }
+
+        self.end_execution();
+    }
+
+    /// Delivers transfers deferred because of the reserve buffer, see `UnlockModeModule`.
+    /// Fails if there is still not enough liquidity above the reserve buffer for one of them.
+    /// Anyone can call it, since the transfers were already approved by the relayers.
+    /// They go through the same checks as when first executed, except for the quarantine rules, and are refunded if they fail.
+    #[endpoint(claimDeferredTransfers)]
+    fn claim_deferred_transfers(&self, tx_nonces: MultiValueEncoded<u64>) {
+        require!(self.not_paused(), TRANSFER_WHILE_PAUSED_ERR_MSG);
+        self.start_execution();
+
+        for tx_nonce in tx_nonces {
+            let (batch_id, eth_tx) = self.take_deferred_transfer(tx_nonce);
+            // the token might have been switched back to mint mode since
+            if self.unlock_mode(&eth_tx.token_id).get() {
+                self.require_can_unlock_above_reserve(&eth_tx.token_id, &eth_tx.amount);
+            }
+
+            let mut transfers = ManagedVec::new();
+            transfers.push(eth_tx);
+
+            let (refund_tx_list, _) = self.transfer_tokens(batch_id, 0, &transfers, false);
+            self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &refund_tx_list);
+        }
+
+        self.end_execution();
+    }
+
+    /// Sends quarantined transfers back to Ethereum, through the refund batches.
+    #[only_owner]
+    #[endpoint(refundQuarantinedTransfers)]
+    fn refund_quarantined_transfers(&self, tx_nonces: MultiValueEncoded<u64>) {
+        let mut refund_tx_list = ManagedVec::new();
+        for tx_nonce in tx_nonces {
+            let (batch_id, eth_tx) = self.take_quarantined_transfer(tx_nonce);
+            self.failed_transfer(tx_nonce)
+                .set((batch_id, eth_tx.clone()));
+
+            refund_tx_list.push(self.convert_to_refund_tx(eth_tx));
+        }
+
+        self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &refund_tx_list);
+    }
+
+    #[only_owner]
+    #[endpoint(getAndClearFirstRefundBatch)]
+    fn get_and_clear_first_refund_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+        let first_batch_id = self.first_batch_id().get();
+        let mut first_batch = self.pending_batches(first_batch_id);
+        if first_batch.is_empty() {
+            return OptionalValue::None;
+        }
+
+        // transfers that were successfully executed again are not refunded anymore
+        let mut refund_txs = MultiValueEncoded::new();
+        for tx in first_batch.iter() {
+            let reexecuted_mapper = self.reexecuted_transfer(tx.nonce);
+            if reexecuted_mapper.get() {
+                reexecuted_mapper.clear();
+                continue;
+            }
+
+            self.failed_transfer(tx.nonce).clear();
+            self.unwrap_egld_transfer(tx.nonce).clear();
+            refund_txs.push(tx.into_multiresult());
+        }
+
+        self.clear_first_batch(ETHEREUM_CHAIN_ID, &mut first_batch);
+
+        OptionalValue::Some((first_batch_id, refund_txs).into())
+    }
+
+    #[endpoint(setWrappingContractAddress)]
+    fn set_wrapping_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
+        self.require_caller_owner_or_admin();
+        let parameter_key = ManagedBuffer::new_from_bytes(b"wrappingContractAddress");
+        let old_value = self.config_value(&parameter_key);
+        match opt_new_address {
+            OptionalValue::Some(sc_addr) => {
+                require!(
+                    self.blockchain().is_smart_contract(&sc_addr),
+                    INVALID_WRAPPING_CONTRACT_ERR_MSG
+                );
+
+                self.wrapping_contract_address().set(&sc_addr);
+            }
+            OptionalValue::None => self.wrapping_contract_address().clear(),
+        }
+
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the contract keeping the executed transfers across redeployments,
+    /// or removes it if no address is given.
+    /// This contract must be one of its registrars (see `addRegistrar`).
+    #[endpoint(setReplayProtectionContractAddress)]
+    fn set_replay_protection_contract_address(
+        &self,
+        opt_new_address: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_caller_owner_or_admin();
+        let parameter_key = ManagedBuffer::new_from_bytes(b"replayProtectionContractAddress");
+        let old_value = self.config_value(&parameter_key);
+        match opt_new_address {
+            OptionalValue::Some(sc_addr) => {
+                require!(
+                    self.blockchain().is_smart_contract(&sc_addr),
+                    INVALID_REPLAY_PROTECTION_CONTRACT_ERR_MSG
+                );
+
+                self.replay_protection_contract_address().set(&sc_addr);
+            }
+            OptionalValue::None => self.replay_protection_contract_address().clear(),
+        }
+
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the EGLD swap contract and its WEGLD token ID.
+    /// Incoming WEGLD transfers marked through `batchTransferEsdtTokenWithEgldUnwrap` are unwrapped through it,
+    /// so the recipient receives native EGLD. Without arguments, the unwrapping is disabled.
+    #[endpoint(setEgldSwapContract)]
+    fn set_egld_swap_contract(
+        &self,
+        opt_swap_contract: OptionalValue<MultiValue2<ManagedAddress, TokenIdentifier>>,
+    ) {
+        self.require_caller_owner_or_admin();
+        let swap_contract_key = ManagedBuffer::new_from_bytes(b"egldSwapContractAddress");
+        let old_swap_contract = self.config_value(&swap_contract_key);
+        let wegld_token_id_key = ManagedBuffer::new_from_bytes(b"wegldTokenId");
+        let old_wegld_token_id = self.config_value(&wegld_token_id_key);
+        match opt_swap_contract {
+            OptionalValue::Some(swap_contract) => {
+                let (sc_addr, wegld_token_id) = swap_contract.into_tuple();
+                require!(
+                    self.blockchain().is_smart_contract(&sc_addr),
+                    INVALID_EGLD_SWAP_CONTRACT_ERR_MSG
+                );
+                require!(
+                    wegld_token_id.is_valid_esdt_identifier(),
+                    INVALID_TOKEN_ID_ERR_MSG
+                );
+
+                self.egld_swap_contract_address().set(&sc_addr);
+                self.wegld_token_id().set(&wegld_token_id);
+            }
+            OptionalValue::None => {
+                self.egld_swap_contract_address().clear();
+                self.wegld_token_id().clear();
+            }
+        }
+
+        self.emit_config_changed(swap_contract_key, old_swap_contract, NO_ACTION_ID);
+        self.emit_config_changed(wegld_token_id_key, old_wegld_token_id, NO_ACTION_ID);
+    }
+
+    /// Compares the WEGLD minted by the bridge with the EGLD locked in the EGLD swap contract.
+    /// Nothing is returned if no swap contract is set.
+    #[view(getWegldReconciliation)]
+    fn get_wegld_reconciliation(&self) -> OptionalValue<WegldReconciliation<Self::Api>> {
+        let swap_contract_mapper = self.egld_swap_contract_address();
+        if swap_contract_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let wegld_minted = self.total_minted(&self.wegld_token_id().get()).get();
+        let egld_unwrapped = self.total_unwrapped_egld().get();
+        let outstanding_wegld = if wegld_minted > egld_unwrapped {
+            &wegld_minted - &egld_unwrapped
+        } else {
+            BigUint::zero()
+        };
+        let locked_egld = self.blockchain().get_balance(&swap_contract_mapper.get());
+        let is_backed = outstanding_wegld <= locked_egld;
+
+        OptionalValue::Some(WegldReconciliation {
+            wegld_minted,
+            egld_unwrapped,
+            outstanding_wegld,
+            locked_egld,
+            is_backed,
+        })
+    }
+
+    /// Lists the incoming transfers to the given address that were not delivered yet,
+    /// along with the reason: not attempted yet, quarantined, deferred, or failed and about to be refunded.
+    #[view(getPendingIncomingTransfers)]
+    fn get_pending_incoming_transfers(
+        &self,
+        recipient: ManagedAddress,
+    ) -> MultiValueEncoded<PendingIncomingTransfer<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for batch_id in self.batches_with_remaining_transfers().iter() {
+            for eth_tx in &self.remaining_transfers(batch_id).get() {
+                if eth_tx.to == recipient {
+                    result.push(PendingIncomingTransfer {
+                        batch_id,
+                        transfer: eth_tx,
+                        reason: PendingTransferReason::AwaitingExecution,
+                    });
+                }
+            }
+        }
+
+        for (batch_id, eth_tx) in self.quarantined_transfers().values() {
+            if eth_tx.to == recipient {
+                result.push(PendingIncomingTransfer {
+                    batch_id,
+                    transfer: eth_tx,
+                    reason: PendingTransferReason::Quarantined,
+                });
+            }
+        }
+
+        for (batch_id, eth_tx) in self.deferred_transfers().values() {
+            if eth_tx.to == recipient {
+                result.push(PendingIncomingTransfer {
+                    batch_id,
+                    transfer: eth_tx,
+                    reason: PendingTransferReason::Deferred,
+                });
+            }
+        }
+
+        // failed transfers stay in the refund batches until they are moved out of this SC
+        let first_batch_id = self.first_batch_id().get();
+        let last_batch_id = self.last_batch_id().get();
+        for refund_batch_id in first_batch_id..=last_batch_id {
+            for refund_tx in self.pending_batches(refund_batch_id).iter() {
+                if refund_tx.to != *recipient.as_managed_buffer()
+                    || self.reexecuted_transfer(refund_tx.nonce).get()
+                {
+                    continue;
+                }
+
+                let failed_transfer_mapper = self.failed_transfer(refund_tx.nonce);
+                if failed_transfer_mapper.is_empty() {
+                    continue;
+                }
+
+                let (batch_id, eth_tx) = failed_transfer_mapper.get();
+                result.push(PendingIncomingTransfer {
+                    batch_id,
+                    transfer: eth_tx,
+                    reason: PendingTransferReason::Failed,
+                });
+            }
+        }
+
+        result
+    }
+
+    /// The refund batch settings currently in effect, and the current pause status.
+    #[view(getDeploymentConfig)]
+    fn get_deployment_config(&self) -> DeploymentConfig {
+        DeploymentConfig {
+            batch: self.get_batch_config(),
+            paused: self.is_paused(),
+        }
+    }
+
+    // private
+
+    fn record_executed_transfers(
+        &self,
+        batch_id: u64,
+        transfers: &ManagedVec<EthTransaction<Self::Api>>,
+    ) {
+        let replay_protection_mapper = self.replay_protection_contract_address();
+        if replay_protection_mapper.is_empty() {
+            return;
+        }
+
+        let mut tx_nonces = MultiValueEncoded::new();
+        for eth_tx in transfers {
+            tx_nonces.push(eth_tx.tx_nonce);
+        }
+
+        let _: IgnoreValue = self
+            .replay_protection_proxy(replay_protection_mapper.get())
+            .record_executed_transfers(batch_id, tx_nonces)
+            .execute_on_dest_context();
+    }
+
+    fn execute_transfers(
+        &self,
+        batch_id: u64,
+        max_tx_batch_gas: u64,
+        transfers: &ManagedVec<EthTransaction<Self::Api>>,
+    ) -> usize {
+        let (refund_tx_list, remaining_transfers) =
+            self.transfer_tokens(batch_id, max_tx_batch_gas, transfers, true);
+        self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &refund_tx_list);
+
+        let nr_remaining_transfers = remaining_transfers.len();
+        if nr_remaining_transfers > 0 {
+            self.remaining_transfers(batch_id).set(&remaining_transfers);
+            let _ = self.batches_with_remaining_transfers().insert(batch_id);
+            self.batch_partially_executed_event(batch_id, nr_remaining_transfers);
+        }
+
+        nr_remaining_transfers
+    }
+
+    /// Sends the tokens for all the valid transfers, until `max_tx_batch_gas` is used (0 means no limit).
+    /// If `apply_quarantine` is set, valid transfers matching the quarantine rules are quarantined instead.
+    /// Transfers of tokens in unlock mode that would breach the reserve buffer are deferred.
+    /// Returns the refund transactions for the failed transfers, and the transfers that were not attempted.
+    fn transfer_tokens(
+        &self,
+        batch_id: u64,
+        max_tx_batch_gas: u64,
+        transfers: &ManagedVec<EthTransaction<Self::Api>>,
+        apply_quarantine: bool,
+    ) -> (
+        ManagedVec<Transaction<Self::Api>>,
+        ManagedVec<EthTransaction<Self::Api>>,
     ) {
         let mut valid_payments_list = ManagedVec::new();
         let mut valid_dest_addresses_list = ManagedVec::new();
+        let mut valid_tx_nonces = ManagedVec::new();
         let mut refund_tx_list = ManagedVec::new();
+        let mut remaining_transfers = ManagedVec::new();
 
         let own_sc_address = self.blockchain().get_sc_address();
         let sc_shard = self.blockchain().get_shard_of_address(&own_sc_address);
+        let gas_at_start = self.blockchain().get_gas_left();
+
+        for (i, eth_tx) in transfers.iter().enumerate() {
+            let gas_used = gas_at_start - self.blockchain().get_gas_left();
+            if max_tx_batch_gas > 0 && gas_used >= max_tx_batch_gas {
+                remaining_transfers = transfers.slice(i, transfers.len()).unwrap_or_default();
+                break;
+            }
 
-        for eth_tx in transfers {
             let mut must_refund = false;
             if eth_tx.to.is_zero() || self.blockchain().is_smart_contract(&eth_tx.to) {
                 self.transfer_failed_invalid_destination(batch_id, eth_tx.tx_nonce);
                 must_refund = true;
-            } else if !self.is_local_role_set(&eth_tx.token_id, &EsdtLocalRole::Mint) {
+            } else if !self.unlock_mode(&eth_tx.token_id).get()
+                && !self.is_local_role_set(&eth_tx.token_id, &EsdtLocalRole::Mint)
+            {
                 self.transfer_failed_invalid_token(batch_id, eth_tx.tx_nonce);
                 must_refund = true;
             } else if self.is_above_max_amount(&eth_tx.token_id, &eth_tx.amount) {
@@ -53,62 +568,109 @@ pub trait MultiTransferEsdt:
             } else if self.is_account_same_shard_frozen(sc_shard, &eth_tx.to, &eth_tx.token_id) {
                 self.transfer_failed_frozen_destination_account(batch_id, eth_tx.tx_nonce);
                 must_refund = true;
+            } else if !self.passes_transfer_screening(
+                &eth_tx.from,
+                &eth_tx.to,
+                &eth_tx.token_id,
+                &eth_tx.amount,
+            ) {
+                self.transfer_failed_screening(batch_id, eth_tx.tx_nonce);
+                must_refund = true;
             }
 
             if must_refund {
+                self.failed_transfer(eth_tx.tx_nonce)
+                    .set((batch_id, eth_tx.clone()));
+
                 let refund_tx = self.convert_to_refund_tx(eth_tx);
                 refund_tx_list.push(refund_tx);
 
                 continue;
             }
 
-            self.send()
-                .esdt_local_mint(&eth_tx.token_id, 0, &eth_tx.amount);
+            if apply_quarantine && self.must_quarantine(&eth_tx) {
+                self.quarantine_transfer(batch_id, eth_tx);
+
+                continue;
+            }
+
+            if self.unlock_mode(&eth_tx.token_id).get() {
+                let already_unlocked =
+                    self.sum_payments_of_token(&valid_payments_list, &eth_tx.token_id);
+                if !self.can_unlock_above_reserve(
+                    &eth_tx.token_id,
+                    &eth_tx.amount,
+                    &already_unlocked,
+                ) {
+                    self.defer_transfer(batch_id, eth_tx);
+
+                    continue;
+                }
+            } else {
+                self.send()
+                    .esdt_local_mint(&eth_tx.token_id, 0, &eth_tx.amount);
+                self.total_minted(&eth_tx.token_id)
+                    .update(|minted| *minted += &eth_tx.amount);
+            }
 
             // emit event before the actual transfer so we don't have to save the tx_nonces as well
             self.transfer_performed_event(batch_id, eth_tx.tx_nonce);
 
             valid_dest_addresses_list.push(eth_tx.to);
+            valid_tx_nonces.push(eth_tx.tx_nonce);
             valid_payments_list.push(EsdtTokenPayment::new(eth_tx.token_id, 0, eth_tx.amount));
         }
 
         let payments_after_wrapping = self.wrap_tokens(valid_payments_list);
-        self.distribute_payments(valid_dest_addresses_list, payments_after_wrapping);
-
-        self.add_multiple_tx_to_batch(&refund_tx_list);
+        self.distribute_payments(
+            valid_dest_addresses_list,
+            valid_tx_nonces,
+            payments_after_wrapping,
+        );
+        self.check_wegld_backing();
+
+        (refund_tx_list, remaining_transfers)
     }
 
-    #[only_owner]
-    #[endpoint(getAndClearFirstRefundBatch)]
-    fn get_and_clear_first_refund_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
-        let opt_current_batch = self.get_first_batch_any_status();
-        if matches!(opt_current_batch, OptionalValue::Some(_)) {
-            let first_batch_id = self.first_batch_id().get();
-            let mut first_batch = self.pending_batches(first_batch_id);
-
-            self.clear_first_batch(&mut first_batch);
+    /// Emits `wegldSupplyDivergence` if the WEGLD minted by the bridge is no longer backed by the locked EGLD.
+    /// Transfers are not stopped, but WEGLD is not unwrapped anymore, see `can_unwrap_egld`.
+    fn check_wegld_backing(&self) {
+        if let OptionalValue::Some(reconciliation) = self.get_wegld_reconciliation() {
+            if !reconciliation.is_backed {
+                self.wegld_supply_divergence_event(
+                    &reconciliation.outstanding_wegld,
+                    &reconciliation.locked_egld,
+                );
+            }
         }
-
-        opt_current_batch
     }
 
-    #[only_owner]
-    #[endpoint(setWrappingContractAddress)]
-    fn set_wrapping_contract_address(&self, opt_new_address: OptionalValue<ManagedAddress>) {
-        match opt_new_address {
-            OptionalValue::Some(sc_addr) => {
-                require!(
-                    self.blockchain().is_smart_contract(&sc_addr),
-                    "Invalid unwrapping contract address"
-                );
-
-                self.wrapping_contract_address().set(&sc_addr);
+    fn sum_payments_of_token(
+        &self,
+        payments: &PaymentsVec<Self::Api>,
+        token_id: &TokenIdentifier,
+    ) -> BigUint {
+        let mut total = BigUint::zero();
+        for payment in payments {
+            if &payment.token_identifier == token_id {
+                total += &payment.amount;
             }
-            OptionalValue::None => self.wrapping_contract_address().clear(),
         }
+
+        total
     }
 
-    // private
+    fn is_same_transfer(
+        &self,
+        first: &EthTransaction<Self::Api>,
+        second: &EthTransaction<Self::Api>,
+    ) -> bool {
+        first.from.raw_addr == second.from.raw_addr
+            && first.to == second.to
+            && first.token_id == second.token_id
+            && first.amount == second.amount
+            && first.tx_nonce == second.tx_nonce
+    }
 
     fn convert_to_refund_tx(&self, eth_tx: EthTransaction<Self::Api>) -> Transaction<Self::Api> {
         Transaction {
@@ -151,7 +713,7 @@ pub trait MultiTransferEsdt:
         }
 
         self.get_wrapping_contract_proxy_instance()
-            .wrap_tokens()
+            .wrap_tokens(OptionalValue::<ManagedBuffer>::None)
             .with_multi_token_transfer(payments)
             .execute_on_dest_context()
     }
@@ -159,14 +721,77 @@ pub trait MultiTransferEsdt:
     fn distribute_payments(
         &self,
         dest_addresses: ManagedVec<ManagedAddress>,
+        tx_nonces: ManagedVec<TxNonce>,
         payments: PaymentsVec<Self::Api>,
     ) {
-        for (dest, p) in dest_addresses.iter().zip(payments.iter()) {
+        let wegld_token_id_mapper = self.wegld_token_id();
+        let opt_wegld_token_id = if wegld_token_id_mapper.is_empty() {
+            None
+        } else {
+            Some(wegld_token_id_mapper.get())
+        };
+        for ((dest, tx_nonce), p) in dest_addresses
+            .iter()
+            .zip(tx_nonces.iter())
+            .zip(payments.iter())
+        {
+            let unwrap_egld = self.unwrap_egld_transfer(tx_nonce).take();
+            let is_wegld = opt_wegld_token_id.as_ref() == Some(&p.token_identifier);
+            if unwrap_egld && is_wegld {
+                if self.can_unwrap_egld() {
+                    self.unwrap_egld_and_send(&dest, &p.token_identifier, &p.amount);
+                    continue;
+                }
+
+                self.egld_unwrap_skipped_event(&dest, &p.amount);
+            }
+
             self.send()
                 .direct_esdt(&dest, &p.token_identifier, 0, &p.amount);
         }
     }
 
+    /// The swap call can't fail without reverting the whole batch, so WEGLD is only unwrapped
+    /// if the swap contract is not paused, and if its locked EGLD still backs the WEGLD minted by the bridge.
+    /// Otherwise, EGLD that no deposit backs would be drained from the swap contract.
+    /// The transfer's WEGLD is already minted at this point, so being backed also means there is enough EGLD for it.
+    fn can_unwrap_egld(&self) -> bool {
+        let is_backed = match self.get_wegld_reconciliation() {
+            OptionalValue::Some(reconciliation) => reconciliation.is_backed,
+            OptionalValue::None => false,
+        };
+        if !is_backed {
+            return false;
+        }
+
+        let swap_contract_address = self.egld_swap_contract_address().get();
+        let is_paused: bool = self
+            .egld_swap_proxy(swap_contract_address)
+            .is_paused()
+            .execute_on_dest_context();
+
+        !is_paused
+    }
+
+    fn unwrap_egld_and_send(
+        &self,
+        dest: &ManagedAddress,
+        wegld_token_id: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let swap_contract_address = self.egld_swap_contract_address().get();
+        let _: IgnoreValue = self
+            .egld_swap_proxy(swap_contract_address)
+            .unwrap_egld()
+            .with_esdt_transfer((wegld_token_id.clone(), 0, amount.clone()))
+            .execute_on_dest_context();
+
+        self.total_unwrapped_egld()
+            .update(|unwrapped| *unwrapped += amount);
+        self.send().direct_egld(dest, amount);
+        self.egld_unwrapped_event(dest, amount);
+    }
+
     // proxies
 
     #[proxy]
@@ -175,6 +800,15 @@ pub trait MultiTransferEsdt:
         sc_address: ManagedAddress,
     ) -> bridged_tokens_wrapper::Proxy<Self::Api>;
 
+    #[proxy]
+    fn egld_swap_proxy(&self, sc_address: ManagedAddress) -> egld_swap_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn replay_protection_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> replay_protection::Proxy<Self::Api>;
+
     fn get_wrapping_contract_proxy_instance(&self) -> bridged_tokens_wrapper::Proxy<Self::Api> {
         self.wrapping_contract_proxy(self.wrapping_contract_address().get())
     }
@@ -185,6 +819,50 @@ pub trait MultiTransferEsdt:
     #[storage_mapper("wrappingContractAddress")]
     fn wrapping_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getReplayProtectionContractAddress)]
+    #[storage_mapper("replayProtectionContractAddress")]
+    fn replay_protection_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getEgldSwapContractAddress)]
+    #[storage_mapper("egldSwapContractAddress")]
+    fn egld_swap_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getWegldTokenId)]
+    #[storage_mapper("wegldTokenId")]
+    fn wegld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getTotalMinted)]
+    #[storage_mapper("totalMinted")]
+    fn total_minted(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// WEGLD unwrapped through the EGLD swap contract on delivery, see `getWegldReconciliation`.
+    #[view(getTotalUnwrappedEgld)]
+    #[storage_mapper("totalUnwrappedEgld")]
+    fn total_unwrapped_egld(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getRemainingTransfers)]
+    #[storage_mapper("remainingTransfers")]
+    fn remaining_transfers(
+        &self,
+        batch_id: u64,
+    ) -> SingleValueMapper<ManagedVec<EthTransaction<Self::Api>>>;
+
+    /// IDs of the batches with transfers in `remainingTransfers`.
+    #[storage_mapper("batchesWithRemainingTransfers")]
+    fn batches_with_remaining_transfers(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("failedTransfer")]
+    fn failed_transfer(&self, tx_nonce: u64)
+        -> SingleValueMapper<(u64, EthTransaction<Self::Api>)>;
+
+    #[storage_mapper("reexecutedTransfer")]
+    fn reexecuted_transfer(&self, tx_nonce: u64) -> SingleValueMapper<bool>;
+
+    /// Set for the transfers whose WEGLD should be unwrapped, until they are delivered or refunded.
+    #[view(isUnwrapEgldTransfer)]
+    #[storage_mapper("unwrapEgldTransfer")]
+    fn unwrap_egld_transfer(&self, tx_nonce: u64) -> SingleValueMapper<bool>;
+
     // events
 
     #[event("transferPerformedEvent")]
@@ -203,6 +881,34 @@ pub trait MultiTransferEsdt:
         #[indexed] tx_id: u64,
     );
 
+    #[event("transferFailedScreening")]
+    fn transfer_failed_screening(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("batchPartiallyExecuted")]
+    fn batch_partially_executed_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] nr_remaining_transfers: usize,
+    );
+
+    #[event("egldUnwrapped")]
+    fn egld_unwrapped_event(&self, #[indexed] dest: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// The transfer was marked to be unwrapped, but the recipient got WEGLD instead, see `can_unwrap_egld`.
+    #[event("egldUnwrapSkipped")]
+    fn egld_unwrap_skipped_event(
+        &self,
+        #[indexed] dest: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("wegldSupplyDivergence")]
+    fn wegld_supply_divergence_event(
+        &self,
+        #[indexed] outstanding_wegld: &BigUint,
+        #[indexed] locked_egld: &BigUint,
+    );
+
     #[event("transferOverMaxAmount")]
     fn transfer_over_max_amount(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
 }
@@ -0,0 +1,25 @@
+multiversx_sc::derive_imports!();
+multiversx_sc::imports!();
+
+use transaction::EthTransaction;
+
+/// Why an incoming transfer was not delivered yet.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy)]
+pub enum PendingTransferReason {
+    /// Not attempted yet, as its batch went over the gas budget (see `executeRemainingTransfers`).
+    AwaitingExecution,
+    /// Held until the owner releases or refunds it (see `QuarantineModule`).
+    Quarantined,
+    /// Failed, and waiting in the refund batches to be sent back to Ethereum.
+    /// May still be delivered through `reexecuteFailedTransfers` until then.
+    Failed,
+    /// Waiting for liquidity above the reserve buffer (see `UnlockModeModule`).
+    Deferred,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct PendingIncomingTransfer<M: ManagedTypeApi> {
+    pub batch_id: u64,
+    pub transfer: EthTransaction<M>,
+    pub reason: PendingTransferReason,
+}
@@ -0,0 +1,97 @@
+multiversx_sc::imports!();
+
+use bridge_errors::TRANSFER_NOT_QUARANTINED_ERR_MSG;
+use config_events_module::NO_ACTION_ID;
+use eth_address::EthAddress;
+use transaction::EthTransaction;
+
+/// Incoming transfers matching one of the rules are not delivered, but kept in a quarantine pool,
+/// until the owner either releases or refunds them
+/// (see `releaseQuarantinedTransfers` and `refundQuarantinedTransfers`).
+///
+/// A transfer is quarantined if its amount is above the threshold set for its token,
+/// or if it was sent from one of the flagged Ethereum addresses.
+#[multiversx_sc::module]
+pub trait QuarantineModule: config_events_module::ConfigEventsModule {
+    /// Sets the amount above which transfers of the token are quarantined. 0 disables the rule.
+    #[only_owner]
+    #[endpoint(setQuarantineThreshold)]
+    fn set_quarantine_threshold(&self, token_id: TokenIdentifier, threshold: BigUint) {
+        let parameter_key = self.config_key(b"quarantineThreshold", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.quarantine_threshold(&token_id).set(&threshold);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[only_owner]
+    #[endpoint(addQuarantinedOrigins)]
+    fn add_quarantined_origins(&self, origins: MultiValueEncoded<EthAddress<Self::Api>>) {
+        let mut quarantined_origins_mapper = self.quarantined_origins();
+        for origin in origins {
+            let _ = quarantined_origins_mapper.insert(origin);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeQuarantinedOrigins)]
+    fn remove_quarantined_origins(&self, origins: MultiValueEncoded<EthAddress<Self::Api>>) {
+        let mut quarantined_origins_mapper = self.quarantined_origins();
+        for origin in origins {
+            let _ = quarantined_origins_mapper.swap_remove(&origin);
+        }
+    }
+
+    fn must_quarantine(&self, eth_tx: &EthTransaction<Self::Api>) -> bool {
+        let threshold = self.quarantine_threshold(&eth_tx.token_id).get();
+        if threshold > 0 && eth_tx.amount > threshold {
+            return true;
+        }
+
+        self.quarantined_origins().contains(&eth_tx.from)
+    }
+
+    fn quarantine_transfer(&self, batch_id: u64, eth_tx: EthTransaction<Self::Api>) {
+        let tx_nonce = eth_tx.tx_nonce;
+        let _ = self
+            .quarantined_transfers()
+            .insert(tx_nonce, (batch_id, eth_tx));
+
+        self.transfer_quarantined_event(batch_id, tx_nonce);
+    }
+
+    /// Removes the transfer from quarantine, returning it along with its batch ID.
+    fn take_quarantined_transfer(&self, tx_nonce: u64) -> (u64, EthTransaction<Self::Api>) {
+        match self.quarantined_transfers().remove(&tx_nonce) {
+            Some(quarantined_transfer) => quarantined_transfer,
+            None => sc_panic!(TRANSFER_NOT_QUARANTINED_ERR_MSG),
+        }
+    }
+
+    /// Quarantined transfers, as pairs of (batch ID, transfer).
+    #[view(getQuarantinedTransfers)]
+    fn get_quarantined_transfers(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<u64, EthTransaction<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for (batch_id, eth_tx) in self.quarantined_transfers().values() {
+            result.push((batch_id, eth_tx).into());
+        }
+
+        result
+    }
+
+    #[view(getQuarantineThreshold)]
+    #[storage_mapper("quarantineThreshold")]
+    fn quarantine_threshold(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getQuarantinedOrigins)]
+    #[storage_mapper("quarantinedOrigins")]
+    fn quarantined_origins(&self) -> UnorderedSetMapper<EthAddress<Self::Api>>;
+
+    /// Quarantined transfers by transaction nonce, along with their batch ID.
+    #[storage_mapper("quarantinedTransfers")]
+    fn quarantined_transfers(&self) -> MapMapper<u64, (u64, EthTransaction<Self::Api>)>;
+
+    #[event("transferQuarantined")]
+    fn transfer_quarantined_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+}
@@ -0,0 +1,189 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    RESERVE_BUFFER_BREACHED_ERR_MSG, TOKEN_NOT_IN_UNLOCK_MODE_ERR_MSG,
+    TRANSFER_NOT_DEFERRED_ERR_MSG, UNLOCK_MODE_UNCHANGED_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
+use transaction::EthTransaction;
+
+/// Tokens in unlock mode are not minted for incoming transfers,
+/// but paid from the liquidity this SC holds (see `addUnlockLiquidity`).
+///
+/// A per-token reserve buffer is kept in the pool: transfers that would take the liquidity below it
+/// are deferred into the claim queue instead of being delivered,
+/// so a single large unlock cannot empty the pool and make the following smaller transfers fail.
+/// Deferred transfers are delivered through `claimDeferredTransfers`, once the pool was replenished.
+#[multiversx_sc::module]
+pub trait UnlockModeModule: config_events_module::ConfigEventsModule {
+    /// Switches the token between unlock mode and mint mode (the default).
+    /// When leaving unlock mode, the liquidity is sent to the custodian, or burned if none is given.
+    /// Deferred transfers of the token are then minted when claimed.
+    ///
+    /// Called by the multisig's `MigrateTokenMode` action, along with EsdtSafe's `migrateTokenMode`.
+    #[only_owner]
+    #[endpoint(migrateTokenMode)]
+    fn migrate_token_mode(
+        &self,
+        token_id: TokenIdentifier,
+        unlock_mode: bool,
+        opt_custodian: OptionalValue<ManagedAddress>,
+    ) {
+        require!(
+            self.unlock_mode(&token_id).get() != unlock_mode,
+            UNLOCK_MODE_UNCHANGED_ERR_MSG
+        );
+
+        let parameter_key = self.config_key(b"unlockMode", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.unlock_mode(&token_id).set(unlock_mode);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+
+        if unlock_mode {
+            return;
+        }
+
+        let liquidity = self.get_unlock_liquidity(&token_id);
+        if liquidity == 0 {
+            return;
+        }
+
+        match opt_custodian {
+            OptionalValue::Some(custodian) => {
+                self.send()
+                    .direct_esdt(&custodian, &token_id, 0, &liquidity);
+                self.unlock_liquidity_sent_event(&token_id, &custodian, &liquidity);
+            }
+            OptionalValue::None => {
+                self.send().esdt_local_burn(&token_id, 0, &liquidity);
+                self.unlock_liquidity_burned_event(&token_id, &liquidity);
+            }
+        }
+    }
+
+    /// Sets the liquidity of the token to keep in the pool. 0 lets transfers use all of it.
+    #[only_owner]
+    #[endpoint(setReserveBuffer)]
+    fn set_reserve_buffer(&self, token_id: TokenIdentifier, reserve_buffer: BigUint) {
+        let parameter_key = self.config_key(b"reserveBuffer", &token_id);
+        let old_value = self.config_value(&parameter_key);
+        self.reserve_buffer(&token_id).set(&reserve_buffer);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Adds liquidity for a token in unlock mode. Anyone can provide it.
+    #[payable("*")]
+    #[endpoint(addUnlockLiquidity)]
+    fn add_unlock_liquidity(&self) {
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        require!(
+            self.unlock_mode(&token_id).get(),
+            TOKEN_NOT_IN_UNLOCK_MODE_ERR_MSG
+        );
+
+        self.unlock_liquidity_added_event(&token_id, &amount);
+    }
+
+    /// The liquidity of the token that transfers can use, i.e. what the pool holds above the reserve buffer.
+    #[view(getAvailableUnlockLiquidity)]
+    fn get_available_unlock_liquidity(&self, token_id: TokenIdentifier) -> BigUint {
+        let liquidity = self.get_unlock_liquidity(&token_id);
+        let reserve_buffer = self.reserve_buffer(&token_id).get();
+        if liquidity > reserve_buffer {
+            liquidity - reserve_buffer
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    fn get_unlock_liquidity(&self, token_id: &TokenIdentifier) -> BigUint {
+        self.blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0)
+    }
+
+    /// `already_unlocked` is the amount of the token about to be sent by the current call,
+    /// which is still part of the liquidity.
+    fn can_unlock_above_reserve(
+        &self,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+        already_unlocked: &BigUint,
+    ) -> bool {
+        let available_liquidity = self.get_available_unlock_liquidity(token_id.clone());
+        available_liquidity >= (amount + already_unlocked)
+    }
+
+    fn require_can_unlock_above_reserve(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        require!(
+            self.can_unlock_above_reserve(token_id, amount, &BigUint::zero()),
+            RESERVE_BUFFER_BREACHED_ERR_MSG
+        );
+    }
+
+    fn defer_transfer(&self, batch_id: u64, eth_tx: EthTransaction<Self::Api>) {
+        let tx_nonce = eth_tx.tx_nonce;
+        let _ = self
+            .deferred_transfers()
+            .insert(tx_nonce, (batch_id, eth_tx));
+
+        self.transfer_deferred_event(batch_id, tx_nonce);
+    }
+
+    /// Removes the transfer from the claim queue, returning it along with its batch ID.
+    fn take_deferred_transfer(&self, tx_nonce: u64) -> (u64, EthTransaction<Self::Api>) {
+        match self.deferred_transfers().remove(&tx_nonce) {
+            Some(deferred_transfer) => deferred_transfer,
+            None => sc_panic!(TRANSFER_NOT_DEFERRED_ERR_MSG),
+        }
+    }
+
+    /// Deferred transfers, as pairs of (batch ID, transfer).
+    #[view(getDeferredTransfers)]
+    fn get_deferred_transfers(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<u64, EthTransaction<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for (batch_id, eth_tx) in self.deferred_transfers().values() {
+            result.push((batch_id, eth_tx).into());
+        }
+
+        result
+    }
+
+    #[view(isUnlockMode)]
+    #[storage_mapper("unlockMode")]
+    fn unlock_mode(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    #[view(getReserveBuffer)]
+    #[storage_mapper("reserveBuffer")]
+    fn reserve_buffer(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Deferred transfers by transaction nonce, along with their batch ID.
+    #[storage_mapper("deferredTransfers")]
+    fn deferred_transfers(&self) -> MapMapper<u64, (u64, EthTransaction<Self::Api>)>;
+
+    #[event("unlockLiquidityAdded")]
+    fn unlock_liquidity_added_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("unlockLiquiditySent")]
+    fn unlock_liquidity_sent_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] custodian: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("unlockLiquidityBurned")]
+    fn unlock_liquidity_burned_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("transferDeferred")]
+    fn transfer_deferred_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+}
@@ -0,0 +1,16 @@
+multiversx_sc::derive_imports!();
+multiversx_sc::imports!();
+
+/// Compares the WEGLD minted by the bridge with the EGLD locked in the EGLD swap contract.
+///
+/// `outstanding_wegld` is the WEGLD minted for incoming transfers, minus the WEGLD unwrapped
+/// through the swap contract on delivery. The locked EGLD backs all the WEGLD in circulation,
+/// so it can never be less than the part of it minted by the bridge.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct WegldReconciliation<M: ManagedTypeApi> {
+    pub wegld_minted: BigUint<M>,
+    pub egld_unwrapped: BigUint<M>,
+    pub outstanding_wegld: BigUint<M>,
+    pub locked_egld: BigUint<M>,
+    pub is_backed: bool,
+}
@@ -13,11 +13,41 @@ fn batch_transfer_one_executed_one_failed_go() {
     multiversx_sc_scenario::run_go("mandos/batch_transfer_one_executed_one_failed.scen.json");
 }
 
+#[test]
+fn batch_transfer_partially_executed_go() {
+    multiversx_sc_scenario::run_go("mandos/batch_transfer_partially_executed.scen.json");
+}
+
 #[test]
 fn batch_transfer_to_frozen_account_go() {
     multiversx_sc_scenario::run_go("mandos/batch_transfer_to_frozen_account.scen.json");
 }
 
+#[test]
+fn egld_swap_contract_config_go() {
+    multiversx_sc_scenario::run_go("mandos/egld_swap_contract_config.scen.json");
+}
+
+#[test]
+fn pending_incoming_transfers_go() {
+    multiversx_sc_scenario::run_go("mandos/pending_incoming_transfers.scen.json");
+}
+
+#[test]
+fn quarantine_go() {
+    multiversx_sc_scenario::run_go("mandos/quarantine.scen.json");
+}
+
+#[test]
+fn reexecute_failed_transfers_go() {
+    multiversx_sc_scenario::run_go("mandos/reexecute_failed_transfers.scen.json");
+}
+
+#[test]
+fn reserve_buffer_go() {
+    multiversx_sc_scenario::run_go("mandos/reserve_buffer.scen.json");
+}
+
 #[test]
 fn setup_accounts_go() {
     multiversx_sc_scenario::run_go("mandos/setup_accounts.scen.json");
@@ -32,3 +62,18 @@ fn transfer_ok_go() {
 fn two_transfers_same_token_go() {
     multiversx_sc_scenario::run_go("mandos/two_transfers_same_token.scen.json");
 }
+
+#[test]
+fn unlock_mode_migration_go() {
+    multiversx_sc_scenario::run_go("mandos/unlock_mode_migration.scen.json");
+}
+
+#[test]
+fn unwrap_egld_fallback_go() {
+    multiversx_sc_scenario::run_go("mandos/unwrap_egld_fallback.scen.json");
+}
+
+#[test]
+fn wegld_reconciliation_go() {
+    multiversx_sc_scenario::run_go("mandos/wegld_reconciliation.scen.json");
+}
@@ -47,7 +47,7 @@ upgradeMultisig() {
     MIN_STAKE=$(echo "$RELAYER_REQUIRED_STAKE*10^18" | bc)
     mxpy --verbose contract upgrade ${ADDRESS} --bytecode=../output/multisig.wasm --recall-nonce --pem=${ALICE} \
     --arguments 0x${ESDT_SAFE_ADDRESS_HEX} 0x${MULTI_TRANSFER_ESDT_ADDRESS_HEX} \
-    ${local} ${SLASH_AMOUNT} 0x07 \
+    ${local} ${SLASH_AMOUNT} ${STAKING_TOKEN} 0x07 \
     --gas-limit=200000000 --send --outfile="upgrade-multisig.json" --proxy=${PROXY} --chain=${CHAIN_ID} || return
     
 }
\ No newline at end of file
@@ -0,0 +1,101 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::supply_reconciliation::SupplyReconciliation;
+
+use token_module::ProxyTrait as _;
+
+/// Accounting of a token at the time the EsdtSafe was paused.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct AccountingSnapshot<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub block_nonce: u64,
+    pub supply: SupplyReconciliation<M>,
+}
+
+/// Records the accounting of every whitelisted token whenever the EsdtSafe is paused
+/// (see `pauseEsdtSafe`): locked balance, amount minted by MultiTransferEsdt,
+/// amount pending in batches and refunds owed.
+/// Reconciling after an incident can then start from the state of the bridge when it was stopped.
+#[multiversx_sc::module]
+pub trait AccountingSnapshotModule:
+    crate::storage::StorageModule + crate::util::UtilModule
+{
+    fn snapshot_bridge_accounting(&self) {
+        let token_ids: MultiValueEncoded<TokenIdentifier> = self
+            .get_esdt_safe_proxy_instance()
+            .token_whitelist()
+            .execute_on_dest_context();
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        let block_nonce = self.blockchain().get_block_nonce();
+        for token_id in token_ids {
+            let supply = self.get_supply_reconciliation(token_id.clone());
+            let mut snapshots_mapper = self.accounting_snapshots(&token_id);
+            let snapshot_index = snapshots_mapper.push(&AccountingSnapshot {
+                timestamp,
+                block_nonce,
+                supply,
+            });
+
+            self.accounting_snapshot_event(&token_id, snapshot_index, timestamp);
+        }
+    }
+
+    /// The accounting of the token when the EsdtSafe was last paused, if ever.
+    #[view(getLatestAccountingSnapshot)]
+    fn get_latest_accounting_snapshot(
+        &self,
+        token_id: TokenIdentifier,
+    ) -> OptionalValue<AccountingSnapshot<Self::Api>> {
+        let snapshots_mapper = self.accounting_snapshots(&token_id);
+        if snapshots_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(snapshots_mapper.get(snapshots_mapper.len()))
+    }
+
+    #[view(getAccountingSnapshotCount)]
+    fn get_accounting_snapshot_count(&self, token_id: TokenIdentifier) -> usize {
+        self.accounting_snapshots(&token_id).len()
+    }
+
+    /// Snapshots of the token, oldest first, for at most `count` entries starting with `from_index`.
+    /// Entries are numbered from 1.
+    #[view(getAccountingSnapshots)]
+    fn get_accounting_snapshots(
+        &self,
+        token_id: TokenIdentifier,
+        from_index: usize,
+        count: usize,
+    ) -> MultiValueEncoded<AccountingSnapshot<Self::Api>> {
+        let snapshots_mapper = self.accounting_snapshots(&token_id);
+        let first_index = core::cmp::max(from_index, 1);
+        let last_index = core::cmp::min(
+            first_index.saturating_add(count),
+            snapshots_mapper.len() + 1,
+        );
+
+        let mut result = MultiValueEncoded::new();
+        for index in first_index..last_index {
+            result.push(snapshots_mapper.get(index));
+        }
+
+        result
+    }
+
+    #[event("accountingSnapshot")]
+    fn accounting_snapshot_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] snapshot_index: usize,
+        #[indexed] timestamp: u64,
+    );
+
+    #[storage_mapper("accountingSnapshots")]
+    fn accounting_snapshots(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> VecMapper<AccountingSnapshot<Self::Api>>;
+}
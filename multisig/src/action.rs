@@ -1,10 +1,20 @@
 use multiversx_sc::api::ManagedTypeApi;
-use multiversx_sc::types::ManagedVec;
-use transaction::transaction_status::TransactionStatus;
-use transaction::EthTransaction;
+use multiversx_sc::types::{BigUint, ManagedAddress, ManagedVec, TokenIdentifier};
+use transaction::nft_transaction::EthNftTransaction;
+use transaction::transaction_status::{BatchStatuses, TransactionStatus};
+use transaction::{ChainId, EthTransaction, TxNonce};
+
+use crate::reserve_attestation::RemoteReserve;
+use crate::slash_offense::SlashOffense;
 
 multiversx_sc::derive_imports!();
 
+/// Discriminant of the last `Action` variant, to be updated when adding one.
+pub const LAST_ACTION_DISCRIMINANT: u32 = 27;
+
+/// Bit `i` is set for the action with discriminant `i`, `Nothing` excluded, see `getBridgeVersion`.
+pub const SUPPORTED_ACTIONS: u64 = ((1u64 << (LAST_ACTION_DISCRIMINANT + 1)) - 1) & !1;
+
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub enum Action<M: ManagedTypeApi> {
     Nothing,
@@ -16,6 +26,74 @@ pub enum Action<M: ManagedTypeApi> {
         eth_batch_id: u64,
         transfers: ManagedVec<M, EthTransaction<M>>,
     },
+    SetChainTransactionBatchStatus {
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+        tx_batch_status: ManagedVec<M, TransactionStatus>,
+    },
+    SetCurrentNftBatchStatus {
+        nft_safe_batch_id: u64,
+        tx_batch_status: ManagedVec<M, TransactionStatus>,
+    },
+    BatchTransferNft {
+        eth_batch_id: u64,
+        transfers: ManagedVec<M, EthNftTransaction<M>>,
+    },
+    ForceReexecuteBatch {
+        eth_batch_id: u64,
+        transfers: ManagedVec<M, EthTransaction<M>>,
+    },
+    AddProposer(ManagedAddress<M>),
+    RemoveProposer(ManagedAddress<M>),
+    /// IDs of other pending actions, performed in order.
+    Bundle(ManagedVec<M, usize>),
+    ResetDailyUsdVolume,
+    AddTrustedForwarder(ManagedAddress<M>),
+    RemoveTrustedForwarder(ManagedAddress<M>),
+    SweepExpiredRefunds {
+        token_id: TokenIdentifier<M>,
+        treasury: ManagedAddress<M>,
+    },
+    ReverseSlash(ManagedAddress<M>),
+    /// Tx nonces of transfers quarantined by MultiTransferEsdt.
+    ReleaseQuarantinedTransfers(ManagedVec<M, u64>),
+    RefundQuarantinedTransfers(ManagedVec<M, u64>),
+    /// Statuses of consecutive EsdtSafe batches, starting with the current one.
+    SetMultipleTransactionBatchStatuses {
+        chain_id: ChainId,
+        batch_statuses: ManagedVec<M, BatchStatuses<M>>,
+    },
+    AddExecutor(ManagedAddress<M>),
+    RemoveExecutor(ManagedAddress<M>),
+    SetSlashTier {
+        offense: SlashOffense,
+        amount: BigUint<M>,
+    },
+    /// Balances locked on the remote chain, as read at `eth_block_number`.
+    AttestRemoteReserves {
+        eth_block_number: u64,
+        reserves: ManagedVec<M, RemoteReserve<M>>,
+    },
+    AddBoardMember(ManagedAddress<M>),
+    RemoveUser(ManagedAddress<M>),
+    ChangeQuorum(usize),
+    SlashBoardMember {
+        board_member: ManagedAddress<M>,
+        offense: SlashOffense,
+    },
+    /// Same as `BatchTransferEsdtToken`, with the tx nonces of the WEGLD transfers to unwrap to EGLD.
+    BatchTransferEsdtTokenWithEgldUnwrap {
+        eth_batch_id: u64,
+        transfers: ManagedVec<M, EthTransaction<M>>,
+        unwrap_egld_tx_nonces: ManagedVec<M, TxNonce>,
+    },
+    /// Switches the token between lock mode and burn mode on both EsdtSafe and MultiTransferEsdt.
+    /// When leaving lock mode, the locked balance goes to `custodian`, or is burned if there is none.
+    MigrateTokenMode {
+        token_id: TokenIdentifier<M>,
+        lock_mode: bool,
+        custodian: Option<ManagedAddress<M>>,
+    },
 }
 
 impl<M: ManagedTypeApi> Action<M> {
@@ -25,4 +103,81 @@ impl<M: ManagedTypeApi> Action<M> {
     pub fn is_pending(&self) -> bool {
         !matches!(*self, Action::Nothing)
     }
+
+    pub fn is_bundle(&self) -> bool {
+        matches!(*self, Action::Bundle(_))
+    }
+
+    /// The variant's discriminant, as in the encoded action.
+    pub fn action_type(&self) -> u8 {
+        match self {
+            Action::Nothing => 0,
+            Action::SetCurrentTransactionBatchStatus { .. } => 1,
+            Action::BatchTransferEsdtToken { .. } => 2,
+            Action::SetChainTransactionBatchStatus { .. } => 3,
+            Action::SetCurrentNftBatchStatus { .. } => 4,
+            Action::BatchTransferNft { .. } => 5,
+            Action::ForceReexecuteBatch { .. } => 6,
+            Action::AddProposer(_) => 7,
+            Action::RemoveProposer(_) => 8,
+            Action::Bundle(_) => 9,
+            Action::ResetDailyUsdVolume => 10,
+            Action::AddTrustedForwarder(_) => 11,
+            Action::RemoveTrustedForwarder(_) => 12,
+            Action::SweepExpiredRefunds { .. } => 13,
+            Action::ReverseSlash(_) => 14,
+            Action::ReleaseQuarantinedTransfers(_) => 15,
+            Action::RefundQuarantinedTransfers(_) => 16,
+            Action::SetMultipleTransactionBatchStatuses { .. } => 17,
+            Action::AddExecutor(_) => 18,
+            Action::RemoveExecutor(_) => 19,
+            Action::SetSlashTier { .. } => 20,
+            Action::AttestRemoteReserves { .. } => 21,
+            Action::AddBoardMember(_) => 22,
+            Action::RemoveUser(_) => 23,
+            Action::ChangeQuorum(_) => 24,
+            Action::SlashBoardMember { .. } => 25,
+            Action::BatchTransferEsdtTokenWithEgldUnwrap { .. } => 26,
+            Action::MigrateTokenMode { .. } => 27,
+        }
+    }
+}
+
+/// A pending action, along with the addresses of the users that signed it, see `getPendingActionFullInfo`.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct ActionFullInfo<M: ManagedTypeApi> {
+    pub action_id: usize,
+    pub action_data: Action<M>,
+    pub signers: ManagedVec<M, ManagedAddress<M>>,
+}
+
+/// Result of the last attempt to perform an action.
+/// Failed actions are kept, and may be performed again.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    ManagedVecItem,
+    Clone,
+    Copy,
+    PartialEq,
+)]
+pub enum ActionExecutionStatus {
+    None,
+    Executed,
+    Failed,
+    /// The child contract call was made, and its callback did not arrive yet.
+    Pending,
+}
+
+/// Outcome of signing one of the actions passed to `signBatch`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum ActionSignResult {
+    Signed,
+    AlreadySigned,
+    ActionDoesNotExist,
+    StaleAction,
+    ActionExpired,
 }
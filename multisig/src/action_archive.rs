@@ -0,0 +1,75 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::action::{Action, ActionExecutionStatus};
+
+pub type ActionHash<M> = ManagedByteArray<M, 32>;
+
+/// What is left of an action once it was performed.
+/// `action_hash` is the keccak256 of the encoded action, so the archived entry
+/// can be matched against the proposal (e.g. from the proposal transaction or an indexer).
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct ArchivedAction<M: ManagedTypeApi> {
+    pub action_id: usize,
+    pub action_hash: ActionHash<M>,
+    pub block_nonce: u64,
+    pub executor: ManagedAddress<M>,
+    pub result: ActionExecutionStatus,
+}
+
+/// Keeps the history of performed actions, which are otherwise cleared from storage.
+/// Every execution attempt is archived, failed ones included.
+#[multiversx_sc::module]
+pub trait ActionArchiveModule {
+    fn archive_action(
+        &self,
+        action_id: usize,
+        action: &Action<Self::Api>,
+        result: ActionExecutionStatus,
+    ) {
+        let mut encoded_action = ManagedBuffer::new();
+        let _ = action.top_encode(&mut encoded_action);
+
+        self.action_archive().push(&ArchivedAction {
+            action_id,
+            action_hash: self.crypto().keccak256(&encoded_action),
+            block_nonce: self.blockchain().get_block_nonce(),
+            executor: self.action_executor().get(),
+            result,
+        });
+    }
+
+    #[view(getActionArchiveLength)]
+    fn get_action_archive_length(&self) -> usize {
+        self.action_archive().len()
+    }
+
+    /// Archived actions, oldest first, for at most `count` entries starting with `from_index`.
+    /// Entries are numbered from 1.
+    #[view(getActionArchive)]
+    fn get_action_archive(
+        &self,
+        from_index: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ArchivedAction<Self::Api>> {
+        let archive_mapper = self.action_archive();
+        let first_index = core::cmp::max(from_index, 1);
+        let last_index =
+            core::cmp::min(first_index.saturating_add(count), archive_mapper.len() + 1);
+
+        let mut result = MultiValueEncoded::new();
+        for index in first_index..last_index {
+            result.push(archive_mapper.get(index));
+        }
+
+        result
+    }
+
+    #[storage_mapper("actionArchive")]
+    fn action_archive(&self) -> VecMapper<ArchivedAction<Self::Api>>;
+
+    /// Caller of the `performAction` in progress. Child contract calls are asynchronous,
+    /// so it is saved until the callback archives the action.
+    #[storage_mapper("actionExecutor")]
+    fn action_executor(&self) -> SingleValueMapper<ManagedAddress>;
+}
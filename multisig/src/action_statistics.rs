@@ -0,0 +1,85 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Number of actions proposed, executed and discarded during one epoch.
+/// Discarded actions are the ones cleared without being executed,
+/// e.g. competing proposals for an already settled batch.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct EpochActionStatistics {
+    pub epoch: u64,
+    pub proposed: u64,
+    pub executed: u64,
+    pub discarded: u64,
+}
+
+/// Per-epoch action counters, as a throughput metric for relayer operators.
+/// Only epochs with at least one counted action get an entry.
+#[multiversx_sc::module]
+pub trait ActionStatisticsModule {
+    fn record_proposed_action(&self) {
+        self.update_current_epoch_statistics(|statistics| statistics.proposed += 1);
+    }
+
+    fn record_executed_action(&self) {
+        self.update_current_epoch_statistics(|statistics| statistics.executed += 1);
+    }
+
+    fn record_discarded_action(&self) {
+        self.update_current_epoch_statistics(|statistics| statistics.discarded += 1);
+    }
+
+    fn update_current_epoch_statistics<F: FnOnce(&mut EpochActionStatistics)>(&self, f: F) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut statistics_mapper = self.action_statistics();
+        let last_index = statistics_mapper.len();
+        if last_index > 0 {
+            let mut last_statistics = statistics_mapper.get(last_index);
+            if last_statistics.epoch == current_epoch {
+                f(&mut last_statistics);
+                statistics_mapper.set(last_index, &last_statistics);
+
+                return;
+            }
+        }
+
+        let mut statistics = EpochActionStatistics {
+            epoch: current_epoch,
+            proposed: 0,
+            executed: 0,
+            discarded: 0,
+        };
+        f(&mut statistics);
+        statistics_mapper.push(&statistics);
+    }
+
+    #[view(getActionStatisticsLength)]
+    fn get_action_statistics_length(&self) -> usize {
+        self.action_statistics().len()
+    }
+
+    /// Statistics for at most `count` epochs, oldest first, starting with entry `from_index`.
+    /// Entries are numbered from 1.
+    #[view(getActionStatistics)]
+    fn get_action_statistics(
+        &self,
+        from_index: usize,
+        count: usize,
+    ) -> MultiValueEncoded<EpochActionStatistics> {
+        let statistics_mapper = self.action_statistics();
+        let first_index = core::cmp::max(from_index, 1);
+        let last_index = core::cmp::min(
+            first_index.saturating_add(count),
+            statistics_mapper.len() + 1,
+        );
+
+        let mut result = MultiValueEncoded::new();
+        for index in first_index..last_index {
+            result.push(statistics_mapper.get(index));
+        }
+
+        result
+    }
+
+    #[storage_mapper("actionStatistics")]
+    fn action_statistics(&self) -> VecMapper<EpochActionStatistics>;
+}
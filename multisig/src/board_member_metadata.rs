@@ -0,0 +1,93 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use bridge_errors::{
+    INVALID_OPERATOR_NAME_LENGTH_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_REGISTER_METADATA_ERR_MSG,
+};
+
+use crate::user_role::UserRole;
+
+pub const MAX_OPERATOR_NAME_LEN: usize = 64;
+
+pub type UrlHash<M> = ManagedByteArray<M, 32>;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct BoardMemberMetadata<M: ManagedTypeApi> {
+    pub operator_name: ManagedBuffer<M>,
+    /// Hash of the URL where the operator publishes the relayer's status.
+    /// Only the hash is kept, the URL itself is shared off-chain.
+    pub endpoint_url_hash: UrlHash<M>,
+}
+
+/// Lets board members say who runs their relayer, without an off-chain registry.
+#[multiversx_sc::module]
+pub trait BoardMemberMetadataModule:
+    crate::storage::StorageModule + crate::util::UtilModule
+{
+    /// Registers or replaces the caller's metadata.
+    #[endpoint(registerBoardMemberMetadata)]
+    fn register_board_member_metadata(
+        &self,
+        operator_name: ManagedBuffer,
+        endpoint_url_hash: UrlHash<Self::Api>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_REGISTER_METADATA_ERR_MSG
+        );
+
+        let name_len = operator_name.len();
+        require!(
+            name_len > 0 && name_len <= MAX_OPERATOR_NAME_LEN,
+            INVALID_OPERATOR_NAME_LENGTH_ERR_MSG
+        );
+
+        self.board_member_metadata(&caller)
+            .set(&BoardMemberMetadata {
+                operator_name,
+                endpoint_url_hash,
+            });
+    }
+
+    /// Metadata is kept after a board member leaves the board, so it can still be looked up here.
+    #[view(getBoardMemberMetadata)]
+    fn get_board_member_metadata(
+        &self,
+        board_member: ManagedAddress,
+    ) -> OptionalValue<BoardMemberMetadata<Self::Api>> {
+        let metadata_mapper = self.board_member_metadata(&board_member);
+        if metadata_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(metadata_mapper.get())
+    }
+
+    /// Current board members that registered metadata, as (address, operator name, endpoint URL hash).
+    #[view(getAllBoardMemberMetadata)]
+    #[label("queries")]
+    fn get_all_board_member_metadata(
+        &self,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, ManagedBuffer, UrlHash<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for board_member in self.get_all_users_with_role(UserRole::BoardMember) {
+            let metadata_mapper = self.board_member_metadata(&board_member);
+            if metadata_mapper.is_empty() {
+                continue;
+            }
+
+            let metadata = metadata_mapper.get();
+            result.push(
+                (
+                    board_member,
+                    metadata.operator_name,
+                    metadata.endpoint_url_hash,
+                )
+                    .into(),
+            );
+        }
+
+        result
+    }
+}
@@ -0,0 +1,13 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use transaction::Transaction;
+
+/// Everything a user has pending or claimable in the bridge contracts.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct UserBridgeActivity<M: ManagedTypeApi> {
+    pub pending_transactions: ManagedVec<M, Transaction<M>>,
+    pub refunds: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub nft_refunds: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub amount_staked: BigUint<M>,
+}
@@ -0,0 +1,152 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use bridge_errors::{
+    BOARD_ABOVE_MAX_SIZE_ERR_MSG, BOARD_MEMBER_NOT_STAKED_ERR_MSG, EMPTY_BOARD_ERR_MSG,
+    QUORUM_ABOVE_BOARD_SIZE_ERR_MSG, QUORUM_BELOW_MINIMUM_ERR_MSG,
+    SUPER_QUORUM_OUT_OF_RANGE_ERR_MSG,
+};
+
+use config_events_module::NO_ACTION_ID;
+
+use crate::user_role::UserRole;
+
+/// Quorum can never be lower than this, regardless of the configured minimum.
+pub const ABSOLUTE_MIN_QUORUM: usize = 1;
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone, Copy,
+)]
+pub enum ConfigViolation {
+    EmptyBoard,
+    QuorumAboveBoardSize,
+    QuorumBelowMinimum,
+    SuperQuorumOutOfRange,
+    BoardMemberNotStaked,
+    BoardAboveMaxSize,
+}
+
+impl ConfigViolation {
+    pub fn error_message(&self) -> &'static [u8] {
+        match self {
+            ConfigViolation::EmptyBoard => EMPTY_BOARD_ERR_MSG.as_bytes(),
+            ConfigViolation::QuorumAboveBoardSize => QUORUM_ABOVE_BOARD_SIZE_ERR_MSG.as_bytes(),
+            ConfigViolation::QuorumBelowMinimum => QUORUM_BELOW_MINIMUM_ERR_MSG.as_bytes(),
+            ConfigViolation::SuperQuorumOutOfRange => SUPER_QUORUM_OUT_OF_RANGE_ERR_MSG.as_bytes(),
+            ConfigViolation::BoardMemberNotStaked => BOARD_MEMBER_NOT_STAKED_ERR_MSG.as_bytes(),
+            ConfigViolation::BoardAboveMaxSize => BOARD_ABOVE_MAX_SIZE_ERR_MSG.as_bytes(),
+        }
+    }
+}
+
+#[multiversx_sc::module]
+pub trait ConfigValidationModule:
+    crate::storage::StorageModule + crate::util::UtilModule + config_events_module::ConfigEventsModule
+{
+    /// Sets the minimum value the quorum can be set to.
+    /// The current quorum must already satisfy the new minimum.
+    #[only_owner]
+    #[endpoint(setMinQuorum)]
+    fn set_min_quorum(&self, min_quorum: usize) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"minQuorum");
+        let old_value = self.config_value(&parameter_key);
+        self.min_quorum().set(min_quorum);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+        self.require_valid_board_config();
+    }
+
+    /// Sets the maximum number of board members, 0 meaning no maximum.
+    /// The current board must already fit, board members have to be removed first otherwise.
+    #[only_owner]
+    #[endpoint(setMaxBoardSize)]
+    fn set_max_board_size(&self, max_board_size: usize) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"maxBoardSize");
+        let old_value = self.config_value(&parameter_key);
+        self.max_board_size().set(max_board_size);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+        self.require_valid_board_config();
+    }
+
+    /// Number of board members that can still be added before reaching the maximum board size.
+    /// Returns nothing if there is no maximum.
+    #[view(getRemainingBoardCapacity)]
+    fn get_remaining_board_capacity(&self) -> OptionalValue<usize> {
+        let max_board_size = self.max_board_size().get();
+        if max_board_size == 0 {
+            return OptionalValue::None;
+        }
+
+        let num_board_members = self.num_board_members().get();
+        OptionalValue::Some(max_board_size.saturating_sub(num_board_members))
+    }
+
+    /// Lists all the current violations of the board and quorum invariants:
+    /// - quorum is at least the configured minimum (and at least 1)
+    /// - quorum does not exceed the board size
+    /// - the board is not empty
+    /// - the super quorum, if set, is between the quorum and the board size
+    /// - the board size does not exceed the maximum, if set
+    /// - every board member has staked the required amount
+    ///
+    /// All but the last are enforced on every board or quorum change. The stake is only reported,
+    /// since new board members can only stake after being added.
+    /// Use `getAllStakedRelayers` to find the board members that did not stake.
+    #[view(validateConfig)]
+    fn validate_config(&self) -> MultiValueEncoded<ConfigViolation> {
+        let mut violations = self.get_board_config_violations();
+        let all_staked = self
+            .get_all_users_with_role(UserRole::BoardMember)
+            .into_iter()
+            .all(|board_member| self.has_enough_stake(&board_member));
+        if !all_staked {
+            violations.push(ConfigViolation::BoardMemberNotStaked);
+        }
+
+        violations.into()
+    }
+
+    fn get_board_config_violations(&self) -> ManagedVec<ConfigViolation> {
+        let quorum = self.quorum().get();
+        let num_board_members = self.num_board_members().get();
+        let min_quorum = core::cmp::max(self.min_quorum().get(), ABSOLUTE_MIN_QUORUM);
+
+        let mut violations = ManagedVec::new();
+        if num_board_members == 0 {
+            violations.push(ConfigViolation::EmptyBoard);
+        }
+        if quorum > num_board_members {
+            violations.push(ConfigViolation::QuorumAboveBoardSize);
+        }
+        if quorum < min_quorum {
+            violations.push(ConfigViolation::QuorumBelowMinimum);
+        }
+
+        let super_quorum = self.super_quorum().get();
+        if super_quorum > 0 && (super_quorum < quorum || super_quorum > num_board_members) {
+            violations.push(ConfigViolation::SuperQuorumOutOfRange);
+        }
+
+        let max_board_size = self.max_board_size().get();
+        if max_board_size > 0 && num_board_members > max_board_size {
+            violations.push(ConfigViolation::BoardAboveMaxSize);
+        }
+
+        violations
+    }
+
+    /// Must be called after every change of the board or the quorum.
+    fn require_valid_board_config(&self) {
+        let violations = self.get_board_config_violations();
+        if let Some(violation) = violations.iter().next() {
+            sc_panic!(violation.error_message());
+        }
+    }
+
+    #[view(getMinQuorum)]
+    #[storage_mapper("minQuorum")]
+    fn min_quorum(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxBoardSize)]
+    #[storage_mapper("maxBoardSize")]
+    fn max_board_size(&self) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,219 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    BOARD_MEMBERS_CANNOT_DELEGATE_ERR_MSG, DELEGATION_POOL_SLASHED_ERR_MSG,
+    DELEGATION_TO_NON_BOARD_MEMBER_ERR_MSG, UNDELEGATE_MORE_THAN_DELEGATED_ERR_MSG,
+};
+
+/// Users that are not board members can delegate the staking token to a board member,
+/// adding to the member's effective stake, see `getEffectiveStake`.
+///
+/// The stake delegated to a board member is kept in a pool, in which each delegator holds shares.
+/// When the board member is slashed, the slash is split between the member's own stake
+/// and the pool, in proportion to their sizes, so every delegator loses the same fraction.
+/// Reversing the slash gives the pool its part back.
+#[multiversx_sc::module]
+pub trait DelegationModule: crate::storage::StorageModule + crate::util::UtilModule {
+    #[payable("*")]
+    #[endpoint]
+    fn delegate(&self, board_member: ManagedAddress) {
+        let payment = self.get_staking_payment();
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.get_user_role(&caller).is_board_member(),
+            BOARD_MEMBERS_CANNOT_DELEGATE_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&board_member).is_board_member(),
+            DELEGATION_TO_NON_BOARD_MEMBER_ERR_MSG
+        );
+
+        let delegated_stake = self.total_delegated_stake(&board_member).get();
+        let total_shares = self.total_delegation_shares(&board_member).get();
+        let new_shares = if total_shares == 0 {
+            payment.clone()
+        } else {
+            require!(delegated_stake > 0, DELEGATION_POOL_SLASHED_ERR_MSG);
+            &payment * &total_shares / &delegated_stake
+        };
+
+        self.delegation_shares(&caller, &board_member)
+            .update(|shares| *shares += &new_shares);
+        self.total_delegation_shares(&board_member)
+            .set(total_shares + new_shares);
+        self.total_delegated_stake(&board_member)
+            .set(delegated_stake + &payment);
+        self.delegators(&board_member).insert(caller.clone());
+        self.delegated_board_members(&caller)
+            .insert(board_member.clone());
+
+        self.stake_delegated_event(&caller, &board_member, &payment);
+    }
+
+    /// Stake can be undelegated at any time, including from former board members.
+    #[endpoint]
+    fn undelegate(&self, board_member: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let delegated_amount = self.get_delegated_stake(caller.clone(), board_member.clone());
+        require!(
+            amount <= delegated_amount,
+            UNDELEGATE_MORE_THAN_DELEGATED_ERR_MSG
+        );
+
+        let shares_mapper = self.delegation_shares(&caller, &board_member);
+        let shares = shares_mapper.get();
+        let total_shares = self.total_delegation_shares(&board_member).get();
+        let delegated_stake = self.total_delegated_stake(&board_member).get();
+
+        // rounded up, so the remaining delegators are never diluted
+        let burned_shares = if amount == delegated_amount {
+            shares.clone()
+        } else {
+            let rounded_up = (&amount * &total_shares + &delegated_stake - 1u32) / &delegated_stake;
+            core::cmp::min(rounded_up, shares.clone())
+        };
+
+        let remaining_shares = &shares - &burned_shares;
+        if remaining_shares == 0 {
+            shares_mapper.clear();
+            self.delegators(&board_member).swap_remove(&caller);
+            self.delegated_board_members(&caller)
+                .swap_remove(&board_member);
+        } else {
+            shares_mapper.set(&remaining_shares);
+        }
+        self.total_delegation_shares(&board_member)
+            .set(total_shares - burned_shares);
+        self.total_delegated_stake(&board_member)
+            .set(delegated_stake - &amount);
+
+        self.send_staking_token(&caller, &amount);
+
+        self.stake_undelegated_event(&caller, &board_member, &amount);
+    }
+
+    /// The amount the delegator can currently undelegate from the board member.
+    #[view(getDelegatedStake)]
+    fn get_delegated_stake(
+        &self,
+        delegator: ManagedAddress,
+        board_member: ManagedAddress,
+    ) -> BigUint {
+        let total_shares = self.total_delegation_shares(&board_member).get();
+        if total_shares == 0 {
+            return BigUint::zero();
+        }
+
+        self.delegation_shares(&delegator, &board_member).get()
+            * self.total_delegated_stake(&board_member).get()
+            / total_shares
+    }
+
+    /// Pairs of (board member, delegated amount), for every board member the delegator delegated to.
+    #[view(getDelegations)]
+    #[label("queries")]
+    fn get_delegations(
+        &self,
+        delegator: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for board_member in self.delegated_board_members(&delegator).iter() {
+            let amount = self.get_delegated_stake(delegator.clone(), board_member.clone());
+            result.push((board_member, amount).into());
+        }
+
+        result
+    }
+
+    /// Pairs of (delegator, delegated amount), for every delegator of the board member.
+    #[view(getDelegators)]
+    #[label("queries")]
+    fn get_delegators(
+        &self,
+        board_member: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for delegator in self.delegators(&board_member).iter() {
+            let amount = self.get_delegated_stake(delegator.clone(), board_member.clone());
+            result.push((delegator, amount).into());
+        }
+
+        result
+    }
+
+    /// The board member's own stake plus the stake delegated to them.
+    #[view(getEffectiveStake)]
+    fn get_effective_stake(&self, board_member: ManagedAddress) -> BigUint {
+        self.amount_staked(&board_member).get() + self.total_delegated_stake(&board_member).get()
+    }
+
+    /// Takes the delegators' part of a slash out of the pool, and returns it.
+    fn slash_delegated_stake(
+        &self,
+        board_member: &ManagedAddress,
+        slash_amount: &BigUint,
+    ) -> BigUint {
+        let delegated_stake = self.total_delegated_stake(board_member).get();
+        if delegated_stake == 0 {
+            return BigUint::zero();
+        }
+
+        let own_stake = self.amount_staked(board_member).get();
+        let delegated_slash_amount =
+            slash_amount * &delegated_stake / (own_stake + &delegated_stake);
+        self.total_delegated_stake(board_member)
+            .set(delegated_stake - &delegated_slash_amount);
+
+        delegated_slash_amount
+    }
+
+    // events
+
+    #[event("stakeDelegated")]
+    fn stake_delegated_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("stakeUndelegated")]
+    fn stake_undelegated_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    // storage
+
+    #[view(getTotalDelegatedStake)]
+    #[storage_mapper("totalDelegatedStake")]
+    fn total_delegated_stake(&self, board_member: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalDelegationShares")]
+    fn total_delegation_shares(&self, board_member: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("delegationShares")]
+    fn delegation_shares(
+        &self,
+        delegator: &ManagedAddress,
+        board_member: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("delegators")]
+    fn delegators(&self, board_member: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("delegatedBoardMembers")]
+    fn delegated_board_members(
+        &self,
+        delegator: &ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// The delegators' part of the slash held in escrow, see `getEscrowedSlashAmount`.
+    #[storage_mapper("escrowedDelegatedSlashAmount")]
+    fn escrowed_delegated_slash_amount(
+        &self,
+        board_member: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,192 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    ACTION_DOES_NOT_EXIST_ERR_MSG, ATTESTATIONS_DO_NOT_CONFLICT_ERR_MSG,
+    INVALID_ETH_PUBLIC_KEY_ERR_MSG, INVALID_ETH_SIGNATURE_ERR_MSG, NOT_A_BOARD_MEMBER_ERR_MSG,
+    NOT_ENOUGH_STAKE_ERR_MSG, NO_ETH_PUBLIC_KEY_ERR_MSG, ONBOARDING_NOT_COMPLETED_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_REGISTER_KEYS_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_SIGN_ERR_MSG,
+};
+use eth_signature_module::Hash;
+use transaction::eip712::{action_attestation_digest, key_registration_digest};
+use transaction::transaction_status::TransactionStatus;
+
+use crate::action::Action;
+use crate::slash_offense::SlashOffense;
+
+#[multiversx_sc::module]
+pub trait EthAttestationModule:
+    crate::multisig_general::MultisigGeneralModule
+    + crate::accounting_snapshot::AccountingSnapshotModule
+    + crate::delegation::DelegationModule
+    + crate::action_statistics::ActionStatisticsModule
+    + crate::config_validation::ConfigValidationModule
+    + crate::setup::SetupModule
+    + crate::storage::StorageModule
+    + crate::util::UtilModule
+    + bridge_domain_module::BridgeDomainModule
+    + config_events_module::ConfigEventsModule
+    + eth_signature_module::EthSignatureModule
+    + crate::pause_status::PauseStatusModule
+    + crate::action_archive::ActionArchiveModule
+    + execution_guard_module::ExecutionGuardModule
+{
+    /// Board members register the uncompressed public key (65 bytes, `0x04 || X || Y`)
+    /// of the Ethereum account they use to sign attestations on the Ethereum side.
+    /// `signature` is made with that key over `getEthKeyRegistrationHash` for the caller,
+    /// as proof that they own the key.
+    ///
+    /// Signatures produced by this key can then be used instead of calling `sign`.
+    #[endpoint(registerEthPublicKey)]
+    fn register_eth_public_key(&self, public_key: ManagedBuffer, signature: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_REGISTER_KEYS_ERR_MSG
+        );
+        require!(
+            self.is_valid_eth_public_key(&public_key),
+            INVALID_ETH_PUBLIC_KEY_ERR_MSG
+        );
+        require!(
+            self.verify_eth_signature(
+                &public_key,
+                &self.get_eth_key_registration_hash(caller.clone()),
+                &signature
+            ),
+            INVALID_ETH_SIGNATURE_ERR_MSG
+        );
+
+        self.eth_public_key(&caller).set(&public_key);
+    }
+
+    /// The hash a board member signs with their Ethereum key to register it, see `registerEthPublicKey`.
+    #[view(getEthKeyRegistrationHash)]
+    fn get_eth_key_registration_hash(&self, board_member: ManagedAddress) -> Hash<Self::Api> {
+        key_registration_digest(
+            &self.get_configured_bridge_domain(),
+            &self.blockchain().get_sc_address(),
+            &board_member,
+        )
+    }
+
+    /// The hash attested by board members' Ethereum keys for the given action.
+    /// It covers this contract's address, the action ID and the action, encoded as it is kept in storage,
+    /// in this bridge's domain (see `transaction::eip712::action_attestation_digest`).
+    #[view(getActionAttestationHash)]
+    fn get_action_attestation_hash(&self, action_id: usize) -> Hash<Self::Api> {
+        require!(
+            !self.action_mapper().item_is_empty_unchecked(action_id),
+            ACTION_DOES_NOT_EXIST_ERR_MSG
+        );
+
+        let action = self.action_mapper().get(action_id);
+
+        self.hash_action(action_id, &action)
+    }
+
+    /// Anyone can submit proof that a board member attested two different lists of statuses
+    /// for the same EsdtSafe batch, under the same action ID. Both signatures must be made
+    /// with the member's registered Ethereum key, over the attestation hash of the respective
+    /// `SetCurrentTransactionBatchStatus` action (see `getActionAttestationHash`).
+    ///
+    /// Action IDs are never reused, so a corrected proposal is a new action,
+    /// and re-signing it is not evidence against the board member.
+    ///
+    /// The actions don't need to have been proposed. If the evidence is valid,
+    /// the board member is slashed, same as with `slashBoardMember`,
+    /// even if the board is at its minimum size.
+    #[endpoint(submitEquivocationEvidence)]
+    fn submit_equivocation_evidence(
+        &self,
+        board_member: ManagedAddress,
+        action_id: usize,
+        esdt_safe_batch_id: u64,
+        first_statuses: ManagedVec<TransactionStatus>,
+        first_signature: ManagedBuffer,
+        second_statuses: ManagedVec<TransactionStatus>,
+        second_signature: ManagedBuffer,
+    ) {
+        require!(
+            self.get_user_role(&board_member).is_board_member(),
+            NOT_A_BOARD_MEMBER_ERR_MSG
+        );
+
+        let public_key_mapper = self.eth_public_key(&board_member);
+        require!(!public_key_mapper.is_empty(), NO_ETH_PUBLIC_KEY_ERR_MSG);
+        let public_key = public_key_mapper.get();
+
+        let first_hash = self.hash_action(
+            action_id,
+            &Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status: first_statuses,
+            },
+        );
+        let second_hash = self.hash_action(
+            action_id,
+            &Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status: second_statuses,
+            },
+        );
+        require!(
+            first_hash != second_hash,
+            ATTESTATIONS_DO_NOT_CONFLICT_ERR_MSG
+        );
+        require!(
+            self.verify_eth_signature(&public_key, &first_hash, &first_signature)
+                && self.verify_eth_signature(&public_key, &second_hash, &second_signature),
+            INVALID_ETH_SIGNATURE_ERR_MSG
+        );
+
+        self.slash(&board_member, SlashOffense::Equivocation);
+    }
+
+    /// Adds the signers of the given Ethereum-origin attestations to the action.
+    /// Each attestation is a pair of (board member address, 65-byte Ethereum signature).
+    fn add_eth_signatures(
+        &self,
+        action_id: usize,
+        signatures: MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>>,
+    ) {
+        let attestation_hash = self.get_action_attestation_hash(action_id);
+
+        for pair in signatures {
+            let (signer, signature) = pair.into_tuple();
+            let signer_id = self.user_mapper().get_user_id(&signer);
+            require!(
+                self.user_id_to_role(signer_id).get().is_board_member(),
+                ONLY_BOARD_MEMBERS_CAN_SIGN_ERR_MSG
+            );
+            require!(self.has_enough_stake(&signer), NOT_ENOUGH_STAKE_ERR_MSG);
+            require!(
+                self.has_completed_onboarding(&signer),
+                ONBOARDING_NOT_COMPLETED_ERR_MSG
+            );
+
+            let public_key_mapper = self.eth_public_key(&signer);
+            require!(!public_key_mapper.is_empty(), NO_ETH_PUBLIC_KEY_ERR_MSG);
+            require!(
+                self.verify_eth_signature(&public_key_mapper.get(), &attestation_hash, &signature),
+                INVALID_ETH_SIGNATURE_ERR_MSG
+            );
+
+            self.add_signature(action_id, signer_id);
+        }
+    }
+
+    fn hash_action(&self, action_id: usize, action: &Action<Self::Api>) -> Hash<Self::Api> {
+        let serialized = self.serialize_action(action);
+
+        action_attestation_digest(
+            &self.get_configured_bridge_domain(),
+            &self.blockchain().get_sc_address(),
+            action_id,
+            &serialized,
+        )
+    }
+
+    #[view(getEthPublicKey)]
+    #[storage_mapper("ethPublicKey")]
+    fn eth_public_key(&self, board_member: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+}
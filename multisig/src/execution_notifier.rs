@@ -0,0 +1,94 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    EXECUTION_NOTIFIER_NOT_SC_ERR_MSG, ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_SET_NOTIFIER_ERR_MSG,
+};
+
+const BATCH_TRANSFER_EXECUTED_ENDPOINT: &[u8] = b"onBatchTransferExecuted";
+const NOTIFICATION_GAS_LIMIT: u64 = 5_000_000;
+
+/// Lets relayer pipelines built as smart contracts react to the batches they proposed.
+///
+/// Once a `BatchTransferEsdtToken` action is executed, the notifier its proposer had registered
+/// when proposing it gets an `onBatchTransferExecuted(action_id, eth_batch_id)` call.
+/// The call is fire-and-forget: its result is not checked, and it is skipped if there is not enough gas left.
+#[multiversx_sc::module]
+pub trait ExecutionNotifierModule: crate::storage::StorageModule + crate::util::UtilModule {
+    /// Sets the contract notified when the caller's batch transfer proposals are executed,
+    /// replacing the previous one. Only applies to actions proposed afterwards.
+    #[endpoint(setExecutionNotifier)]
+    fn set_execution_notifier(&self, notifier: ManagedAddress) {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).can_propose(),
+            ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_SET_NOTIFIER_ERR_MSG
+        );
+        require!(
+            self.blockchain().is_smart_contract(&notifier),
+            EXECUTION_NOTIFIER_NOT_SC_ERR_MSG
+        );
+
+        self.execution_notifier(&caller).set(&notifier);
+    }
+
+    #[endpoint(removeExecutionNotifier)]
+    fn remove_execution_notifier(&self) {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        self.execution_notifier(&caller).clear();
+    }
+
+    #[view(getExecutionNotifier)]
+    fn get_execution_notifier(&self, proposer: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let notifier_mapper = self.execution_notifier(&proposer);
+        if notifier_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(notifier_mapper.get())
+    }
+
+    #[view(getActionExecutionNotifier)]
+    fn get_action_execution_notifier(&self, action_id: usize) -> OptionalValue<ManagedAddress> {
+        let notifier_mapper = self.action_execution_notifier(action_id);
+        if notifier_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(notifier_mapper.get())
+    }
+
+    // private
+
+    fn record_action_execution_notifier(&self, action_id: usize) {
+        let proposer = self.get_acting_user(&self.blockchain().get_caller());
+        let notifier_mapper = self.execution_notifier(&proposer);
+        if !notifier_mapper.is_empty() {
+            self.action_execution_notifier(action_id)
+                .set(notifier_mapper.get());
+        }
+    }
+
+    fn notify_batch_transfer_executed(&self, action_id: usize, eth_batch_id: u64) {
+        let notifier_mapper = self.action_execution_notifier(action_id);
+        if notifier_mapper.is_empty() {
+            return;
+        }
+
+        let notifier = notifier_mapper.take();
+        if self.blockchain().get_gas_left() <= NOTIFICATION_GAS_LIMIT {
+            return;
+        }
+
+        self.send()
+            .contract_call::<()>(notifier, BATCH_TRANSFER_EXECUTED_ENDPOINT)
+            .argument(&action_id)
+            .argument(&eth_batch_id)
+            .with_gas_limit(NOTIFICATION_GAS_LIMIT)
+            .transfer_execute();
+    }
+
+    // storage
+
+    #[storage_mapper("executionNotifier")]
+    fn execution_notifier(&self, proposer: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+}
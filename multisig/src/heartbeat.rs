@@ -0,0 +1,47 @@
+multiversx_sc::imports!();
+
+use crate::user_role::UserRole;
+use bridge_errors::ONLY_BOARD_MEMBERS_CAN_SEND_HEARTBEATS_ERR_MSG;
+
+/// A relayer is considered active if it sent a heartbeat
+/// in the current epoch or in the previous one.
+const ACTIVE_RELAYER_EPOCHS: u64 = 1;
+
+#[multiversx_sc::module]
+pub trait HeartbeatModule: crate::storage::StorageModule + crate::util::UtilModule {
+    /// Board members call this periodically (at least once per epoch)
+    /// to signal they are online, even if there are no batches to sign.
+    #[endpoint]
+    fn heartbeat(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_SEND_HEARTBEATS_ERR_MSG
+        );
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.last_heartbeat_epoch(&caller).set(current_epoch);
+    }
+
+    /// Number of board members that sent a heartbeat in the current or the previous epoch.
+    #[view(getActiveRelayerCount)]
+    #[label("queries")]
+    fn get_active_relayer_count(&self) -> usize {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let min_epoch = current_epoch.saturating_sub(ACTIVE_RELAYER_EPOCHS);
+
+        self.get_all_users_with_role(UserRole::BoardMember)
+            .to_vec()
+            .iter()
+            .filter(|board_member| {
+                let heartbeat_mapper = self.last_heartbeat_epoch(board_member);
+
+                !heartbeat_mapper.is_empty() && heartbeat_mapper.get() >= min_epoch
+            })
+            .count()
+    }
+
+    #[view(getLastHeartbeatEpoch)]
+    #[storage_mapper("lastHeartbeatEpoch")]
+    fn last_heartbeat_epoch(&self, board_member: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -1,41 +1,142 @@
 #![no_std]
 #![allow(clippy::too_many_arguments)]
 
-mod action;
-mod multisig_general;
-mod queries;
-mod setup;
-mod storage;
-mod user_role;
-mod util;
-
-use action::Action;
-use token_module::{AddressPercentagePair, INVALID_PERCENTAGE_SUM_OVER_ERR_MSG, PERCENTAGE_TOTAL};
-use transaction::transaction_status::TransactionStatus;
+pub mod accounting_snapshot;
+pub mod action;
+pub mod action_archive;
+pub mod action_statistics;
+pub mod board_member_metadata;
+pub mod bridge_activity;
+pub mod config_validation;
+pub mod delegation;
+pub mod eth_attestation;
+pub mod execution_notifier;
+pub mod heartbeat;
+pub mod multisig_config;
+pub mod multisig_general;
+pub mod pause_status;
+pub mod queries;
+pub mod reserve_attestation;
+pub mod setup;
+pub mod signing_key;
+pub mod slash_offense;
+pub mod status_proposal;
+pub mod storage;
+pub mod supply_reconciliation;
+pub mod user_role;
+pub mod util;
+
+use action::{Action, ActionExecutionStatus, SUPPORTED_ACTIONS};
+use bridge_errors::{
+    ACTION_ALREADY_EXECUTED_ERR_MSG, ACTION_ALREADY_PROPOSED_ERR_MSG,
+    ACTION_ALREADY_VETOED_BY_CALLER_ERR_MSG, ACTION_CALL_NOT_PENDING_ERR_MSG,
+    ACTION_CALL_PENDING_ERR_MSG, ACTION_DOES_NOT_EXIST_ERR_MSG, ACTION_EXPIRED_ERR_MSG,
+    ACTION_NOT_STALE_ERR_MSG, BATCH_ALREADY_PROPOSED_ERR_MSG, BATCH_NOT_EXECUTED_ERR_MSG,
+    BOARD_ABOVE_MAX_SIZE_ERR_MSG, BUNDLED_ACTION_ALREADY_EXECUTED_ERR_MSG,
+    BUNDLED_ACTION_DOES_NOT_EXIST_ERR_MSG, BUNDLE_TOO_SMALL_ERR_MSG, CURRENT_BATCH_EMPTY_ERR_MSG,
+    DUPLICATE_BOARD_MEMBER_ERR_MSG, DUPLICATE_BUNDLED_ACTION_ERR_MSG,
+    DUPLICATE_RESERVE_TOKEN_ERR_MSG, ENDPOINT_NOT_ALLOWED_IN_MULTICALL_ERR_MSG,
+    ESDT_SAFE_NOT_SC_ERR_MSG, EXECUTION_TIMELOCK_NOT_ENDED_ERR_MSG, INVALID_BUNDLED_ACTION_ERR_MSG,
+    INVALID_MULTICALL_ARGUMENT_ERR_MSG, INVALID_NUMBER_OF_ARGUMENTS_ERR_MSG,
+    INVALID_PERCENTAGE_SUM_ERR_MSG, MULTI_TRANSFER_NOT_SC_ERR_MSG, NESTED_BUNDLE_ERR_MSG,
+    NOTHING_TO_PROPOSE_ERR_MSG, NOT_A_BOARD_MEMBER_ERR_MSG, NOT_ENOUGH_GAS_FOR_ACTION_CALL_ERR_MSG,
+    NOT_NEXT_BATCH_ID_ERR_MSG, NO_RESERVES_PROVIDED_ERR_MSG, NO_SLASH_UNDER_APPEAL_ERR_MSG,
+    NO_TRANSFERS_PROVIDED_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_ADD_BOARD_MEMBERS_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_AUTO_PROPOSE_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_CHANGE_QUORUM_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_MANAGE_EXECUTORS_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_MANAGE_PROPOSERS_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_PROPOSE_SLASH_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_REMOVE_BOARD_MEMBERS_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_STAKE_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_VETO_ERR_MSG, ONLY_BOARD_MEMBERS_CAN_VOTE_TO_DISCARD_ERR_MSG,
+    ONLY_BOARD_MEMBERS_PROPOSERS_AND_EXECUTORS_CAN_PERFORM_ERR_MSG,
+    PERFORMING_WHILE_PAUSED_ERR_MSG, QUORUM_ABOVE_BOARD_SIZE_ERR_MSG, QUORUM_NOT_REACHED_ERR_MSG,
+    QUORUM_SET_AS_PERCENTAGE_ERR_MSG, SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG,
+    STAKING_TOKEN_CHANGED_ERR_MSG, STALE_ACTION_ERR_MSG, STATUS_COUNT_MISMATCH_ERR_MSG,
+    SUPER_QUORUM_NOT_REACHED_ERR_MSG, TRANSFER_TO_SC_ERR_MSG, UNSTAKE_BELOW_MINIMUM_ERR_MSG,
+    UNSTAKE_MORE_THAN_STAKED_ERR_MSG, UNWRAP_EGLD_TX_NOT_IN_BATCH_ERR_MSG,
+    USER_ALREADY_HAS_ROLE_ERR_MSG, USER_NOT_EXECUTOR_ERR_MSG, USER_NOT_PROPOSER_ERR_MSG,
+    VETOES_DISABLED_ERR_MSG, WRONG_NFT_SAFE_BATCH_ID_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
+use eth_address::EthAddress;
+use migration_module::bridge_version::{
+    BridgeVersion, InterfaceVersion, TRANSFER_FIELD_DESTINATION_CHAIN,
+    TRANSFER_FIELD_NFT_ATTRIBUTES, TRANSFER_FIELD_UNWRAP_EGLD,
+};
+use migration_module::StorageVersion;
+use reserve_attestation::RemoteReserve;
+use slash_offense::SlashOffense;
+use token_module::{AddressPercentagePair, PERCENTAGE_TOTAL};
+use transaction::nft_transaction::{
+    join_nft_batch, EthNftTransaction, EthNftTxAsMultiValue, NftTxBatchSplitInFields,
+    NFT_TX_MULTIRESULT_NR_FIELDS,
+};
+use transaction::transaction_status::{
+    pack_batch_statuses, pack_statuses, BatchStatuses, TransactionStatus,
+};
 use transaction::TxBatchSplitInFields;
 use transaction::*;
 use user_role::UserRole;
 
+use esdt_safe::daily_limit::ProxyTrait as _;
+use esdt_safe::trusted_forwarders::ProxyTrait as _;
 use esdt_safe::ProxyTrait as _;
+use multi_transfer_esdt::unlock_mode::ProxyTrait as _;
 use multi_transfer_esdt::ProxyTrait as _;
+use nft_safe::ProxyTrait as _;
+use nft_transfer::ProxyTrait as _;
 use token_module::ProxyTrait as _;
 use tx_batch_module::ProxyTrait as _;
 
 multiversx_sc::imports!();
 
+const MULTICALL_SIGN_ENDPOINT: &[u8] = b"sign";
+const MULTICALL_PROPOSE_STATUS_ENDPOINT: &[u8] = b"proposeEsdtSafeSetCurrentTransactionBatchStatus";
+const MULTICALL_PROPOSE_TRANSFER_ENDPOINT: &[u8] = b"proposeMultiTransferEsdtBatch";
+const ETH_TX_NR_FIELDS: usize = 5;
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+/// Reserved for `perform_action_callback`, which clears the other actions proposed for the same batch.
+const PERFORM_ACTION_CALLBACK_GAS: u64 = 20_000_000;
+/// Left for the rest of `performAction`, after the child contract call is registered.
+const PERFORM_ACTION_GAS_RESERVE: u64 = 1_000_000;
+
 /// Multi-signature smart contract implementation.
 /// Acts like a wallet that needs multiple signers for any action performed.
 #[multiversx_sc::contract]
 pub trait Multisig:
     multisig_general::MultisigGeneralModule
+    + accounting_snapshot::AccountingSnapshotModule
+    + action_archive::ActionArchiveModule
+    + action_statistics::ActionStatisticsModule
+    + board_member_metadata::BoardMemberMetadataModule
+    + delegation::DelegationModule
+    + bridge_domain_module::BridgeDomainModule
+    + config_validation::ConfigValidationModule
+    + execution_notifier::ExecutionNotifierModule
     + setup::SetupModule
+    + signing_key::SigningKeyModule
     + storage::StorageModule
     + util::UtilModule
     + queries::QueriesModule
-    + multiversx_sc_modules::pause::PauseModule
+    + reserve_attestation::ReserveAttestationModule
+    + eth_attestation::EthAttestationModule
+    + eth_signature_module::EthSignatureModule
+    + heartbeat::HeartbeatModule
+    + migration_module::MigrationModule
+    + execution_guard_module::ExecutionGuardModule
+    + pause_status::PauseStatusModule
+    + storage_inspection_module::StorageInspectionModule
+    + config_events_module::ConfigEventsModule
 {
     /// EsdtSafe and MultiTransferEsdt are expected to be deployed and configured separately,
     /// and then having their ownership changed to this Multisig SC.
+    ///
+    /// slash_amount - Seeds the slash tiers of all the offenses.
+    /// On upgrade, it only seeds the tiers that are not set yet.
+    ///
+    /// staking_token - The token board members stake, EGLD or a fungible ESDT.
+    /// It cannot be changed once set, since stakes are held in it.
+    /// Contracts deployed before it could be chosen stake EGLD, so they can only be upgraded with EGLD.
     #[init]
     fn init(
         &self,
@@ -43,6 +144,7 @@ pub trait Multisig:
         multi_transfer_sc_address: ManagedAddress,
         required_stake: BigUint,
         slash_amount: BigUint,
+        staking_token: EgldOrEsdtTokenIdentifier,
         quorum: usize,
         board: MultiValueEncoded<ManagedAddress>,
     ) {
@@ -55,34 +157,72 @@ pub trait Multisig:
                 }
                 self.user_id_to_role(user_id).set(UserRole::BoardMember);
             });
-        require!(!duplicates, "duplicate board member");
+        require!(!duplicates, DUPLICATE_BOARD_MEMBER_ERR_MSG);
 
         self.num_board_members()
             .update(|nr_board_members| *nr_board_members += board_len);
-        self.change_quorum(quorum);
+        // on upgrade, a quorum set as a percentage replaces the given one
+        self.save_quorum(quorum, NO_ACTION_ID);
+        self.update_quorum_from_percentage();
+        self.require_valid_board_config();
 
         require!(
             slash_amount <= required_stake,
-            "slash amount must be less than or equal to required stake"
+            SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG
         );
         self.required_stake_amount().set(&required_stake);
-        self.slash_amount().set(&slash_amount);
+        // on upgrade, the slash tiers already set are kept, and only the missing ones are seeded
+        for offense in SlashOffense::ALL {
+            let slash_tier_mapper = self.slash_tier(offense);
+            if slash_tier_mapper.is_empty() {
+                slash_tier_mapper.set(&slash_amount);
+            }
+            require!(
+                slash_tier_mapper.get() <= required_stake,
+                SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG
+            );
+        }
+
+        let staking_token_mapper = self.staking_token();
+        // contracts deployed before the staking token could be chosen hold their stakes in EGLD
+        if staking_token_mapper.is_empty() && !self.esdt_safe_address().is_empty() {
+            staking_token_mapper.set(EgldOrEsdtTokenIdentifier::egld());
+        }
+        require!(
+            staking_token_mapper.is_empty() || staking_token_mapper.get() == staking_token,
+            STAKING_TOKEN_CHANGED_ERR_MSG
+        );
+        staking_token_mapper.set(&staking_token);
 
         require!(
             self.blockchain().is_smart_contract(&esdt_safe_sc_address),
-            "Esdt Safe address is not a Smart Contract address"
+            ESDT_SAFE_NOT_SC_ERR_MSG
         );
         self.esdt_safe_address().set(&esdt_safe_sc_address);
 
         require!(
             self.blockchain()
                 .is_smart_contract(&multi_transfer_sc_address),
-            "Multi Transfer address is not a Smart Contract address"
+            MULTI_TRANSFER_NOT_SC_ERR_MSG
         );
         self.multi_transfer_esdt_address()
             .set(&multi_transfer_sc_address);
 
         self.set_paused(true);
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(
+            INTERFACE_VERSION,
+            SUPPORTED_ACTIONS,
+            TRANSFER_FIELD_DESTINATION_CHAIN
+                | TRANSFER_FIELD_UNWRAP_EGLD
+                | TRANSFER_FIELD_NFT_ATTRIBUTES,
+        )
     }
 
     /// Distributes the accumulated fees to the given addresses.
@@ -102,7 +242,7 @@ pub trait Multisig:
 
             require!(
                 !self.blockchain().is_smart_contract(&dest_address),
-                "Cannot transfer to smart contract dest_address"
+                TRANSFER_TO_SC_ERR_MSG
             );
 
             total_percentage += percentage as u64;
@@ -114,7 +254,7 @@ pub trait Multisig:
 
         require!(
             total_percentage == PERCENTAGE_TOTAL as u64,
-            INVALID_PERCENTAGE_SUM_OVER_ERR_MSG
+            INVALID_PERCENTAGE_SUM_ERR_MSG
         );
 
         let _: IgnoreValue = self
@@ -123,16 +263,17 @@ pub trait Multisig:
             .execute_on_dest_context();
     }
 
-    /// Board members have to stake a certain amount of EGLD
+    /// Board members have to stake a certain amount of the staking token (see `getStakingToken`)
     /// before being allowed to sign actions
-    #[payable("EGLD")]
+    #[payable("*")]
     #[endpoint]
-    fn stake(&self, #[payment] payment: BigUint) {
+    fn stake(&self) {
+        let payment = self.get_staking_payment();
         let caller = self.blockchain().get_caller();
         let caller_role = self.user_role(&caller);
         require!(
             caller_role == UserRole::BoardMember,
-            "Only board members can stake"
+            ONLY_BOARD_MEMBERS_CAN_STAKE_ERR_MSG
         );
 
         self.amount_staked(&caller)
@@ -143,22 +284,19 @@ pub trait Multisig:
     fn unstake(&self, amount: BigUint) {
         let caller = self.blockchain().get_caller();
         let amount_staked = self.amount_staked(&caller).get();
-        require!(
-            amount <= amount_staked,
-            "can't unstake more than amount staked"
-        );
+        require!(amount <= amount_staked, UNSTAKE_MORE_THAN_STAKED_ERR_MSG);
 
         let remaining_stake = &amount_staked - &amount;
         if self.user_role(&caller) == UserRole::BoardMember {
             let required_stake_amount = self.required_stake_amount().get();
             require!(
                 remaining_stake >= required_stake_amount,
-                "can't unstake, must keep minimum amount as insurance"
+                UNSTAKE_BELOW_MINIMUM_ERR_MSG
             );
         }
 
         self.amount_staked(&caller).set(&remaining_stake);
-        self.send().direct_egld(&caller, &amount);
+        self.send_staking_token(&caller, &amount);
     }
 
     // ESDT Safe SC calls
@@ -174,79 +312,597 @@ pub trait Multisig:
         esdt_safe_batch_id: u64,
         tx_batch_status: MultiValueEncoded<TransactionStatus>,
     ) -> usize {
-        let call_result: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
-            .get_esdt_safe_proxy_instance()
-            .get_current_tx_batch()
+        self.propose_set_current_transaction_batch_status(
+            ETHEREUM_CHAIN_ID,
+            esdt_safe_batch_id,
+            tx_batch_status.to_vec(),
+        )
+    }
+
+    /// Same as `proposeEsdtSafeSetCurrentTransactionBatchStatus`,
+    /// but for the current batch of the given destination chain.
+    #[endpoint(proposeEsdtSafeSetChainTransactionBatchStatus)]
+    fn propose_esdt_safe_set_chain_transaction_batch_status(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+        tx_batch_status: MultiValueEncoded<TransactionStatus>,
+    ) -> usize {
+        self.propose_set_current_transaction_batch_status(
+            chain_id,
+            esdt_safe_batch_id,
+            tx_batch_status.to_vec(),
+        )
+    }
+
+    /// Proposes to set the statuses of several consecutive batches of the given destination chain at once,
+    /// as pairs of (batch ID, statuses), starting with its current batch.
+    ///
+    /// Lets relayers catch up on the batches processed while the statuses were not being set,
+    /// with a single quorum instead of one for each batch.
+    #[endpoint(proposeEsdtSafeSetMultipleTransactionBatchStatuses)]
+    fn propose_esdt_safe_set_multiple_transaction_batch_statuses(
+        &self,
+        chain_id: ChainId,
+        batch_statuses: MultiValueEncoded<MultiValue2<u64, ManagedVec<TransactionStatus>>>,
+    ) -> usize {
+        let mut batch_statuses_vec = ManagedVec::new();
+        for entry in batch_statuses {
+            let (batch_id, tx_statuses) = entry.into_tuple();
+            batch_statuses_vec.push(BatchStatuses {
+                batch_id,
+                tx_statuses,
+            });
+        }
+
+        self.propose_set_multiple_transaction_batch_statuses(chain_id, batch_statuses_vec)
+    }
+
+    /// Proposes to reset the EsdtSafe daily USD volume, overriding the daily limit for the current day.
+    /// The limit itself is set by the owner, through `esdtSafeSetDailyUsdLimit`.
+    #[endpoint(proposeEsdtSafeResetDailyUsdVolume)]
+    fn propose_esdt_safe_reset_daily_usd_volume(&self) -> usize {
+        self.propose_action(Action::ResetDailyUsdVolume)
+    }
+
+    /// Proposes to allow a contract (e.g. a DEX aggregator) to call
+    /// `createTransactionOnBehalf` on the EsdtSafe, for its users.
+    #[endpoint(proposeEsdtSafeAddTrustedForwarder)]
+    fn propose_esdt_safe_add_trusted_forwarder(&self, forwarder: ManagedAddress) -> usize {
+        self.propose_action(Action::AddTrustedForwarder(forwarder))
+    }
+
+    #[endpoint(proposeEsdtSafeRemoveTrustedForwarder)]
+    fn propose_esdt_safe_remove_trusted_forwarder(&self, forwarder: ManagedAddress) -> usize {
+        self.propose_action(Action::RemoveTrustedForwarder(forwarder))
+    }
+
+    /// Proposes to send the expired refunds of the given token from the EsdtSafe to the treasury.
+    /// Refunds are marked as expired through the EsdtSafe's `expireRefunds` endpoint.
+    #[endpoint(proposeEsdtSafeSweepExpiredRefunds)]
+    fn propose_esdt_safe_sweep_expired_refunds(
+        &self,
+        token_id: TokenIdentifier,
+        treasury: ManagedAddress,
+    ) -> usize {
+        self.propose_action(Action::SweepExpiredRefunds { token_id, treasury })
+    }
+
+    // Multi-transfer ESDT SC calls
+
+    /// Proposes a batch of Ethereum -> Elrond transfers.
+    /// Transactions have to be separated by fields, in the following order:
+    /// Sender Address, Destination Address, Token ID, Amount, Tx Nonce
+    #[endpoint(proposeMultiTransferEsdtBatch)]
+    fn propose_multi_transfer_esdt_batch(
+        &self,
+        eth_batch_id: u64,
+        transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
+    ) -> usize {
+        let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
+
+        self.propose_batch_transfer_esdt_token(eth_batch_id, transfers_as_eth_tx, ManagedVec::new())
+    }
+
+    /// Same as `proposeMultiTransferEsdtBatch`, for batches where some senders asked
+    /// for their WEGLD to be unwrapped, so the recipient gets native EGLD.
+    /// `unwrap_egld_tx_nonces` are the tx nonces of those transfers.
+    /// The unwrapping is done through the EGLD swap contract set by `multiTransferEsdtSetEgldSwapContract`.
+    #[endpoint(proposeMultiTransferEsdtBatchWithEgldUnwrap)]
+    fn propose_multi_transfer_esdt_batch_with_egld_unwrap(
+        &self,
+        eth_batch_id: u64,
+        unwrap_egld_tx_nonces: ManagedVec<TxNonce>,
+        transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
+    ) -> usize {
+        let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
+        for tx_nonce in &unwrap_egld_tx_nonces {
+            require!(
+                transfers_as_eth_tx
+                    .iter()
+                    .any(|eth_tx| eth_tx.tx_nonce == tx_nonce),
+                UNWRAP_EGLD_TX_NOT_IN_BATCH_ERR_MSG
+            );
+        }
+
+        self.propose_batch_transfer_esdt_token(
+            eth_batch_id,
+            transfers_as_eth_tx,
+            unwrap_egld_tx_nonces,
+        )
+    }
+
+    /// Proposes a whole bridge round in one call: the statuses of the EsdtSafe's current batch,
+    /// once it is final, and the next Ethereum -> Elrond batch.
+    /// Both batch IDs are read from the contracts' state instead of being passed by the relayer.
+    /// If the same action was already proposed by another board member, it is signed instead,
+    /// so each relayer can call this with what it observed on Ethereum.
+    ///
+    /// `esdt_safe_batch_status` is left empty if there is no batch to settle,
+    /// and `transfers`, in the format of `proposeMultiTransferEsdtBatch`, if there were no deposits.
+    /// Returns the IDs of the set-status and transfer actions, 0 for those not proposed.
+    /// Only board members can call this.
+    #[endpoint(autoProposeNextRound)]
+    fn auto_propose_next_round(
+        &self,
+        esdt_safe_batch_status: ManagedVec<TransactionStatus>,
+        transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
+    ) -> MultiValue2<usize, usize> {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_AUTO_PROPOSE_ERR_MSG
+        );
+        require!(
+            !esdt_safe_batch_status.is_empty() || !transfers.is_empty(),
+            NOTHING_TO_PROPOSE_ERR_MSG
+        );
+
+        let status_action_id = if esdt_safe_batch_status.is_empty() {
+            0
+        } else {
+            self.propose_or_sign_current_batch_status(esdt_safe_batch_status)
+        };
+
+        let transfer_action_id = if transfers.is_empty() {
+            0
+        } else {
+            let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
+            self.propose_or_sign_next_transfer_batch(transfers_as_eth_tx)
+        };
+
+        (status_action_id, transfer_action_id).into()
+    }
+
+    /// Proposes to execute again some of the transfers of an already executed
+    /// Ethereum -> Elrond batch, which failed because of a temporary issue
+    /// (e.g. a frozen account or a missing role).
+    ///
+    /// Only the failed transfers whose refund was not yet moved to the EsdtSafe are accepted.
+    /// This action needs the super quorum to be performed (see `changeSuperQuorum`).
+    /// Transfers have the same format as for `proposeMultiTransferEsdtBatch`.
+    #[endpoint(proposeForceReexecuteBatch)]
+    fn propose_force_reexecute_batch(
+        &self,
+        eth_batch_id: u64,
+        transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
+    ) -> usize {
+        require!(
+            eth_batch_id <= self.last_executed_eth_batch_id().get(),
+            BATCH_NOT_EXECUTED_ERR_MSG
+        );
+        require!(!transfers.is_empty(), NO_TRANSFERS_PROVIDED_ERR_MSG);
+
+        let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
+
+        self.propose_action(Action::ForceReexecuteBatch {
+            eth_batch_id,
+            transfers: transfers_as_eth_tx,
+        })
+    }
+
+    /// Proposes to deliver Ethereum -> Elrond transfers that MultiTransferEsdt quarantined,
+    /// identified by their tx nonces (see its `getQuarantinedTransfers` view).
+    /// The quarantine rules are set by the owner, e.g. through `multiTransferEsdtSetQuarantineThreshold`.
+    #[endpoint(proposeReleaseQuarantinedTransfers)]
+    fn propose_release_quarantined_transfers(&self, tx_nonces: MultiValueEncoded<u64>) -> usize {
+        require!(!tx_nonces.is_empty(), NO_TRANSFERS_PROVIDED_ERR_MSG);
+
+        self.propose_action(Action::ReleaseQuarantinedTransfers(tx_nonces.to_vec()))
+    }
+
+    /// Proposes to refund quarantined transfers to their Ethereum senders, instead of delivering them.
+    #[endpoint(proposeRefundQuarantinedTransfers)]
+    fn propose_refund_quarantined_transfers(&self, tx_nonces: MultiValueEncoded<u64>) -> usize {
+        require!(!tx_nonces.is_empty(), NO_TRANSFERS_PROVIDED_ERR_MSG);
+
+        self.propose_action(Action::RefundQuarantinedTransfers(tx_nonces.to_vec()))
+    }
+
+    /// Proposes to switch the token between lock mode and burn mode (the default).
+    /// In lock mode, the EsdtSafe keeps the tokens of executed transfers instead of burning them,
+    /// and MultiTransferEsdt pays incoming transfers from its liquidity instead of minting them.
+    /// Both contracts are switched by the same action, or neither is.
+    ///
+    /// When leaving lock mode, the tokens locked by both contracts are sent to the custodian,
+    /// or burned if none is given. Requires the super quorum, as the whole locked balance may leave the bridge.
+    #[endpoint(proposeMigrateTokenMode)]
+    fn propose_migrate_token_mode(
+        &self,
+        token_id: TokenIdentifier,
+        lock_mode: bool,
+        opt_custodian: OptionalValue<ManagedAddress>,
+    ) -> usize {
+        self.propose_action(Action::MigrateTokenMode {
+            token_id,
+            lock_mode,
+            custodian: opt_custodian.into_option(),
+        })
+    }
+
+    // Board members
+
+    /// Proposes to add a board member, growing the board without redeploying the contract.
+    /// A proposer or executor is promoted to board member.
+    /// The new board member can only sign once they staked, see `stake`.
+    /// Only board members can propose this.
+    #[endpoint(proposeAddBoardMember)]
+    fn propose_add_board_member(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_ADD_BOARD_MEMBERS_ERR_MSG
+        );
+        require!(
+            !self.get_user_role(&address).is_board_member(),
+            DUPLICATE_BOARD_MEMBER_ERR_MSG
+        );
+        if let OptionalValue::Some(remaining_capacity) = self.get_remaining_board_capacity() {
+            require!(remaining_capacity > 0, BOARD_ABOVE_MAX_SIZE_ERR_MSG);
+        }
+
+        self.propose_action(Action::AddBoardMember(address))
+    }
+
+    /// Proposes to remove a board member, e.g. one that is compromised or inactive.
+    /// The remaining board must still satisfy the quorum, which is checked when performing the action.
+    /// The removed member's signatures on pending actions stop counting towards the quorum.
+    /// Only board members can propose this.
+    #[endpoint(proposeRemoveUser)]
+    fn propose_remove_user(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_REMOVE_BOARD_MEMBERS_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&address).is_board_member(),
+            NOT_A_BOARD_MEMBER_ERR_MSG
+        );
+
+        self.propose_action(Action::RemoveUser(address))
+    }
+
+    /// Proposes a new quorum, e.g. after the board grew or shrank.
+    /// The quorum cannot exceed the board size, which is checked again when performing the action,
+    /// along with the minimum quorum and the super quorum.
+    /// Only board members can propose this.
+    #[endpoint(proposeChangeQuorum)]
+    fn propose_change_quorum(&self, new_quorum: usize) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_CHANGE_QUORUM_ERR_MSG
+        );
+        require!(
+            new_quorum <= self.num_board_members().get(),
+            QUORUM_ABOVE_BOARD_SIZE_ERR_MSG
+        );
+        require!(
+            self.quorum_percentage().get() == 0,
+            QUORUM_SET_AS_PERCENTAGE_ERR_MSG
+        );
+
+        self.propose_action(Action::ChangeQuorum(new_quorum))
+    }
+
+    /// Proposes to slash a misbehaving board member, the same as the owner's `slashBoardMember`:
+    /// the amount set for the offense is cut from their stake and they are removed from the board.
+    /// The slashed stake goes to the slashed tokens pool (see `getSlashedTokensAmount`),
+    /// through the escrow if an appeal window is set.
+    /// Only board members can propose this.
+    #[endpoint(proposeSlashBoardMember)]
+    fn propose_slash_board_member(
+        &self,
+        board_member: ManagedAddress,
+        offense: SlashOffense,
+    ) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_PROPOSE_SLASH_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&board_member).is_board_member(),
+            NOT_A_BOARD_MEMBER_ERR_MSG
+        );
+
+        self.propose_action(Action::SlashBoardMember {
+            board_member,
+            offense,
+        })
+    }
+
+    // Proposers
+
+    /// Proposes to give the proposer role to an address without a role.
+    /// Proposers can propose and perform actions, but cannot sign them.
+    /// Only board members can propose this.
+    #[endpoint(proposeAddProposer)]
+    fn propose_add_proposer(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_MANAGE_PROPOSERS_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&address) == UserRole::None,
+            USER_ALREADY_HAS_ROLE_ERR_MSG
+        );
+
+        self.propose_action(Action::AddProposer(address))
+    }
+
+    /// Proposes to give a slashed board member their stake back, while the slash is still in escrow.
+    /// This action needs the super quorum to be performed (see `changeSuperQuorum`).
+    #[endpoint(proposeReverseSlash)]
+    fn propose_reverse_slash(&self, board_member: ManagedAddress) -> usize {
+        require!(
+            self.is_slash_under_appeal(&board_member),
+            NO_SLASH_UNDER_APPEAL_ERR_MSG
+        );
+
+        self.propose_action(Action::ReverseSlash(board_member))
+    }
+
+    /// Proposes to change the amount slashed for the given offense.
+    /// This action needs the super quorum to be performed (see `changeSuperQuorum`).
+    #[endpoint(proposeSetSlashTier)]
+    fn propose_set_slash_tier(&self, offense: SlashOffense, amount: BigUint) -> usize {
+        require!(
+            amount <= self.required_stake_amount().get(),
+            SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG
+        );
+
+        self.propose_action(Action::SetSlashTier { offense, amount })
+    }
+
+    /// Proposes to record the balances locked in the bridge on the remote chain at the given block,
+    /// as pairs of (token ID, locked amount), so minted tokens can be audited against them.
+    /// The block must be after the one of the last attested reserves (see `getLastReservesEthBlockNumber`).
+    #[endpoint(proposeAttestRemoteReserves)]
+    fn propose_attest_remote_reserves(
+        &self,
+        eth_block_number: u64,
+        reserves: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>>,
+    ) -> usize {
+        require!(!reserves.is_empty(), NO_RESERVES_PROVIDED_ERR_MSG);
+        self.require_newer_reserves_block(eth_block_number);
+
+        let mut attested_reserves = ManagedVec::new();
+        let mut token_ids = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        for reserve in reserves {
+            let (token_id, locked_amount) = reserve.into_tuple();
+            require!(
+                !token_ids.contains(&token_id),
+                DUPLICATE_RESERVE_TOKEN_ERR_MSG
+            );
+
+            token_ids.push(token_id.clone());
+            attested_reserves.push(RemoteReserve {
+                token_id,
+                locked_amount,
+            });
+        }
+
+        self.propose_action(Action::AttestRemoteReserves {
+            eth_block_number,
+            reserves: attested_reserves,
+        })
+    }
+
+    /// Proposes to remove the proposer role from an address.
+    /// Only board members can propose this.
+    #[endpoint(proposeRemoveProposer)]
+    fn propose_remove_proposer(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_MANAGE_PROPOSERS_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&address) == UserRole::Proposer,
+            USER_NOT_PROPOSER_ERR_MSG
+        );
+
+        self.propose_action(Action::RemoveProposer(address))
+    }
+
+    // Executors
+
+    /// Proposes to give the executor role to an address without a role,
+    /// e.g. a keeper wallet that performs actions once they have enough signatures.
+    /// Executors can perform actions, but cannot propose or sign them.
+    /// Only board members can propose this.
+    #[endpoint(proposeAddExecutor)]
+    fn propose_add_executor(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_MANAGE_EXECUTORS_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&address) == UserRole::None,
+            USER_ALREADY_HAS_ROLE_ERR_MSG
+        );
+
+        self.propose_action(Action::AddExecutor(address))
+    }
+
+    /// Proposes to remove the executor role from an address.
+    /// Only board members can propose this.
+    #[endpoint(proposeRemoveExecutor)]
+    fn propose_remove_executor(&self, address: ManagedAddress) -> usize {
+        let caller = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_MANAGE_EXECUTORS_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&address) == UserRole::Executor,
+            USER_NOT_EXECUTOR_ERR_MSG
+        );
+
+        self.propose_action(Action::RemoveExecutor(address))
+    }
+
+    // Multicall
+
+    /// Bundles multiple relayer calls into a single transaction.
+    /// Each call is a pair of (endpoint name, arguments), where the arguments
+    /// are encoded exactly as they would be for a direct call to that endpoint.
+    ///
+    /// Only the following endpoints may be called this way:
+    /// `sign`, `proposeEsdtSafeSetCurrentTransactionBatchStatus` and `proposeMultiTransferEsdtBatch`.
+    ///
+    /// Returns one action ID per call: the proposed action's ID, or the signed action's ID.
+    #[endpoint]
+    fn multicall(
+        &self,
+        calls: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedVec<ManagedBuffer>>>,
+    ) -> MultiValueEncoded<usize> {
+        let mut results = MultiValueEncoded::new();
+        for call in calls {
+            let (endpoint_name, args) = call.into_tuple();
+            let action_id = self.dispatch_multicall(endpoint_name, args);
+            results.push(action_id);
+        }
+
+        results
+    }
+
+    // Bundles
+
+    /// Proposes to perform several pending actions in the given order, with a single vote.
+    /// E.g. setting the statuses of an EsdtSafe batch and executing an Ethereum batch.
+    ///
+    /// The bundled actions don't need signatures of their own.
+    /// They are performed synchronously, so if one of them fails, none is performed.
+    /// Bundles cannot contain other bundles.
+    #[endpoint(proposeBundle)]
+    fn propose_bundle(&self, action_ids: MultiValueEncoded<usize>) -> usize {
+        require!(action_ids.len() >= 2, BUNDLE_TOO_SMALL_ERR_MSG);
+
+        let mut bundled_action_ids = ManagedVec::new();
+        for action_id in action_ids {
+            require!(
+                self.is_valid_action_id(action_id)
+                    && !self.action_mapper().item_is_empty(action_id),
+                BUNDLED_ACTION_DOES_NOT_EXIST_ERR_MSG
+            );
+            require!(
+                !self.action_mapper().get(action_id).is_bundle(),
+                NESTED_BUNDLE_ERR_MSG
+            );
+            require!(
+                !bundled_action_ids.contains(&action_id),
+                DUPLICATE_BUNDLED_ACTION_ERR_MSG
+            );
+
+            bundled_action_ids.push(action_id);
+        }
+
+        self.propose_action(Action::Bundle(bundled_action_ids))
+    }
+
+    // NFT bridge SC calls
+
+    /// Same as `proposeEsdtSafeSetCurrentTransactionBatchStatus`, but for the NftSafe batches.
+    #[endpoint(proposeNftSafeSetCurrentTransactionBatchStatus)]
+    fn propose_nft_safe_set_current_transaction_batch_status(
+        &self,
+        nft_safe_batch_id: u64,
+        tx_batch_status: MultiValueEncoded<TransactionStatus>,
+    ) -> usize {
+        let call_result: OptionalValue<NftTxBatchSplitInFields<Self::Api>> = self
+            .get_nft_safe_proxy_instance()
+            .get_current_nft_tx_batch()
             .execute_on_dest_context();
         let (current_batch_id, current_batch_transactions) = match call_result {
             OptionalValue::Some(batch) => batch.into_tuple(),
-            OptionalValue::None => sc_panic!("Current batch is empty"),
+            OptionalValue::None => sc_panic!(CURRENT_BATCH_EMPTY_ERR_MSG),
         };
-        let statuses_vec = tx_batch_status.to_vec();
 
+        let statuses_vec = tx_batch_status.to_vec();
+        let packed_statuses = pack_statuses(&statuses_vec);
         require!(
-            self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id)
-                .get(&statuses_vec)
+            self.action_id_for_set_current_nft_batch_status(nft_safe_batch_id)
+                .get(&packed_statuses)
                 .is_none(),
-            "Action already proposed"
+            ACTION_ALREADY_PROPOSED_ERR_MSG
         );
 
-        let current_batch_len = current_batch_transactions.raw_len() / TX_MULTIRESULT_NR_FIELDS;
+        let current_batch_len = current_batch_transactions.raw_len() / NFT_TX_MULTIRESULT_NR_FIELDS;
         let status_batch_len = statuses_vec.len();
         require!(
             current_batch_len == status_batch_len,
-            "Number of statuses provided must be equal to number of transactions in current batch"
+            STATUS_COUNT_MISMATCH_ERR_MSG
         );
         require!(
-            esdt_safe_batch_id == current_batch_id,
-            "Current EsdtSafe tx batch does not have the provided ID"
+            nft_safe_batch_id == current_batch_id,
+            WRONG_NFT_SAFE_BATCH_ID_ERR_MSG
         );
 
-        let action_id = self.propose_action(Action::SetCurrentTransactionBatchStatus {
-            esdt_safe_batch_id,
-            tx_batch_status: statuses_vec.clone(),
+        let action_id = self.propose_action(Action::SetCurrentNftBatchStatus {
+            nft_safe_batch_id,
+            tx_batch_status: statuses_vec,
         });
 
-        self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id)
-            .insert(statuses_vec, action_id);
+        self.action_id_for_set_current_nft_batch_status(nft_safe_batch_id)
+            .insert(packed_statuses, action_id);
 
         action_id
     }
 
-    // Multi-transfer ESDT SC calls
-
-    /// Proposes a batch of Ethereum -> Elrond transfers.
+    /// Proposes a batch of Ethereum -> Elrond NFT transfers.
     /// Transactions have to be separated by fields, in the following order:
-    /// Sender Address, Destination Address, Token ID, Amount, Tx Nonce
-    #[endpoint(proposeMultiTransferEsdtBatch)]
-    fn propose_multi_transfer_esdt_batch(
+    /// Sender Address, Destination Address, Token ID, Token Nonce, Attributes, Tx Nonce
+    #[endpoint(proposeNftTransferBatch)]
+    fn propose_nft_transfer_batch(
         &self,
         eth_batch_id: u64,
-        transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
+        transfers: MultiValueEncoded<EthNftTxAsMultiValue<Self::Api>>,
     ) -> usize {
-        let next_eth_batch_id = self.last_executed_eth_batch_id().get() + 1;
-        require!(
-            eth_batch_id == next_eth_batch_id,
-            "Can only propose for next batch ID"
-        );
+        let next_eth_batch_id = self.last_executed_eth_nft_batch_id().get() + 1;
+        require!(eth_batch_id == next_eth_batch_id, NOT_NEXT_BATCH_ID_ERR_MSG);
 
-        let transfers_as_eth_tx = self.transfers_multi_value_to_eth_tx_vec(transfers);
-        self.require_valid_eth_tx_ids(&transfers_as_eth_tx);
+        let transfers_as_eth_tx = self.transfers_multi_value_to_eth_nft_tx_vec(transfers);
+        self.require_valid_eth_nft_tx_ids(&transfers_as_eth_tx);
 
         let batch_hash = self.hash_eth_tx_batch(&transfers_as_eth_tx);
         require!(
-            self.batch_id_to_action_id_mapping(eth_batch_id)
+            self.nft_batch_id_to_action_id_mapping(eth_batch_id)
                 .get(&batch_hash)
                 .is_none(),
-            "This batch was already proposed"
+            BATCH_ALREADY_PROPOSED_ERR_MSG
         );
 
-        let action_id = self.propose_action(Action::BatchTransferEsdtToken {
+        let action_id = self.propose_action(Action::BatchTransferNft {
             eth_batch_id,
             transfers: transfers_as_eth_tx,
         });
 
-        self.batch_id_to_action_id_mapping(eth_batch_id)
+        self.nft_batch_id_to_action_id_mapping(eth_batch_id)
             .insert(batch_hash, action_id);
 
         action_id
@@ -267,12 +923,7 @@ pub trait Multisig:
             .execute_on_dest_context();
 
         if let OptionalValue::Some(refund_batch_fields) = opt_refund_batch_fields {
-            let (_batch_id, all_tx_fields) = refund_batch_fields.into_tuple();
-            let mut refund_batch = ManagedVec::new();
-
-            for tx_fields in all_tx_fields {
-                refund_batch.push(Transaction::from(tx_fields));
-            }
+            let (_batch_id, refund_batch) = join_batch(refund_batch_fields);
 
             let _: IgnoreValue = self
                 .get_esdt_safe_proxy_instance()
@@ -281,90 +932,1204 @@ pub trait Multisig:
         }
     }
 
-    /// Proposers and board members use this to launch signed actions.
+    /// Same as `moveRefundBatchToSafe`, but for the failed NFT transfers,
+    /// which are moved from the NftTransfer SC to the NftSafe SC.
+    #[only_owner]
+    #[endpoint(moveNftRefundBatchToSafe)]
+    fn move_nft_refund_batch_to_safe(&self) {
+        let opt_refund_batch_fields: OptionalValue<NftTxBatchSplitInFields<Self::Api>> = self
+            .get_nft_transfer_proxy_instance()
+            .get_and_clear_first_refund_batch()
+            .execute_on_dest_context();
+
+        if let OptionalValue::Some(refund_batch_fields) = opt_refund_batch_fields {
+            let (_batch_id, refund_batch) = join_nft_batch(refund_batch_fields);
+
+            let _: IgnoreValue = self
+                .get_nft_safe_proxy_instance()
+                .add_refund_batch(refund_batch)
+                .execute_on_dest_context();
+        }
+    }
+
+    /// Board members, proposers and executors use this to launch signed actions.
     #[endpoint(performAction)]
     fn perform_action_endpoint(&self, action_id: usize) {
         require!(
             !self.action_mapper().item_is_empty(action_id),
-            "Action was already executed"
+            ACTION_ALREADY_EXECUTED_ERR_MSG
         );
+        require!(
+            !self.is_action_call_pending(action_id),
+            ACTION_CALL_PENDING_ERR_MSG
+        );
+        require!(!self.is_stale_action(action_id), STALE_ACTION_ERR_MSG);
+        require!(!self.is_expired_action(action_id), ACTION_EXPIRED_ERR_MSG);
 
-        let caller_address = self.blockchain().get_caller();
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
         let caller_role = self.get_user_role(&caller_address);
         require!(
-            caller_role.is_board_member(),
-            "only board members can perform actions"
+            caller_role.can_perform(),
+            ONLY_BOARD_MEMBERS_PROPOSERS_AND_EXECUTORS_CAN_PERFORM_ERR_MSG
         );
+        require!(self.quorum_reached(action_id), QUORUM_NOT_REACHED_ERR_MSG);
         require!(
-            self.quorum_reached(action_id),
-            "quorum has not been reached"
+            self.is_execution_timelock_over(action_id),
+            EXECUTION_TIMELOCK_NOT_ENDED_ERR_MSG
         );
-        require!(self.not_paused(), "No actions may be executed while paused");
+        if self.requires_super_quorum(&self.action_mapper().get(action_id)) {
+            let super_quorum = self.super_quorum().get();
+            require!(
+                super_quorum > 0 && self.get_action_valid_signer_count(action_id) >= super_quorum,
+                SUPER_QUORUM_NOT_REACHED_ERR_MSG
+            );
+        }
+        require!(self.not_paused(), PERFORMING_WHILE_PAUSED_ERR_MSG);
 
+        self.action_executor().set(&caller_address);
         self.perform_action(action_id);
     }
 
+    /// Recovery for an action whose child contract call never got its callback, e.g. because the callback failed.
+    /// The action is marked as failed, so it can be performed again. This is safe to do,
+    /// since the child contracts reject batches and transfers that were already processed.
+    ///
+    /// Also clears the execution flag, which older versions of this contract kept set until the callback.
+    #[only_owner]
+    #[endpoint(resetPendingActionCall)]
+    fn reset_pending_action_call(&self, action_id: usize) {
+        require!(
+            self.is_action_call_pending(action_id),
+            ACTION_CALL_NOT_PENDING_ERR_MSG
+        );
+
+        self.action_execution_status(action_id)
+            .set(ActionExecutionStatus::Failed);
+        self.end_execution();
+    }
+
+    /// Removes stale actions, i.e. set-status actions for EsdtSafe batches that were already settled,
+    /// along with all the other actions proposed for the same batches. Anyone can call this.
+    ///
+    /// Expired actions (see `setActionTtl`) are removed as well, on their own,
+    /// so the same action can be proposed again.
+    #[endpoint(purgeStaleActions)]
+    fn purge_stale_actions(&self, action_ids: MultiValueEncoded<usize>) {
+        for action_id in action_ids {
+            // might have been purged along with a previous action for the same batch,
+            // and pending actions are left to their callbacks
+            if !self.is_valid_action_id(action_id)
+                || self.action_mapper().item_is_empty(action_id)
+                || self.is_action_call_pending(action_id)
+            {
+                continue;
+            }
+
+            if !self.is_stale_action(action_id) {
+                require!(self.is_expired_action(action_id), ACTION_NOT_STALE_ERR_MSG);
+
+                self.clear_proposed_action(action_id);
+                continue;
+            }
+
+            match self.action_mapper().get(action_id) {
+                Action::SetCurrentTransactionBatchStatus {
+                    esdt_safe_batch_id, ..
+                } => self.clear_batch_actions(
+                    self.set_status_action_ids_mapper(ETHEREUM_CHAIN_ID, esdt_safe_batch_id),
+                ),
+                Action::SetChainTransactionBatchStatus {
+                    chain_id,
+                    esdt_safe_batch_id,
+                    ..
+                } => self.clear_batch_actions(
+                    self.set_status_action_ids_mapper(chain_id, esdt_safe_batch_id),
+                ),
+                Action::SetMultipleTransactionBatchStatuses {
+                    chain_id,
+                    batch_statuses,
+                } => self.clear_batch_actions(
+                    self.set_status_action_ids_mapper(chain_id, batch_statuses.get(0).batch_id),
+                ),
+                _ => self.clear_action(action_id),
+            }
+        }
+    }
+
+    /// Discards a pending action, e.g. a wrong batch proposal, so the same batch can be proposed again.
+    ///
+    /// The proposer can discard their own action, as long as no one else signed it.
+    /// Otherwise, board members vote to discard it by calling this endpoint,
+    /// and the action is discarded once the votes reach the quorum.
+    ///
+    /// Returns `true` if the action was discarded.
+    #[endpoint(discardAction)]
+    fn discard_action(&self, action_id: usize) -> bool {
+        require!(
+            !self.action_mapper().item_is_empty_unchecked(action_id),
+            ACTION_DOES_NOT_EXIST_ERR_MSG
+        );
+        require!(
+            !self.is_action_call_pending(action_id),
+            ACTION_CALL_PENDING_ERR_MSG
+        );
+
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
+        let caller_id = self.user_mapper().get_user_id(&caller_address);
+        let is_proposer = caller_id != 0 && caller_id == self.action_proposer(action_id).get();
+        let signed_by_others = self
+            .action_signer_ids(action_id)
+            .iter()
+            .any(|signer_id| signer_id != caller_id);
+        if is_proposer && !signed_by_others {
+            self.clear_proposed_action(action_id);
+            return true;
+        }
+
+        require!(
+            self.is_active_board_member(&caller_address),
+            ONLY_BOARD_MEMBERS_CAN_VOTE_TO_DISCARD_ERR_MSG
+        );
+        self.action_discard_votes(action_id).insert(caller_id);
+        if self.count_valid_discard_votes(action_id) < self.quorum().get() {
+            return false;
+        }
+
+        self.clear_proposed_action(action_id);
+        true
+    }
+
+    /// Vetoes a pending action, giving the reason, which is kept in storage for audit (see `getActionVetoes`).
+    /// Once the vetoes of board members reach the threshold set through `setVetoThreshold`,
+    /// the action is permanently blocked, even if it already reached the quorum.
+    /// Unlike with `discardAction`, the same action cannot be proposed again.
+    /// A different one can still be proposed for the same batch, e.g. with corrected statuses.
+    ///
+    /// Returns `true` if the action was blocked.
+    #[endpoint]
+    fn veto(&self, action_id: usize, reason: ManagedBuffer) -> bool {
+        require!(
+            !self.action_mapper().item_is_empty_unchecked(action_id),
+            ACTION_DOES_NOT_EXIST_ERR_MSG
+        );
+        require!(
+            !self.is_action_call_pending(action_id),
+            ACTION_CALL_PENDING_ERR_MSG
+        );
+        let veto_threshold = self.veto_threshold().get();
+        require!(veto_threshold > 0, VETOES_DISABLED_ERR_MSG);
+
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
+        require!(
+            self.is_active_board_member(&caller_address),
+            ONLY_BOARD_MEMBERS_CAN_VETO_ERR_MSG
+        );
+        let caller_id = self.user_mapper().get_user_id(&caller_address);
+        require!(
+            self.action_vetoes(action_id)
+                .insert(caller_id, reason.clone())
+                .is_none(),
+            ACTION_ALREADY_VETOED_BY_CALLER_ERR_MSG
+        );
+        self.action_vetoed_event(action_id, &caller_address, &reason);
+
+        if self.count_valid_vetoes(action_id) < veto_threshold {
+            return false;
+        }
+
+        let action = self.action_mapper().get(action_id);
+        self.is_action_vetoed(action_id).set(true);
+        self.vetoed_action_hashes()
+            .insert(self.get_action_hash(&action));
+        // no longer found through its batch, so proposing it again fails because it was vetoed
+        self.clear_proposed_action(action_id);
+        true
+    }
+
+    /// Same as `performAction`, but first adds the signatures produced by
+    /// the board members' Ethereum keys (see `registerEthPublicKey`).
+    /// Signatures are given as pairs of (board member address, 65-byte signature),
+    /// and must be over the hash returned by `getActionAttestationHash`.
+    ///
+    /// This allows an action to be signed and executed in a single transaction.
+    #[endpoint(performActionWithEthSignatures)]
+    fn perform_action_with_eth_signatures(
+        &self,
+        action_id: usize,
+        signatures: MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>>,
+    ) {
+        require!(
+            !self.action_mapper().item_is_empty(action_id),
+            ACTION_ALREADY_EXECUTED_ERR_MSG
+        );
+
+        self.add_eth_signatures(action_id, signatures);
+        self.perform_action_endpoint(action_id);
+    }
+
     // private
 
+    fn requires_super_quorum(&self, action: &Action<Self::Api>) -> bool {
+        match action {
+            Action::ForceReexecuteBatch { .. }
+            | Action::ReverseSlash(_)
+            | Action::SetSlashTier { .. }
+            | Action::MigrateTokenMode { .. } => true,
+            Action::Bundle(action_ids) => action_ids.iter().any(|bundled_action_id| {
+                self.requires_super_quorum(&self.action_mapper().get(bundled_action_id))
+            }),
+            _ => false,
+        }
+    }
+
+    /// The address might have become a board member since the action was proposed,
+    /// or the board might have reached its maximum size, so both are checked again.
+    fn add_board_member_from_action(&self, address: &ManagedAddress) {
+        require!(
+            !self.get_user_role(address).is_board_member(),
+            DUPLICATE_BOARD_MEMBER_ERR_MSG
+        );
+
+        self.add_board_member(address);
+        self.update_quorum_from_percentage();
+        self.require_valid_board_config();
+    }
+
+    /// The quorum might have been set as a percentage of the board since the action was proposed.
+    fn change_quorum_from_action(&self, new_quorum: usize, action_id: usize) {
+        require!(
+            self.quorum_percentage().get() == 0,
+            QUORUM_SET_AS_PERCENTAGE_ERR_MSG
+        );
+
+        self.set_quorum(new_quorum, action_id);
+    }
+
+    /// The address might have been removed already, e.g. by the owner, since the action was proposed.
+    fn remove_board_member_from_action(&self, address: &ManagedAddress) {
+        require!(
+            self.get_user_role(address).is_board_member(),
+            NOT_A_BOARD_MEMBER_ERR_MSG
+        );
+
+        self.remove_user_and_check_quorum(address);
+    }
+
+    /// The board member might have been removed or slashed already since the action was proposed.
+    fn slash_board_member_from_action(&self, board_member: &ManagedAddress, offense: SlashOffense) {
+        require!(
+            self.get_user_role(board_member).is_board_member(),
+            NOT_A_BOARD_MEMBER_ERR_MSG
+        );
+
+        self.slash(board_member, offense);
+    }
+
+    fn propose_set_current_transaction_batch_status(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+        statuses_vec: ManagedVec<TransactionStatus>,
+    ) -> usize {
+        let packed_statuses = pack_statuses(&statuses_vec);
+        if let Some(message) = self.get_batch_statuses_error(
+            chain_id,
+            esdt_safe_batch_id,
+            &statuses_vec,
+            &packed_statuses,
+        ) {
+            sc_panic!(message);
+        }
+
+        let mut action_ids_mapper = self.set_status_action_ids_mapper(chain_id, esdt_safe_batch_id);
+
+        let action = if chain_id == ETHEREUM_CHAIN_ID {
+            Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status: statuses_vec,
+            }
+        } else {
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                tx_batch_status: statuses_vec,
+            }
+        };
+        let action_id = self.propose_action(action);
+
+        action_ids_mapper.insert(packed_statuses, action_id);
+
+        action_id
+    }
+
+    fn propose_set_multiple_transaction_batch_statuses(
+        &self,
+        chain_id: ChainId,
+        batch_statuses: ManagedVec<BatchStatuses<Self::Api>>,
+    ) -> usize {
+        if let Some(message) = self.get_multiple_batch_statuses_error(chain_id, &batch_statuses) {
+            sc_panic!(message);
+        }
+
+        // registered along with the first batch's proposals, which are all cleared once it is settled
+        let first_batch_id = batch_statuses.get(0).batch_id;
+        let mut action_ids_mapper = self.set_status_action_ids_mapper(chain_id, first_batch_id);
+        let packed_statuses = pack_batch_statuses(&batch_statuses);
+
+        let action_id = self.propose_action(Action::SetMultipleTransactionBatchStatuses {
+            chain_id,
+            batch_statuses,
+        });
+
+        action_ids_mapper.insert(packed_statuses, action_id);
+
+        action_id
+    }
+
+    /// The unwrapped transfers are not part of the batch hash,
+    /// so a batch can only be proposed once, whether some of its transfers are unwrapped or not.
+    fn propose_batch_transfer_esdt_token(
+        &self,
+        eth_batch_id: u64,
+        transfers_as_eth_tx: ManagedVec<EthTransaction<Self::Api>>,
+        unwrap_egld_tx_nonces: ManagedVec<TxNonce>,
+    ) -> usize {
+        let next_eth_batch_id = self.last_executed_eth_batch_id().get() + 1;
+        require!(eth_batch_id == next_eth_batch_id, NOT_NEXT_BATCH_ID_ERR_MSG);
+
+        self.require_valid_eth_tx_ids(&transfers_as_eth_tx);
+
+        let batch_hash = self.hash_eth_tx_batch(&transfers_as_eth_tx);
+        require!(
+            self.batch_id_to_action_id_mapping(eth_batch_id)
+                .get(&batch_hash)
+                .is_none(),
+            BATCH_ALREADY_PROPOSED_ERR_MSG
+        );
+
+        let action = if unwrap_egld_tx_nonces.is_empty() {
+            Action::BatchTransferEsdtToken {
+                eth_batch_id,
+                transfers: transfers_as_eth_tx,
+            }
+        } else {
+            Action::BatchTransferEsdtTokenWithEgldUnwrap {
+                eth_batch_id,
+                transfers: transfers_as_eth_tx,
+                unwrap_egld_tx_nonces,
+            }
+        };
+        let action_id = self.propose_action(action);
+        self.record_action_execution_notifier(action_id);
+
+        self.batch_id_to_action_id_mapping(eth_batch_id)
+            .insert(batch_hash, action_id);
+
+        action_id
+    }
+
+    fn propose_or_sign_current_batch_status(
+        &self,
+        statuses_vec: ManagedVec<TransactionStatus>,
+    ) -> usize {
+        let call_result: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
+            .get_esdt_safe_proxy_instance()
+            .get_current_tx_batch(OptionalValue::Some(ETHEREUM_CHAIN_ID))
+            .execute_on_dest_context();
+        let esdt_safe_batch_id = match call_result {
+            OptionalValue::Some(batch) => batch.into_tuple().0,
+            OptionalValue::None => sc_panic!(CURRENT_BATCH_EMPTY_ERR_MSG),
+        };
+
+        let existing_action_id = self
+            .action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id)
+            .get(&pack_statuses(&statuses_vec));
+        match existing_action_id {
+            Some(action_id) => {
+                self.sign(action_id);
+                action_id
+            }
+            None => self.propose_set_current_transaction_batch_status(
+                ETHEREUM_CHAIN_ID,
+                esdt_safe_batch_id,
+                statuses_vec,
+            ),
+        }
+    }
+
+    fn propose_or_sign_next_transfer_batch(
+        &self,
+        transfers_as_eth_tx: ManagedVec<EthTransaction<Self::Api>>,
+    ) -> usize {
+        let eth_batch_id = self.last_executed_eth_batch_id().get() + 1;
+        let batch_hash = self.hash_eth_tx_batch(&transfers_as_eth_tx);
+        match self
+            .batch_id_to_action_id_mapping(eth_batch_id)
+            .get(&batch_hash)
+        {
+            Some(action_id) => {
+                self.sign(action_id);
+                action_id
+            }
+            None => self.propose_batch_transfer_esdt_token(
+                eth_batch_id,
+                transfers_as_eth_tx,
+                ManagedVec::new(),
+            ),
+        }
+    }
+
+    fn dispatch_multicall(
+        &self,
+        endpoint_name: ManagedBuffer,
+        args: ManagedVec<ManagedBuffer>,
+    ) -> usize {
+        if endpoint_name == *MULTICALL_SIGN_ENDPOINT {
+            require!(args.len() == 1, INVALID_NUMBER_OF_ARGUMENTS_ERR_MSG);
+
+            let action_id = self.decode_multicall_arg(&args, 0);
+            self.sign(action_id);
+
+            action_id
+        } else if endpoint_name == *MULTICALL_PROPOSE_STATUS_ENDPOINT {
+            require!(!args.is_empty(), INVALID_NUMBER_OF_ARGUMENTS_ERR_MSG);
+
+            let esdt_safe_batch_id = self.decode_multicall_arg(&args, 0);
+            let mut tx_batch_status = MultiValueEncoded::new();
+            for i in 1..args.len() {
+                tx_batch_status.push(self.decode_multicall_arg(&args, i));
+            }
+
+            self.propose_esdt_safe_set_current_transaction_batch_status(
+                esdt_safe_batch_id,
+                tx_batch_status,
+            )
+        } else if endpoint_name == *MULTICALL_PROPOSE_TRANSFER_ENDPOINT {
+            require!(
+                !args.is_empty() && (args.len() - 1) % ETH_TX_NR_FIELDS == 0,
+                INVALID_NUMBER_OF_ARGUMENTS_ERR_MSG
+            );
+
+            let eth_batch_id = self.decode_multicall_arg(&args, 0);
+            let mut transfers = MultiValueEncoded::new();
+            for i in (1..args.len()).step_by(ETH_TX_NR_FIELDS) {
+                let from: EthAddress<Self::Api> = self.decode_multicall_arg(&args, i);
+                let to: ManagedAddress = self.decode_multicall_arg(&args, i + 1);
+                let token_id: TokenIdentifier = self.decode_multicall_arg(&args, i + 2);
+                let amount: BigUint = self.decode_multicall_arg(&args, i + 3);
+                let tx_nonce: TxNonce = self.decode_multicall_arg(&args, i + 4);
+                transfers.push((from, to, token_id, amount, tx_nonce).into());
+            }
+
+            self.propose_multi_transfer_esdt_batch(eth_batch_id, transfers)
+        } else {
+            sc_panic!(ENDPOINT_NOT_ALLOWED_IN_MULTICALL_ERR_MSG)
+        }
+    }
+
+    fn decode_multicall_arg<T: TopDecode>(
+        &self,
+        args: &ManagedVec<ManagedBuffer>,
+        index: usize,
+    ) -> T {
+        let raw_arg = (*args.get(index)).clone();
+
+        T::top_decode(raw_arg).unwrap_or_else(|_| sc_panic!(INVALID_MULTICALL_ARGUMENT_ERR_MSG))
+    }
+
+    /// Child contract calls are asynchronous, so a failing call
+    /// does not revert the multisig transaction.
+    /// The action is only cleared once the call succeeded, see `perform_action_callback`.
+    /// Until then, the action is pending, and cannot be performed again.
     fn perform_action(&self, action_id: usize) {
-        let action = self.action_mapper().get(action_id);
-        self.clear_action(action_id);
+        self.start_execution();
 
+        let action = self.action_mapper().get(action_id);
         match action {
-            Action::Nothing => {}
+            Action::Nothing => {
+                self.clear_proposed_action(action_id);
+                self.end_execution();
+            }
             Action::SetCurrentTransactionBatchStatus {
                 esdt_safe_batch_id,
                 tx_batch_status,
             } => {
-                let mut action_ids_mapper =
-                    self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id);
-
-                // if there's only one proposed action,
-                // the action was already cleared at the beginning of this function
-                if action_ids_mapper.len() > 1 {
-                    for act_id in action_ids_mapper.values() {
-                        self.clear_action(act_id);
-                    }
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .set_transaction_batch_status(
+                            esdt_safe_batch_id,
+                            action_id,
+                            self.action_executor().get(),
+                            MultiValueEncoded::from(tx_batch_status),
+                        ),
+                );
+            }
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                tx_batch_status,
+            } => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .set_chain_transaction_batch_status(
+                            chain_id,
+                            esdt_safe_batch_id,
+                            action_id,
+                            self.action_executor().get(),
+                            MultiValueEncoded::from(tx_batch_status),
+                        ),
+                );
+            }
+            Action::SetMultipleTransactionBatchStatuses {
+                chain_id,
+                batch_statuses,
+            } => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .set_multiple_transaction_batch_statuses(
+                            chain_id,
+                            action_id,
+                            self.action_executor().get(),
+                            MultiValueEncoded::from(batch_statuses),
+                        ),
+                );
+            }
+            Action::BatchTransferEsdtToken {
+                eth_batch_id,
+                transfers,
+            } => {
+                let transfers_multi: MultiValueEncoded<Self::Api, EthTransaction<Self::Api>> =
+                    transfers.into();
+                self.call_child_contract(
+                    action_id,
+                    self.get_multi_transfer_esdt_proxy_instance()
+                        .batch_transfer_esdt_token(
+                            eth_batch_id,
+                            self.max_tx_batch_gas().get(),
+                            transfers_multi,
+                        ),
+                );
+            }
+            Action::BatchTransferEsdtTokenWithEgldUnwrap {
+                eth_batch_id,
+                transfers,
+                unwrap_egld_tx_nonces,
+            } => {
+                let transfers_multi: MultiValueEncoded<Self::Api, EthTransaction<Self::Api>> =
+                    transfers.into();
+                self.call_child_contract(
+                    action_id,
+                    self.get_multi_transfer_esdt_proxy_instance()
+                        .batch_transfer_esdt_token_with_egld_unwrap(
+                            eth_batch_id,
+                            self.max_tx_batch_gas().get(),
+                            unwrap_egld_tx_nonces,
+                            transfers_multi,
+                        ),
+                );
+            }
+            Action::SetCurrentNftBatchStatus {
+                nft_safe_batch_id,
+                tx_batch_status,
+            } => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_nft_safe_proxy_instance()
+                        .set_transaction_batch_status(
+                            nft_safe_batch_id,
+                            MultiValueEncoded::from(tx_batch_status),
+                        ),
+                );
+            }
+            Action::BatchTransferNft {
+                eth_batch_id,
+                transfers,
+            } => {
+                let transfers_multi: MultiValueEncoded<Self::Api, EthNftTransaction<Self::Api>> =
+                    transfers.into();
+                self.call_child_contract(
+                    action_id,
+                    self.get_nft_transfer_proxy_instance()
+                        .batch_transfer_nft(eth_batch_id, transfers_multi),
+                );
+            }
+            Action::ForceReexecuteBatch {
+                eth_batch_id,
+                transfers,
+            } => {
+                let transfers_multi: MultiValueEncoded<Self::Api, EthTransaction<Self::Api>> =
+                    transfers.into();
+                self.call_child_contract(
+                    action_id,
+                    self.get_multi_transfer_esdt_proxy_instance()
+                        .reexecute_failed_transfers(eth_batch_id, transfers_multi),
+                );
+            }
+            Action::AddProposer(address) => {
+                self.add_proposer(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::RemoveProposer(address) => {
+                self.remove_proposer(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::AddExecutor(address) => {
+                self.add_executor(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::RemoveExecutor(address) => {
+                self.remove_executor(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::AddBoardMember(address) => {
+                self.add_board_member_from_action(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::RemoveUser(address) => {
+                self.remove_board_member_from_action(&address);
+                self.on_local_action_executed(action_id);
+            }
+            Action::ChangeQuorum(new_quorum) => {
+                self.change_quorum_from_action(new_quorum, action_id);
+                self.on_local_action_executed(action_id);
+            }
+            Action::SlashBoardMember {
+                board_member,
+                offense,
+            } => {
+                self.slash_board_member_from_action(&board_member, offense);
+                self.on_local_action_executed(action_id);
+            }
+            Action::ReverseSlash(board_member) => {
+                self.reverse_slash(&board_member);
+                self.on_local_action_executed(action_id);
+            }
+            Action::SetSlashTier { offense, amount } => {
+                self.set_slash_tier(offense, &amount, action_id);
+                self.on_local_action_executed(action_id);
+            }
+            Action::AttestRemoteReserves {
+                eth_block_number,
+                reserves,
+            } => {
+                self.record_remote_reserves(eth_block_number, &reserves, action_id);
+                self.on_local_action_executed(action_id);
+            }
+            Action::Bundle(action_ids) => {
+                for bundled_action_id in action_ids.iter() {
+                    self.perform_bundled_action(bundled_action_id);
                 }
+                self.on_local_action_executed(action_id);
+            }
+            Action::MigrateTokenMode {
+                token_id,
+                lock_mode,
+                custodian,
+            } => {
+                self.migrate_token_mode(token_id, lock_mode, custodian);
+                self.on_local_action_executed(action_id);
+            }
+            Action::ResetDailyUsdVolume => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance().reset_daily_usd_volume(),
+                );
+            }
+            Action::AddTrustedForwarder(forwarder) => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .add_trusted_forwarder(forwarder),
+                );
+            }
+            Action::RemoveTrustedForwarder(forwarder) => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .remove_trusted_forwarder(forwarder),
+                );
+            }
+            Action::SweepExpiredRefunds { token_id, treasury } => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_esdt_safe_proxy_instance()
+                        .sweep_expired_refunds(token_id, treasury),
+                );
+            }
+            Action::ReleaseQuarantinedTransfers(tx_nonces) => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_multi_transfer_esdt_proxy_instance()
+                        .release_quarantined_transfers(MultiValueEncoded::from(tx_nonces)),
+                );
+            }
+            Action::RefundQuarantinedTransfers(tx_nonces) => {
+                self.call_child_contract(
+                    action_id,
+                    self.get_multi_transfer_esdt_proxy_instance()
+                        .refund_quarantined_transfers(MultiValueEncoded::from(tx_nonces)),
+                );
+            }
+        }
+    }
+
+    /// Both child contracts are called synchronously, so a failing call reverts the whole migration.
+    fn migrate_token_mode(
+        &self,
+        token_id: TokenIdentifier,
+        lock_mode: bool,
+        custodian: Option<ManagedAddress>,
+    ) {
+        let opt_custodian = OptionalValue::from(custodian);
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .migrate_token_mode(token_id.clone(), lock_mode, opt_custodian.clone())
+            .execute_on_dest_context();
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .migrate_token_mode(token_id, lock_mode, opt_custodian)
+            .execute_on_dest_context();
+    }
+
+    /// Calls a child contract for the action, with gas reserved for the callback,
+    /// so a call using up all its gas still gets its result recorded.
+    fn call_child_contract<C: ContractCall<Self::Api>>(&self, action_id: usize, call: C) {
+        let gas_left = self.blockchain().get_gas_left();
+        let gas_for_callback_and_reserve = PERFORM_ACTION_CALLBACK_GAS + PERFORM_ACTION_GAS_RESERVE;
+        require!(
+            gas_left > gas_for_callback_and_reserve,
+            NOT_ENOUGH_GAS_FOR_ACTION_CALL_ERR_MSG
+        );
+
+        self.action_execution_status(action_id)
+            .set(ActionExecutionStatus::Pending);
+        call.with_gas_limit(gas_left - gas_for_callback_and_reserve)
+            .async_call_promise()
+            .with_callback(
+                self.callbacks()
+                    .perform_action_callback(action_id, self.action_executor().get()),
+            )
+            .with_extra_gas_for_callback(PERFORM_ACTION_CALLBACK_GAS)
+            .register_promise();
+
+        self.end_execution();
+    }
 
-                action_ids_mapper.clear();
+    /// Same as `perform_action`, but child contracts are called synchronously,
+    /// so a failing call reverts the whole bundle.
+    ///
+    /// Each bundled action is checked the same way `performAction` checks it, quorum aside.
+    /// Its own execution timelock only applies if it reached quorum by itself,
+    /// otherwise the bundle's timelock covers it.
+    fn perform_bundled_action(&self, action_id: usize) {
+        require!(
+            !self.action_mapper().item_is_empty(action_id),
+            BUNDLED_ACTION_ALREADY_EXECUTED_ERR_MSG
+        );
+        require!(
+            !self.is_action_call_pending(action_id),
+            ACTION_CALL_PENDING_ERR_MSG
+        );
+        require!(!self.is_stale_action(action_id), STALE_ACTION_ERR_MSG);
+        require!(!self.is_expired_action(action_id), ACTION_EXPIRED_ERR_MSG);
+        require!(
+            self.action_unlock_block(action_id).is_empty()
+                || self.is_execution_timelock_over(action_id),
+            EXECUTION_TIMELOCK_NOT_ENDED_ERR_MSG
+        );
 
+        let action = self.action_mapper().get(action_id);
+        match action {
+            Action::Nothing | Action::Bundle(_) => sc_panic!(INVALID_BUNDLED_ACTION_ERR_MSG),
+            Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status,
+            } => {
                 let _: IgnoreValue = self
                     .get_esdt_safe_proxy_instance()
                     .set_transaction_batch_status(
                         esdt_safe_batch_id,
+                        action_id,
+                        self.action_executor().get(),
                         MultiValueEncoded::from(tx_batch_status),
                     )
                     .execute_on_dest_context();
             }
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                tx_batch_status,
+            } => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .set_chain_transaction_batch_status(
+                        chain_id,
+                        esdt_safe_batch_id,
+                        action_id,
+                        self.action_executor().get(),
+                        MultiValueEncoded::from(tx_batch_status),
+                    )
+                    .execute_on_dest_context();
+            }
+            Action::SetMultipleTransactionBatchStatuses {
+                chain_id,
+                batch_statuses,
+            } => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .set_multiple_transaction_batch_statuses(
+                        chain_id,
+                        action_id,
+                        self.action_executor().get(),
+                        MultiValueEncoded::from(batch_statuses),
+                    )
+                    .execute_on_dest_context();
+            }
             Action::BatchTransferEsdtToken {
                 eth_batch_id,
                 transfers,
             } => {
-                let mut action_ids_mapper = self.batch_id_to_action_id_mapping(eth_batch_id);
-
-                // if there's only one proposed action,
-                // the action was already cleared at the beginning of this function
-                if action_ids_mapper.len() > 1 {
-                    for act_id in action_ids_mapper.values() {
-                        self.clear_action(act_id);
-                    }
-                }
+                let _: IgnoreValue = self
+                    .get_multi_transfer_esdt_proxy_instance()
+                    .batch_transfer_esdt_token(
+                        eth_batch_id,
+                        self.max_tx_batch_gas().get(),
+                        MultiValueEncoded::from(transfers),
+                    )
+                    .execute_on_dest_context();
+            }
+            Action::BatchTransferEsdtTokenWithEgldUnwrap {
+                eth_batch_id,
+                transfers,
+                unwrap_egld_tx_nonces,
+            } => {
+                let _: IgnoreValue = self
+                    .get_multi_transfer_esdt_proxy_instance()
+                    .batch_transfer_esdt_token_with_egld_unwrap(
+                        eth_batch_id,
+                        self.max_tx_batch_gas().get(),
+                        unwrap_egld_tx_nonces,
+                        MultiValueEncoded::from(transfers),
+                    )
+                    .execute_on_dest_context();
+            }
+            Action::SetCurrentNftBatchStatus {
+                nft_safe_batch_id,
+                tx_batch_status,
+            } => {
+                let _: IgnoreValue = self
+                    .get_nft_safe_proxy_instance()
+                    .set_transaction_batch_status(
+                        nft_safe_batch_id,
+                        MultiValueEncoded::from(tx_batch_status),
+                    )
+                    .execute_on_dest_context();
+            }
+            Action::BatchTransferNft {
+                eth_batch_id,
+                transfers,
+            } => {
+                let _: IgnoreValue = self
+                    .get_nft_transfer_proxy_instance()
+                    .batch_transfer_nft(eth_batch_id, MultiValueEncoded::from(transfers))
+                    .execute_on_dest_context();
+            }
+            Action::ForceReexecuteBatch {
+                eth_batch_id,
+                transfers,
+            } => {
+                let _: IgnoreValue = self
+                    .get_multi_transfer_esdt_proxy_instance()
+                    .reexecute_failed_transfers(eth_batch_id, MultiValueEncoded::from(transfers))
+                    .execute_on_dest_context();
+            }
+            Action::AddProposer(address) => self.add_proposer(&address),
+            Action::RemoveProposer(address) => self.remove_proposer(&address),
+            Action::AddExecutor(address) => self.add_executor(&address),
+            Action::RemoveExecutor(address) => self.remove_executor(&address),
+            Action::AddBoardMember(address) => self.add_board_member_from_action(&address),
+            Action::RemoveUser(address) => self.remove_board_member_from_action(&address),
+            Action::ChangeQuorum(new_quorum) => {
+                self.change_quorum_from_action(new_quorum, action_id)
+            }
+            Action::SlashBoardMember {
+                board_member,
+                offense,
+            } => self.slash_board_member_from_action(&board_member, offense),
+            Action::ReverseSlash(board_member) => self.reverse_slash(&board_member),
+            Action::SetSlashTier { offense, amount } => {
+                self.set_slash_tier(offense, &amount, action_id)
+            }
+            Action::AttestRemoteReserves {
+                eth_block_number,
+                reserves,
+            } => self.record_remote_reserves(eth_block_number, &reserves, action_id),
+            Action::MigrateTokenMode {
+                token_id,
+                lock_mode,
+                custodian,
+            } => self.migrate_token_mode(token_id, lock_mode, custodian),
+            Action::ResetDailyUsdVolume => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .reset_daily_usd_volume()
+                    .execute_on_dest_context();
+            }
+            Action::AddTrustedForwarder(forwarder) => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .add_trusted_forwarder(forwarder)
+                    .execute_on_dest_context();
+            }
+            Action::RemoveTrustedForwarder(forwarder) => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .remove_trusted_forwarder(forwarder)
+                    .execute_on_dest_context();
+            }
+            Action::SweepExpiredRefunds { token_id, treasury } => {
+                let _: IgnoreValue = self
+                    .get_esdt_safe_proxy_instance()
+                    .sweep_expired_refunds(token_id, treasury)
+                    .execute_on_dest_context();
+            }
+            Action::ReleaseQuarantinedTransfers(tx_nonces) => {
+                let _: IgnoreValue = self
+                    .get_multi_transfer_esdt_proxy_instance()
+                    .release_quarantined_transfers(MultiValueEncoded::from(tx_nonces))
+                    .execute_on_dest_context();
+            }
+            Action::RefundQuarantinedTransfers(tx_nonces) => {
+                let _: IgnoreValue = self
+                    .get_multi_transfer_esdt_proxy_instance()
+                    .refund_quarantined_transfers(MultiValueEncoded::from(tx_nonces))
+                    .execute_on_dest_context();
+            }
+        }
+
+        self.on_action_executed(action_id);
+        self.action_execution_status(action_id)
+            .set(ActionExecutionStatus::Executed);
+    }
+
+    /// Actions without child contract calls are executed directly, without a callback.
+    fn on_local_action_executed(&self, action_id: usize) {
+        self.on_action_executed(action_id);
+        self.action_execution_status(action_id)
+            .set(ActionExecutionStatus::Executed);
+
+        self.end_execution();
+    }
 
-                action_ids_mapper.clear();
+    /// On success, the action is cleared, along with all the other actions
+    /// proposed for the same batch.
+    ///
+    /// On failure, the action and its signatures are kept,
+    /// so it can be performed again later.
+    ///
+    /// Other actions might have been performed since the call was made, so the executor is passed along.
+    #[promises_callback]
+    fn perform_action_callback(
+        &self,
+        action_id: usize,
+        executor: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        self.start_execution();
+        self.action_executor().set(&executor);
+
+        // cleared along with another action for the same batch, whose call got there first
+        if self.action_mapper().item_is_empty_unchecked(action_id) {
+            self.action_execution_status(action_id).clear();
+            self.end_execution();
+            return;
+        }
+
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.on_action_executed(action_id);
+                self.action_execution_status(action_id)
+                    .set(ActionExecutionStatus::Executed);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                let action = self.action_mapper().get(action_id);
+                self.archive_action(action_id, &action, ActionExecutionStatus::Failed);
+                self.action_execution_failed_event(action_id, &self.action_executor().get());
+                self.action_execution_status(action_id)
+                    .set(ActionExecutionStatus::Failed);
+            }
+        }
+
+        self.end_execution();
+    }
+
+    fn on_action_executed(&self, action_id: usize) {
+        let action = self.action_mapper().get(action_id);
+        self.archive_action(action_id, &action, ActionExecutionStatus::Executed);
+        self.action_executed_event(action_id, &self.action_executor().get());
+        self.record_executed_action();
+        self.remove_action(action_id);
+
+        match action {
+            Action::Nothing => {}
+            Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status: _,
+            } => {
+                self.clear_batch_actions(
+                    self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id),
+                );
+            }
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                tx_batch_status: _,
+            } => {
+                self.clear_batch_actions(self.action_id_for_set_chain_transaction_batch_status(
+                    chain_id,
+                    esdt_safe_batch_id,
+                ));
+            }
+            Action::SetMultipleTransactionBatchStatuses {
+                chain_id,
+                batch_statuses,
+            } => {
+                for batch in batch_statuses.iter() {
+                    self.clear_batch_actions(
+                        self.set_status_action_ids_mapper(chain_id, batch.batch_id),
+                    );
+                }
+            }
+            Action::BatchTransferEsdtToken {
+                eth_batch_id,
+                transfers,
+            }
+            | Action::BatchTransferEsdtTokenWithEgldUnwrap {
+                eth_batch_id,
+                transfers,
+                ..
+            } => {
+                // before clearing the batch's actions, which also drops their notifiers
+                self.notify_batch_transfer_executed(action_id, eth_batch_id);
+                self.clear_batch_actions(self.batch_id_to_action_id_mapping(eth_batch_id));
                 self.last_executed_eth_batch_id().update(|id| *id += 1);
 
                 let last_tx_index = transfers.len() - 1;
                 let last_tx = transfers.get(last_tx_index);
                 self.last_executed_eth_tx_id().set(last_tx.tx_nonce);
+            }
+            Action::SetCurrentNftBatchStatus {
+                nft_safe_batch_id,
+                tx_batch_status: _,
+            } => {
+                self.clear_batch_actions(
+                    self.action_id_for_set_current_nft_batch_status(nft_safe_batch_id),
+                );
+            }
+            Action::BatchTransferNft {
+                eth_batch_id,
+                transfers,
+            } => {
+                self.clear_batch_actions(self.nft_batch_id_to_action_id_mapping(eth_batch_id));
+                self.last_executed_eth_nft_batch_id().update(|id| *id += 1);
 
-                let transfers_multi: MultiValueEncoded<Self::Api, EthTransaction<Self::Api>> =
-                    transfers.into();
-                let _: IgnoreValue = self
-                    .get_multi_transfer_esdt_proxy_instance()
-                    .batch_transfer_esdt_token(eth_batch_id, transfers_multi)
-                    .execute_on_dest_context();
+                let last_tx_index = transfers.len() - 1;
+                let last_tx = transfers.get(last_tx_index);
+                self.last_executed_eth_nft_tx_id().set(last_tx.tx_nonce);
             }
+            Action::ForceReexecuteBatch { .. }
+            | Action::AddProposer(_)
+            | Action::RemoveProposer(_)
+            | Action::AddExecutor(_)
+            | Action::RemoveExecutor(_)
+            | Action::AddBoardMember(_)
+            | Action::RemoveUser(_)
+            | Action::ChangeQuorum(_)
+            | Action::SlashBoardMember { .. }
+            | Action::Bundle(_)
+            | Action::ResetDailyUsdVolume
+            | Action::AddTrustedForwarder(_)
+            | Action::RemoveTrustedForwarder(_)
+            | Action::SweepExpiredRefunds { .. }
+            | Action::ReverseSlash(_)
+            | Action::SetSlashTier { .. }
+            | Action::AttestRemoteReserves { .. }
+            | Action::ReleaseQuarantinedTransfers(_)
+            | Action::RefundQuarantinedTransfers(_)
+            | Action::MigrateTokenMode { .. } => {}
         }
     }
+
+    /// Clears the action, along with its entry in the proposals registered for its batch,
+    /// so the same action can be proposed again.
+    fn clear_proposed_action(&self, action_id: usize) {
+        match self.action_mapper().get(action_id) {
+            Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id,
+                tx_batch_status,
+            } => {
+                self.set_status_action_ids_mapper(ETHEREUM_CHAIN_ID, esdt_safe_batch_id)
+                    .remove(&pack_statuses(&tx_batch_status));
+            }
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                tx_batch_status,
+            } => {
+                self.set_status_action_ids_mapper(chain_id, esdt_safe_batch_id)
+                    .remove(&pack_statuses(&tx_batch_status));
+            }
+            Action::SetMultipleTransactionBatchStatuses {
+                chain_id,
+                batch_statuses,
+            } => {
+                self.set_status_action_ids_mapper(chain_id, batch_statuses.get(0).batch_id)
+                    .remove(&pack_batch_statuses(&batch_statuses));
+            }
+            Action::BatchTransferEsdtToken {
+                eth_batch_id,
+                transfers,
+            }
+            | Action::BatchTransferEsdtTokenWithEgldUnwrap {
+                eth_batch_id,
+                transfers,
+                ..
+            } => {
+                self.batch_id_to_action_id_mapping(eth_batch_id)
+                    .remove(&self.hash_eth_tx_batch(&transfers));
+            }
+            Action::SetCurrentNftBatchStatus {
+                nft_safe_batch_id,
+                tx_batch_status,
+            } => {
+                self.action_id_for_set_current_nft_batch_status(nft_safe_batch_id)
+                    .remove(&pack_statuses(&tx_batch_status));
+            }
+            Action::BatchTransferNft {
+                eth_batch_id,
+                transfers,
+            } => {
+                self.nft_batch_id_to_action_id_mapping(eth_batch_id)
+                    .remove(&self.hash_eth_tx_batch(&transfers));
+            }
+            _ => {}
+        }
+
+        self.clear_action(action_id);
+    }
+
+    /// Clears all the actions proposed for the same batch as the executed one.
+    fn clear_batch_actions<K>(&self, mut action_ids_mapper: MapMapper<K, usize>)
+    where
+        K: TopEncode + TopDecode + NestedEncode + NestedDecode,
+    {
+        for act_id in action_ids_mapper.values() {
+            self.clear_action(act_id);
+        }
+
+        action_ids_mapper.clear();
+    }
 }
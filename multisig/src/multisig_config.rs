@@ -0,0 +1,18 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// All the multisig settings relayers need at startup.
+/// The NFT bridge contract addresses are missing if the NFT bridge was not set up.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct MultisigConfig<M: ManagedTypeApi> {
+    pub quorum: usize,
+    pub required_stake: BigUint<M>,
+    /// Slashed amount for each `SlashOffense`, in declaration order.
+    pub slash_tiers: ManagedVec<M, BigUint<M>>,
+    pub num_board_members: usize,
+    pub is_paused: bool,
+    pub esdt_safe_address: ManagedAddress<M>,
+    pub multi_transfer_esdt_address: ManagedAddress<M>,
+    pub nft_safe_address: Option<ManagedAddress<M>>,
+    pub nft_transfer_address: Option<ManagedAddress<M>>,
+}
@@ -1,51 +1,209 @@
 multiversx_sc::imports!();
 
-use crate::action::Action;
+use crate::action::{Action, ActionSignResult};
 use crate::user_role::UserRole;
+use bridge_errors::{
+    ACTION_CALL_PENDING_ERR_MSG, ACTION_DOES_NOT_EXIST_ERR_MSG, ACTION_EXPIRED_ERR_MSG,
+    ACTION_NOT_SIGNED_BY_CALLER_ERR_MSG, ACTION_VETOED_ERR_MSG, NOT_ENOUGH_STAKE_ERR_MSG,
+    ONBOARDING_NOT_COMPLETED_ERR_MSG, ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_PROPOSE_ERR_MSG,
+    ONLY_BOARD_MEMBERS_CAN_SIGN_ERR_MSG, PROPOSING_WHILE_PAUSED_ERR_MSG, STALE_ACTION_ERR_MSG,
+    USER_ALREADY_HAS_ROLE_ERR_MSG, USER_NOT_EXECUTOR_ERR_MSG, USER_NOT_PROPOSER_ERR_MSG,
+};
 
 #[multiversx_sc::module]
 pub trait MultisigGeneralModule:
-    crate::util::UtilModule + crate::storage::StorageModule + multiversx_sc_modules::pause::PauseModule
+    crate::util::UtilModule
+    + crate::storage::StorageModule
+    + crate::action_statistics::ActionStatisticsModule
+    + crate::pause_status::PauseStatusModule
+    + crate::action_archive::ActionArchiveModule
+    + execution_guard_module::ExecutionGuardModule
 {
-    /// Used by board members to sign actions.
+    /// Used by board members, or their signing keys, to sign actions.
+    ///
+    /// Signing an action again starts its execution timelock (see `setExecutionTimelock`),
+    /// if it reached the quorum without a new signature, e.g. because the quorum was lowered.
     #[endpoint]
     fn sign(&self, action_id: usize) {
         require!(
             !self.action_mapper().item_is_empty_unchecked(action_id),
-            "action does not exist"
+            ACTION_DOES_NOT_EXIST_ERR_MSG
         );
 
-        let caller_address = self.blockchain().get_caller();
+        let caller_id = self.require_caller_can_sign();
+        require!(!self.is_stale_action(action_id), STALE_ACTION_ERR_MSG);
+        require!(!self.is_expired_action(action_id), ACTION_EXPIRED_ERR_MSG);
+
+        self.add_signature(action_id, caller_id);
+    }
+
+    /// Same as `sign`, for several actions at once.
+    /// Actions that do not exist (anymore), are stale or have expired are skipped instead of failing the transaction.
+    ///
+    /// Returns pairs of (action ID, result), in the order the IDs were given.
+    #[endpoint(signBatch)]
+    fn sign_batch(
+        &self,
+        action_ids: MultiValueEncoded<usize>,
+    ) -> MultiValueEncoded<MultiValue2<usize, ActionSignResult>> {
+        let caller_id = self.require_caller_can_sign();
+
+        let mut results = MultiValueEncoded::new();
+        for action_id in action_ids {
+            let result = if self.action_mapper().item_is_empty_unchecked(action_id) {
+                ActionSignResult::ActionDoesNotExist
+            } else if self.is_stale_action(action_id) {
+                ActionSignResult::StaleAction
+            } else if self.is_expired_action(action_id) {
+                ActionSignResult::ActionExpired
+            } else if self.action_signer_ids(action_id).contains(&caller_id) {
+                self.start_execution_timelock(action_id);
+                ActionSignResult::AlreadySigned
+            } else {
+                self.add_signature(action_id, caller_id);
+                ActionSignResult::Signed
+            };
+            results.push((action_id, result).into());
+        }
+
+        results
+    }
+
+    /// Withdraws the caller's signature from an action that was not performed yet.
+    /// If the quorum was reached only thanks to this signature, the action cannot be performed
+    /// until another board member signs it, and its execution timelock starts over.
+    /// Signatures cannot be withdrawn while the action's child contract call is pending.
+    #[endpoint]
+    fn unsign(&self, action_id: usize) {
+        require!(
+            !self.action_mapper().item_is_empty_unchecked(action_id),
+            ACTION_DOES_NOT_EXIST_ERR_MSG
+        );
+        require!(
+            !self.is_action_call_pending(action_id),
+            ACTION_CALL_PENDING_ERR_MSG
+        );
+
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
+        let caller_id = self.user_mapper().get_user_id(&caller_address);
+        require!(
+            self.action_signer_ids(action_id).swap_remove(&caller_id),
+            ACTION_NOT_SIGNED_BY_CALLER_ERR_MSG
+        );
+
+        self.action_unsigned_event(action_id, &caller_address);
+
+        if self.count_valid_signers(action_id) < self.quorum().get() {
+            self.action_unlock_block(action_id).clear();
+        }
+    }
+
+    /// Returns the user ID of the board member signing on behalf of the caller.
+    fn require_caller_can_sign(&self) -> usize {
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
         let caller_id = self.user_mapper().get_user_id(&caller_address);
         let caller_role = self.user_id_to_role(caller_id).get();
-        require!(caller_role.is_board_member(), "only board members can sign");
-        require!(self.has_enough_stake(&caller_address), "not enough stake");
+        require!(
+            caller_role.is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_SIGN_ERR_MSG
+        );
+        require!(
+            self.has_enough_stake(&caller_address),
+            NOT_ENOUGH_STAKE_ERR_MSG
+        );
+        require!(
+            self.has_completed_onboarding(&caller_address),
+            ONBOARDING_NOT_COMPLETED_ERR_MSG
+        );
 
-        let _ = self.action_signer_ids(action_id).insert(caller_id);
+        caller_id
     }
 
     fn propose_action(&self, action: Action<Self::Api>) -> usize {
-        let caller_address = self.blockchain().get_caller();
+        let caller_address = self.get_acting_user(&self.blockchain().get_caller());
         let caller_id = self.user_mapper().get_user_id(&caller_address);
         let caller_role = self.user_id_to_role(caller_id).get();
         require!(
-            caller_role.is_board_member(),
-            "only board members can propose"
+            caller_role.can_propose(),
+            ONLY_BOARD_MEMBERS_AND_PROPOSERS_CAN_PROPOSE_ERR_MSG
         );
 
-        require!(self.not_paused(), "No actions may be proposed while paused");
+        require!(self.not_paused(), PROPOSING_WHILE_PAUSED_ERR_MSG);
+        require!(
+            !self
+                .vetoed_action_hashes()
+                .contains(&self.get_action_hash(&action)),
+            ACTION_VETOED_ERR_MSG
+        );
 
         let action_id = self.action_mapper().push(&action);
-        if self.has_enough_stake(&caller_address) {
-            let _ = self.action_signer_ids(action_id).insert(caller_id);
+        self.action_proposer(action_id).set(caller_id);
+        let action_ttl = self.action_ttl().get();
+        if action_ttl > 0 {
+            self.action_expiry_block(action_id)
+                .set(self.blockchain().get_block_nonce() + action_ttl);
+        }
+        self.record_proposed_action();
+        self.action_proposed_event(action_id, &caller_address, action.action_type());
+        if self.is_active_board_member(&caller_address) {
+            self.add_signature(action_id, caller_id);
         }
 
         action_id
     }
 
+    /// The signer must be a board member with enough stake.
+    /// Emits `quorumReached` if this is the signature that completes the quorum.
+    fn add_signature(&self, action_id: usize, signer_id: usize) {
+        let valid_signers_before = self.count_valid_signers(action_id);
+        if self.action_signer_ids(action_id).insert(signer_id) {
+            let signer_address = self
+                .user_mapper()
+                .get_user_address(signer_id)
+                .unwrap_or_default();
+            self.action_signed_event(action_id, &signer_address);
+
+            let quorum = self.quorum().get();
+            if valid_signers_before < quorum && valid_signers_before + 1 >= quorum {
+                self.quorum_reached_event(action_id);
+            }
+        }
+
+        self.start_execution_timelock(action_id);
+    }
+
+    /// Starts the execution timelock of an action that reached the quorum, unless already started.
+    fn start_execution_timelock(&self, action_id: usize) {
+        let execution_timelock = self.execution_timelock().get();
+        let unlock_block_mapper = self.action_unlock_block(action_id);
+        if execution_timelock == 0
+            || !unlock_block_mapper.is_empty()
+            || self.count_valid_signers(action_id) < self.quorum().get()
+        {
+            return;
+        }
+
+        unlock_block_mapper.set(self.blockchain().get_block_nonce() + execution_timelock);
+    }
+
+    /// Discards the action, if still pending.
     fn clear_action(&self, action_id: usize) {
+        if !self.action_mapper().item_is_empty_unchecked(action_id) {
+            self.record_discarded_action();
+            self.action_discarded_event(action_id, &self.get_lifecycle_event_caller());
+        }
+
+        self.remove_action(action_id);
+        self.action_execution_notifier(action_id).clear();
+    }
+
+    fn remove_action(&self, action_id: usize) {
         self.action_mapper().clear_entry_unchecked(action_id);
         self.action_signer_ids(action_id).clear();
+        self.action_expiry_block(action_id).clear();
+        self.action_unlock_block(action_id).clear();
+        self.action_proposer(action_id).clear();
+        self.action_discard_votes(action_id).clear();
     }
 
     fn add_board_member(&self, user_address: &ManagedAddress) {
@@ -58,6 +216,46 @@ pub trait MultisigGeneralModule:
         }
     }
 
+    fn add_proposer(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_or_create_user(user_address);
+        require!(
+            self.user_id_to_role(user_id).get() == UserRole::None,
+            USER_ALREADY_HAS_ROLE_ERR_MSG
+        );
+
+        self.user_id_to_role(user_id).set(UserRole::Proposer);
+    }
+
+    fn remove_proposer(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_user_id(user_address);
+        require!(
+            user_id != 0 && self.user_id_to_role(user_id).get() == UserRole::Proposer,
+            USER_NOT_PROPOSER_ERR_MSG
+        );
+
+        self.user_id_to_role(user_id).set(UserRole::None);
+    }
+
+    fn add_executor(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_or_create_user(user_address);
+        require!(
+            self.user_id_to_role(user_id).get() == UserRole::None,
+            USER_ALREADY_HAS_ROLE_ERR_MSG
+        );
+
+        self.user_id_to_role(user_id).set(UserRole::Executor);
+    }
+
+    fn remove_executor(&self, user_address: &ManagedAddress) {
+        let user_id = self.user_mapper().get_user_id(user_address);
+        require!(
+            user_id != 0 && self.user_id_to_role(user_id).get() == UserRole::Executor,
+            USER_NOT_EXECUTOR_ERR_MSG
+        );
+
+        self.user_id_to_role(user_id).set(UserRole::None);
+    }
+
     fn remove_board_member(&self, user_address: &ManagedAddress) {
         let user_id = self.user_mapper().get_user_id(user_address);
         if user_id == 0 {
@@ -70,4 +268,67 @@ pub trait MultisigGeneralModule:
             self.user_id_to_role(user_id).set(UserRole::None);
         }
     }
+
+    /// The account reported by the action lifecycle events: the executor while an action
+    /// is being performed (e.g. for the other proposals of an executed batch, discarded along with it),
+    /// otherwise the caller, or the board member whose signing key the caller is.
+    fn get_lifecycle_event_caller(&self) -> ManagedAddress {
+        if self.execution_in_progress().get() {
+            self.action_executor().get()
+        } else {
+            self.get_acting_user(&self.blockchain().get_caller())
+        }
+    }
+
+    #[event("quorumReached")]
+    fn quorum_reached_event(&self, #[indexed] action_id: usize);
+
+    #[event("actionProposed")]
+    fn action_proposed_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] action_type: u8,
+    );
+
+    #[event("actionSigned")]
+    fn action_signed_event(&self, #[indexed] action_id: usize, #[indexed] signer: &ManagedAddress);
+
+    #[event("actionUnsigned")]
+    fn action_unsigned_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] signer: &ManagedAddress,
+    );
+
+    #[event("actionExecuted")]
+    fn action_executed_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] executor: &ManagedAddress,
+    );
+
+    /// The child contract call failed. The action is kept, and can be performed again.
+    #[event("actionExecutionFailed")]
+    fn action_execution_failed_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] executor: &ManagedAddress,
+    );
+
+    /// Emitted for actions cleared without being executed, e.g. through `discardAction`.
+    #[event("actionDiscarded")]
+    fn action_discarded_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("actionVetoed")]
+    fn action_vetoed_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] board_member: &ManagedAddress,
+        reason: &ManagedBuffer,
+    );
 }
@@ -0,0 +1,25 @@
+multiversx_sc::imports!();
+
+/// The multisig's own pause flag, in place of `multiversx_sc_modules::pause::PauseModule`,
+/// whose `pause` and `unpause` endpoints only pause this SC.
+/// The endpoints are in the setup module instead, and also pause the child contracts.
+///
+/// Uses the same storage key as the framework module, so upgrading keeps the current status.
+#[multiversx_sc::module]
+pub trait PauseStatusModule {
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused_status().get()
+    }
+
+    fn not_paused(&self) -> bool {
+        !self.is_paused()
+    }
+
+    fn set_paused(&self, paused: bool) {
+        self.paused_status().set(paused);
+    }
+
+    #[storage_mapper("pause_module:paused")]
+    fn paused_status(&self) -> SingleValueMapper<bool>;
+}
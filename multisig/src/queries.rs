@@ -1,23 +1,116 @@
 multiversx_sc::imports!();
 
-use crate::{action::Action, user_role::UserRole};
-use transaction::{transaction_status::TransactionStatus, EthTxAsMultiValue, TxBatchSplitInFields};
+use crate::{
+    action::{Action, ActionFullInfo},
+    bridge_activity::UserBridgeActivity,
+    multisig_config::MultisigConfig,
+    slash_offense::SlashOffense,
+    status_proposal::StatusProposal,
+    supply_reconciliation::SupplyReconciliation,
+    user_role::UserRole,
+};
+use bridge_errors::BATCH_DOES_NOT_EXIST_ERR_MSG;
+use transaction::eip712::{hash_batch, BatchAttestation, Eip712Hash};
+use transaction::{
+    compact_batch::CompactTxBatch,
+    join_batch,
+    transaction_status::{pack_statuses, unpack_statuses, TransactionStatus},
+    ChainId, EthTxAsMultiValue, Transaction, TxAsMultiValue, TxBatchSplitInFields,
+    ETHEREUM_CHAIN_ID,
+};
 
+use esdt_safe::ProxyTrait as _;
+use nft_safe::ProxyTrait as _;
 use tx_batch_module::ProxyTrait as _;
 
 /// Note: Additional queries can be found in the Storage module
 #[multiversx_sc::module]
-pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule {
+pub trait QueriesModule:
+    crate::storage::StorageModule
+    + crate::util::UtilModule
+    + bridge_domain_module::BridgeDomainModule
+    + crate::pause_status::PauseStatusModule
+{
     /// Returns the current EsdtSafe batch.
     ///
     /// First result is the batch ID, then pairs of 6 results, representing transactions
     /// split by fields:
     ///
     /// Block Nonce, Tx Nonce, Sender Address, Receiver Address, Token ID, Amount
+    ///
+    /// If no chain ID is provided, the batch for Ethereum is returned.
     #[view(getCurrentTxBatch)]
-    fn get_current_tx_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+    fn get_current_tx_batch(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
+        self.get_esdt_safe_proxy_instance()
+            .get_current_tx_batch(opt_chain_id)
+            .execute_on_dest_context()
+    }
+
+    /// Same as `getCurrentTxBatch`, but with the batch encoded as a single buffer,
+    /// which is much smaller for large batches.
+    /// `transaction::compact_batch::decode_compact_batch` decodes it.
+    #[view(getCurrentTxBatchCompact)]
+    fn get_current_tx_batch_compact(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<CompactTxBatch<Self::Api>> {
+        self.get_esdt_safe_proxy_instance()
+            .get_current_tx_batch_compact(opt_chain_id)
+            .execute_on_dest_context()
+    }
+
+    /// The EsdtSafe's current batch with the digest relayers sign for the Ethereum side,
+    /// see the EsdtSafe's `getCurrentBatchAttestation`.
+    #[view(getCurrentBatchAttestation)]
+    #[label("queries")]
+    fn get_current_batch_attestation(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> OptionalValue<BatchAttestation<Self::Api>> {
+        self.get_esdt_safe_proxy_instance()
+            .get_current_batch_attestation(opt_chain_id)
+            .execute_on_dest_context()
+    }
+
+    /// Lists the EsdtSafe batches that were created, but did not have their statuses set yet,
+    /// as pairs of (batch ID, number of transactions).
+    ///
+    /// If no chain ID is provided, the batches for Ethereum are returned.
+    #[view(getPendingEsdtSafeBatches)]
+    #[label("queries")]
+    fn get_pending_esdt_safe_batches(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValueEncoded<MultiValue2<u64, usize>> {
+        self.get_esdt_safe_proxy_instance()
+            .get_pending_batch_sizes(opt_chain_id)
+            .execute_on_dest_context()
+    }
+
+    /// Combines the EsdtSafe reconciliation of the given token with the amount minted
+    /// by MultiTransferEsdt. `esdt_safe.is_balanced` is false on any mismatch
+    /// between the locked tokens and what the EsdtSafe owes.
+    #[view(getTokenSupplyReconciliation)]
+    #[label("queries")]
+    fn get_token_supply_reconciliation(
+        &self,
+        token_id: TokenIdentifier,
+    ) -> SupplyReconciliation<Self::Api> {
+        self.get_supply_reconciliation(token_id)
+    }
+
+    /// Returns the EsdtSafe batch watermarks, as (last created batch ID, last settled batch ID, first unsettled batch ID).
+    /// Relayers can use it to find where to resume after downtime.
+    #[view(getEsdtSafeBatchWatermarks)]
+    fn get_esdt_safe_batch_watermarks(
+        &self,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValue3<u64, u64, u64> {
         self.get_esdt_safe_proxy_instance()
-            .get_current_tx_batch()
+            .get_batch_watermarks(opt_chain_id)
             .execute_on_dest_context()
     }
 
@@ -26,10 +119,114 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
     #[view(getCurrentRefundBatch)]
     fn get_current_refund_batch(&self) -> OptionalValue<TxBatchSplitInFields<Self::Api>> {
         self.get_multi_transfer_esdt_proxy_instance()
-            .get_first_batch_any_status()
+            .get_first_batch_any_status(OptionalValue::<ChainId>::None)
             .execute_on_dest_context()
     }
 
+    /// Returns all the multisig settings in a single call.
+    #[view(getFullMultisigConfig)]
+    #[label("queries")]
+    fn get_full_multisig_config(&self) -> MultisigConfig<Self::Api> {
+        let nft_safe_address_mapper = self.nft_safe_address();
+        let nft_transfer_address_mapper = self.nft_transfer_address();
+
+        MultisigConfig {
+            quorum: self.quorum().get(),
+            required_stake: self.required_stake_amount().get(),
+            slash_tiers: SlashOffense::ALL
+                .iter()
+                .map(|offense| self.slash_tier(*offense).get())
+                .collect(),
+            num_board_members: self.num_board_members().get(),
+            is_paused: self.is_paused(),
+            esdt_safe_address: self.esdt_safe_address().get(),
+            multi_transfer_esdt_address: self.multi_transfer_esdt_address().get(),
+            nft_safe_address: (!nft_safe_address_mapper.is_empty())
+                .then(|| nft_safe_address_mapper.get()),
+            nft_transfer_address: (!nft_transfer_address_mapper.is_empty())
+                .then(|| nft_transfer_address_mapper.get()),
+        }
+    }
+
+    /// Pairs of (offense, slashed amount), for every kind of offense.
+    #[view(getSlashTiers)]
+    fn get_slash_tiers(&self) -> MultiValueEncoded<MultiValue2<SlashOffense, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for offense in SlashOffense::ALL {
+            result.push((offense, self.slash_tier(offense).get()).into());
+        }
+
+        result
+    }
+
+    /// Hash of the given EsdtSafe batch, computed the same way as
+    /// the EsdtSafe's `computeBatchHash` view, from the batch returned by `getBatch`.
+    #[view(computeBatchHash)]
+    #[label("queries")]
+    fn compute_batch_hash(
+        &self,
+        batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> Eip712Hash<Self::Api> {
+        let opt_batch: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
+            .get_esdt_safe_proxy_instance()
+            .get_batch(batch_id, opt_chain_id)
+            .execute_on_dest_context();
+        let (_, transactions) = match opt_batch {
+            OptionalValue::Some(batch) => join_batch(batch),
+            OptionalValue::None => sc_panic!(BATCH_DOES_NOT_EXIST_ERR_MSG),
+        };
+
+        hash_batch(
+            &self.get_configured_bridge_domain(),
+            batch_id,
+            &transactions,
+        )
+    }
+
+    /// Aggregates the user's state across the bridge contracts, in a single call:
+    /// pending Elrond -> Ethereum transactions, claimable refunds in the EsdtSafe
+    /// and NftSafe, and the amount staked in this contract.
+    #[view(getUserBridgeActivity)]
+    #[label("queries")]
+    fn get_user_bridge_activity(&self, address: ManagedAddress) -> UserBridgeActivity<Self::Api> {
+        let pending_tx_fields: MultiValueEncoded<TxAsMultiValue<Self::Api>> = self
+            .get_esdt_safe_proxy_instance()
+            .get_pending_transactions(address.clone(), OptionalValue::<ChainId>::None)
+            .execute_on_dest_context();
+        let mut pending_transactions = ManagedVec::new();
+        for tx_fields in pending_tx_fields {
+            pending_transactions.push(Transaction::from(tx_fields));
+        }
+
+        let refund_amounts: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> = self
+            .get_esdt_safe_proxy_instance()
+            .get_refund_amounts(address.clone())
+            .execute_on_dest_context();
+        let mut refunds = ManagedVec::new();
+        for refund in refund_amounts {
+            let (token_id, amount) = refund.into_tuple();
+            refunds.push(EsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        let nft_refunds = if self.nft_safe_address().is_empty() {
+            ManagedVec::new()
+        } else {
+            let refund_nfts: MultiValueEncoded<EsdtTokenPayment> = self
+                .get_nft_safe_proxy_instance()
+                .get_refund_nfts(address.clone())
+                .execute_on_dest_context();
+            refund_nfts.to_vec()
+        };
+
+        UserBridgeActivity {
+            pending_transactions,
+            refunds,
+            nft_refunds,
+            amount_staked: self.amount_staked(&address).get(),
+        }
+    }
+
     /// Actions are cleared after execution, so an empty entry means the action was executed already
     /// Returns "false" if the action ID is invalid
     #[view(wasActionExecuted)]
@@ -87,6 +284,70 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         ))
     }
 
+    /// Runs the same checks as `proposeEsdtSafeSetCurrentTransactionBatchStatus`, without proposing anything:
+    /// the batch is the current one, the number of statuses matches its transactions,
+    /// and the same statuses were not already proposed.
+    /// Returns the error the proposal would fail with, or nothing if it would be accepted.
+    #[view(validateBatchStatuses)]
+    fn validate_batch_statuses(
+        &self,
+        esdt_safe_batch_id: u64,
+        tx_batch_status: MultiValueEncoded<TransactionStatus>,
+    ) -> OptionalValue<ManagedBuffer> {
+        self.validate_chain_batch_statuses(ETHEREUM_CHAIN_ID, esdt_safe_batch_id, tx_batch_status)
+    }
+
+    /// Same as `validateBatchStatuses`, but for the current batch of the given destination chain.
+    #[view(validateChainBatchStatuses)]
+    fn validate_chain_batch_statuses(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+        tx_batch_status: MultiValueEncoded<TransactionStatus>,
+    ) -> OptionalValue<ManagedBuffer> {
+        let statuses_vec = tx_batch_status.to_vec();
+        let packed_statuses = pack_statuses(&statuses_vec);
+        match self.get_batch_statuses_error(
+            chain_id,
+            esdt_safe_batch_id,
+            &statuses_vec,
+            &packed_statuses,
+        ) {
+            Some(message) => OptionalValue::Some(ManagedBuffer::from(message)),
+            None => OptionalValue::None,
+        }
+    }
+
+    /// Lists all the pending set-status actions for an EsdtSafe batch, with their statuses
+    /// and their number of valid signatures. More than one entry means relayers
+    /// disagree on the statuses, and should converge on one of the proposals.
+    #[view(getBatchStatusProposals)]
+    fn get_batch_status_proposals(
+        &self,
+        esdt_safe_batch_id: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) -> MultiValueEncoded<StatusProposal<Self::Api>> {
+        let chain_id = opt_chain_id.into_option().unwrap_or(ETHEREUM_CHAIN_ID);
+
+        let mut result = MultiValueEncoded::new();
+        for (packed_statuses, action_id) in self
+            .set_status_action_ids_mapper(chain_id, esdt_safe_batch_id)
+            .iter()
+        {
+            if self.action_mapper().item_is_empty(action_id) {
+                continue;
+            }
+
+            result.push(StatusProposal {
+                action_id,
+                valid_signer_count: self.get_action_valid_signer_count(action_id),
+                statuses: unpack_statuses(&packed_statuses),
+            });
+        }
+
+        result
+    }
+
     /// If `wasSetCurrentTransactionBatchStatusActionProposed` return true,
     /// this can be used to get the action ID.
     /// Will return 0 if the set status action was not proposed
@@ -97,7 +358,7 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         expected_tx_batch_status: MultiValueEncoded<TransactionStatus>,
     ) -> usize {
         self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id)
-            .get(&expected_tx_batch_status.to_vec())
+            .get(&pack_statuses(&expected_tx_batch_status.to_vec()))
             .unwrap_or(0)
     }
 
@@ -116,6 +377,8 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
     /// Indicates user rights.
     /// `0` = no rights,
     /// `1` = can propose. Can also sign if they have enough stake.
+    /// `2` = can propose and perform, but not sign.
+    /// `3` = can only perform.
     #[view(userRole)]
     fn user_role(&self, user: &ManagedAddress) -> UserRole {
         self.get_user_role(user)
@@ -127,6 +390,18 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         self.get_all_users_with_role(UserRole::BoardMember)
     }
 
+    /// Lists all proposers. Board members can propose as well, but are not included.
+    #[view(getAllProposers)]
+    fn get_all_proposers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.get_all_users_with_role(UserRole::Proposer)
+    }
+
+    /// Lists all executors, i.e. addresses that can only perform actions.
+    #[view(getAllExecutors)]
+    fn get_all_executors(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.get_all_users_with_role(UserRole::Executor)
+    }
+
     /// Lists all board members that staked the correct amount.
     /// A board member with not enough stake can propose, but cannot sign.
     #[view(getAllStakedRelayers)]
@@ -143,6 +418,54 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         staked_relayers.into()
     }
 
+    /// Returns `true` (`1`) if the address is a board member that staked the required amount
+    /// and completed the onboarding steps required by `setBoardOnboardingRequirements`,
+    /// i.e. one that can sign and counts towards the quorum.
+    #[view(isBoardMemberActive)]
+    fn is_board_member_active(&self, address: ManagedAddress) -> bool {
+        self.is_active_board_member(&address)
+    }
+
+    /// Board members whose stake is below the required amount, e.g. because they did not top up
+    /// before the end of the grace period after an increase. They cannot sign until they stake more.
+    #[view(getSuspendedBoardMembers)]
+    fn get_suspended_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut suspended_board_members = MultiValueEncoded::new();
+        for board_member in self.get_all_board_members() {
+            if !self.has_enough_stake(&board_member) {
+                suspended_board_members.push(board_member);
+            }
+        }
+
+        suspended_board_members
+    }
+
+    /// Addresses of the users that signed the action with the given ID.
+    /// Compared with `getAllBoardMembers`, it shows which board members did not sign yet.
+    #[view(getActionSigners)]
+    fn get_action_signers(&self, action_id: usize) -> MultiValueEncoded<ManagedAddress> {
+        self.get_action_signer_addresses(action_id).into()
+    }
+
+    /// Board members that vetoed the action with the given ID, with the reason they gave.
+    /// Still available once the action is blocked, see `isActionVetoed`.
+    #[view(getActionVetoes)]
+    fn get_action_vetoes(
+        &self,
+        action_id: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        for (vetoer_id, reason) in self.action_vetoes(action_id).iter() {
+            let vetoer_address = self
+                .user_mapper()
+                .get_user_address(vetoer_id)
+                .unwrap_or_default();
+            result.push((vetoer_address, reason).into());
+        }
+
+        result
+    }
+
     /// Gets the number of signatures for the action with the given ID
     #[view(getActionSignerCount)]
     fn get_action_signer_count(&self, action_id: usize) -> usize {
@@ -156,18 +479,7 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
     /// It also makes it easy to check before performing an action.
     #[view(getActionValidSignerCount)]
     fn get_action_valid_signer_count(&self, action_id: usize) -> usize {
-        self.action_signer_ids(action_id)
-            .iter()
-            .filter(|signer_id| {
-                let signer_role = self.user_id_to_role(*signer_id).get();
-                let signer_address = self
-                    .user_mapper()
-                    .get_user_address(*signer_id)
-                    .unwrap_or_default();
-
-                signer_role.is_board_member() && self.has_enough_stake(&signer_address)
-            })
-            .count()
+        self.count_valid_signers(action_id)
     }
 
     /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`.
@@ -185,9 +497,49 @@ pub trait QueriesModule: crate::storage::StorageModule + crate::util::UtilModule
         self.action_mapper().len()
     }
 
+    /// All the pending actions, with their signers, e.g. for relayers to resync after a restart.
+    /// Signers that lost their role are listed as well, see `getActionValidSignerCount`.
+    #[view(getPendingActionFullInfo)]
+    fn get_pending_action_full_info(&self) -> MultiValueEncoded<ActionFullInfo<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let action_last_index = self.get_action_last_index();
+        let action_mapper = self.action_mapper();
+        for action_id in 1..=action_last_index {
+            let action_data = action_mapper.get(action_id);
+            if !action_data.is_pending() {
+                continue;
+            }
+
+            result.push(ActionFullInfo {
+                action_id,
+                action_data,
+                signers: self.get_action_signer_addresses(action_id),
+            });
+        }
+
+        result
+    }
+
+    fn get_action_signer_addresses(&self, action_id: usize) -> ManagedVec<ManagedAddress> {
+        let mut signers = ManagedVec::new();
+        for signer_id in self.action_signer_ids(action_id).iter() {
+            signers.push(self.user_mapper().get_user_address_unchecked(signer_id));
+        }
+
+        signers
+    }
+
     /// Serialized action data of an action with index.
     #[view(getActionData)]
     fn get_action_data(&self, action_id: usize) -> Action<Self::Api> {
         self.action_mapper().get(action_id)
     }
+
+    /// Encodes the given action the same way the contract stores and hashes proposed actions,
+    /// without proposing it. Its keccak256 is the `action_hash` of the archived action
+    /// (see `getActionArchive`), and its attestation digest is what `getActionAttestationHash` returns.
+    #[view(encodeAction)]
+    fn encode_action(&self, action: Action<Self::Api>) -> ManagedBuffer {
+        self.serialize_action(&action)
+    }
 }
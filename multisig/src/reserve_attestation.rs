@@ -0,0 +1,133 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use bridge_errors::RESERVES_BLOCK_NOT_NEWER_ERR_MSG;
+
+/// Amount of a token locked in the bridge contract on the remote chain.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
+pub struct RemoteReserve<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub locked_amount: BigUint<M>,
+}
+
+/// An entry of a token's remote reserves history.
+/// `eth_block_number` is the remote block the balance was read at,
+/// `timestamp` is when the attestation was recorded on this side.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct ReserveAttestation<M: ManagedTypeApi> {
+    pub eth_block_number: u64,
+    pub locked_amount: BigUint<M>,
+    pub action_id: usize,
+    pub timestamp: u64,
+}
+
+/// Keeps the trail of the remote chain reserves claimed by the board,
+/// which back the tokens minted on this side.
+/// Reserves are attested through `AttestRemoteReserves` actions (see `proposeAttestRemoteReserves`),
+/// for increasing remote block numbers.
+#[multiversx_sc::module]
+pub trait ReserveAttestationModule {
+    fn record_remote_reserves(
+        &self,
+        eth_block_number: u64,
+        reserves: &ManagedVec<RemoteReserve<Self::Api>>,
+        action_id: usize,
+    ) {
+        self.require_newer_reserves_block(eth_block_number);
+        self.last_reserves_eth_block_number().set(eth_block_number);
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        for reserve in reserves.iter() {
+            let _ = self
+                .reserve_attested_tokens()
+                .insert(reserve.token_id.clone());
+            self.remote_reserves_history(&reserve.token_id)
+                .push(&ReserveAttestation {
+                    eth_block_number,
+                    locked_amount: reserve.locked_amount.clone(),
+                    action_id,
+                    timestamp,
+                });
+
+            self.remote_reserves_attested_event(
+                &reserve.token_id,
+                eth_block_number,
+                &reserve.locked_amount,
+                action_id,
+            );
+        }
+    }
+
+    fn require_newer_reserves_block(&self, eth_block_number: u64) {
+        require!(
+            eth_block_number > self.last_reserves_eth_block_number().get(),
+            RESERVES_BLOCK_NOT_NEWER_ERR_MSG
+        );
+    }
+
+    /// The most recent attested reserves of the token, if any.
+    #[view(getLatestRemoteReserves)]
+    fn get_latest_remote_reserves(
+        &self,
+        token_id: TokenIdentifier,
+    ) -> OptionalValue<ReserveAttestation<Self::Api>> {
+        let history_mapper = self.remote_reserves_history(&token_id);
+        if history_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(history_mapper.get(history_mapper.len()))
+    }
+
+    #[view(getRemoteReservesHistoryLength)]
+    fn get_remote_reserves_history_length(&self, token_id: TokenIdentifier) -> usize {
+        self.remote_reserves_history(&token_id).len()
+    }
+
+    /// Attested reserves of the token, oldest first, for at most `count` entries
+    /// starting with `from_index`. Entries are numbered from 1.
+    #[view(getRemoteReservesHistory)]
+    fn get_remote_reserves_history(
+        &self,
+        token_id: TokenIdentifier,
+        from_index: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ReserveAttestation<Self::Api>> {
+        let history_mapper = self.remote_reserves_history(&token_id);
+        let first_index = core::cmp::max(from_index, 1);
+        let last_index =
+            core::cmp::min(first_index.saturating_add(count), history_mapper.len() + 1);
+
+        let mut result = MultiValueEncoded::new();
+        for index in first_index..last_index {
+            result.push(history_mapper.get(index));
+        }
+
+        result
+    }
+
+    #[event("remoteReservesAttested")]
+    fn remote_reserves_attested_event(
+        &self,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] eth_block_number: u64,
+        #[indexed] locked_amount: &BigUint,
+        #[indexed] action_id: usize,
+    );
+
+    /// Remote block number of the last attested reserves.
+    #[view(getLastReservesEthBlockNumber)]
+    #[storage_mapper("lastReservesEthBlockNumber")]
+    fn last_reserves_eth_block_number(&self) -> SingleValueMapper<u64>;
+
+    /// Tokens with at least one attested reserves entry.
+    #[view(getReserveAttestedTokens)]
+    #[storage_mapper("reserveAttestedTokens")]
+    fn reserve_attested_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[storage_mapper("remoteReservesHistory")]
+    fn remote_reserves_history(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> VecMapper<ReserveAttestation<Self::Api>>;
+}
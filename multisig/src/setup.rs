@@ -1,21 +1,55 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use bridge_errors::{
+    APPEAL_WINDOW_NOT_ENDED_ERR_MSG, ERC20_MAPPING_DOES_NOT_EXIST_ERR_MSG,
+    ERC20_MAPPING_EXISTS_ERR_MSG, INVALID_MAPPING_ERR_MSG, INVALID_QUORUM_PERCENTAGE_ERR_MSG,
+    NFT_SAFE_NOT_SC_ERR_MSG, NFT_TRANSFER_NOT_SC_ERR_MSG, NO_SLASH_IN_ESCROW_ERR_MSG,
+    NO_SLASH_UNDER_APPEAL_ERR_MSG, OWNERSHIP_NOT_ACCEPTED_ERR_MSG,
+    QUORUM_SET_AS_PERCENTAGE_ERR_MSG, SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG,
+    TOKEN_ID_MAPPING_DOES_NOT_EXIST_ERR_MSG, TOKEN_ID_MAPPING_EXISTS_ERR_MSG,
+};
+use config_events_module::NO_ACTION_ID;
 use eth_address::EthAddress;
+use token_module::PERCENTAGE_TOTAL;
+use transaction::ChainId;
 
+use crate::config_validation::ABSOLUTE_MIN_QUORUM;
+use crate::slash_offense::SlashOffense;
+
+use bridge_domain_module::ProxyTrait as _;
+use esdt_safe::daily_limit::ProxyTrait as _;
+use esdt_safe::destination_limit::ProxyTrait as _;
+use esdt_safe::ProxyTrait as _;
 use fee_estimator_module::ProxyTrait as _;
 use max_bridged_amount_module::ProxyTrait as _;
+use multi_transfer_esdt::quarantine::ProxyTrait as _;
+use multi_transfer_esdt::unlock_mode::ProxyTrait as _;
 use multi_transfer_esdt::ProxyTrait as _;
 use multiversx_sc_modules::pause::ProxyTrait as _;
+use nft_safe::ProxyTrait as _;
+use nft_transfer::ProxyTrait as _;
+use screening_module::ProxyTrait as _;
 use token_module::ProxyTrait as _;
 use tx_batch_module::ProxyTrait as _;
 
+const PROPOSE_OWNERSHIP_ENDPOINT: &[u8] = b"proposeOwnership";
+const GET_ACCEPTED_OWNER_ENDPOINT: &[u8] = b"getAcceptedOwner";
+
 #[multiversx_sc::module]
 pub trait SetupModule:
     crate::multisig_general::MultisigGeneralModule
+    + crate::accounting_snapshot::AccountingSnapshotModule
+    + crate::delegation::DelegationModule
+    + crate::action_statistics::ActionStatisticsModule
+    + crate::config_validation::ConfigValidationModule
     + crate::storage::StorageModule
     + crate::util::UtilModule
-    + multiversx_sc_modules::pause::PauseModule
+    + bridge_domain_module::BridgeDomainModule
+    + config_events_module::ConfigEventsModule
+    + crate::pause_status::PauseStatusModule
+    + crate::action_archive::ActionArchiveModule
+    + execution_guard_module::ExecutionGuardModule
 {
     #[only_owner]
     #[endpoint(upgradeChildContractFromSource)]
@@ -43,54 +77,249 @@ pub trait SetupModule:
         );
     }
 
+    /// Starts a two-step ownership transfer of one of the contracts owned by this Multisig SC,
+    /// see their `proposeOwnership`.
+    #[only_owner]
+    #[endpoint(proposeChildContractOwner)]
+    fn propose_child_contract_owner(
+        &self,
+        child_sc_address: ManagedAddress,
+        new_owner: ManagedAddress,
+    ) {
+        let _: IgnoreValue = self
+            .send()
+            .contract_call::<()>(child_sc_address, PROPOSE_OWNERSHIP_ENDPOINT)
+            .argument(&new_owner)
+            .execute_on_dest_context();
+    }
+
+    /// Completes the ownership transfer, once the proposed owner accepted it.
+    /// The contract cannot change its own owner, so this Multisig SC, as the current owner, does it.
+    #[only_owner]
+    #[endpoint(changeChildContractOwner)]
+    fn change_child_contract_owner(&self, child_sc_address: ManagedAddress) {
+        let accepted_owner: OptionalValue<ManagedAddress> = self
+            .send()
+            .contract_call::<OptionalValue<ManagedAddress>>(
+                child_sc_address.clone(),
+                GET_ACCEPTED_OWNER_ENDPOINT,
+            )
+            .execute_on_dest_context();
+        let new_owner = match accepted_owner {
+            OptionalValue::Some(new_owner) => new_owner,
+            OptionalValue::None => sc_panic!(OWNERSHIP_NOT_ACCEPTED_ERR_MSG),
+        };
+
+        let _: IgnoreValue = self
+            .send()
+            .change_owner_address(child_sc_address, &new_owner)
+            .execute_on_dest_context();
+    }
+
     #[only_owner]
     #[endpoint(addBoardMember)]
     fn add_board_member_endpoint(&self, board_member: ManagedAddress) {
         self.add_board_member(&board_member);
+        self.update_quorum_from_percentage();
+        self.require_valid_board_config();
     }
 
     #[only_owner]
     #[endpoint(removeUser)]
     fn remove_user(&self, board_member: ManagedAddress) {
-        self.remove_board_member(&board_member);
-        let num_board_members = self.num_board_members().get();
-        require!(num_board_members > 0, "cannot remove all board members");
-        require!(
-            self.quorum().get() <= num_board_members,
-            "quorum cannot exceed board size"
-        );
+        self.remove_user_and_check_quorum(&board_member);
     }
 
-    /// Cuts a fixed amount from a board member's stake.
+    /// Cuts the amount set for the given offense from a board member's stake.
     /// This should be used only in cases where the board member
-    /// is being actively malicious.
+    /// is being actively malicious, or has been inactive for too long.
     ///
     /// After stake is cut, the board member would have to stake again
     /// to be able to sign actions.
     #[only_owner]
     #[endpoint(slashBoardMember)]
-    fn slash_board_member(&self, board_member: ManagedAddress) {
-        self.remove_user(board_member.clone());
+    fn slash_board_member(&self, board_member: ManagedAddress, offense: SlashOffense) {
+        self.slash(&board_member, offense);
+    }
 
-        let slash_amount = self.slash_amount().get();
+    /// Sets the period during which slashed stake is held in escrow.
+    /// Within it, a `ReverseSlash` action (see `proposeReverseSlash`) can restore the stake.
+    #[only_owner]
+    #[endpoint(setSlashAppealWindow)]
+    fn set_slash_appeal_window(&self, appeal_window: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"slashAppealWindow");
+        let old_value = self.config_value(&parameter_key);
+        self.slash_appeal_window().set(appeal_window);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
 
-        // remove slashed amount from user stake amountself
-        self.amount_staked(&board_member)
-            .update(|stake| *stake -= &slash_amount);
+    /// Moves a slash whose appeal window has ended out of escrow,
+    /// into the total slashed amount. Anyone can call this.
+    #[endpoint(finalizeSlash)]
+    fn finalize_slash(&self, board_member: ManagedAddress) {
+        let escrowed_amount = self.escrowed_slash_amount(&board_member).get();
+        require!(escrowed_amount > 0, NO_SLASH_IN_ESCROW_ERR_MSG);
+        require!(
+            !self.is_slash_under_appeal(&board_member),
+            APPEAL_WINDOW_NOT_ENDED_ERR_MSG
+        );
 
-        // add it to total slashed amount pool
+        self.escrowed_slash_amount(&board_member).clear();
+        self.escrowed_delegated_slash_amount(&board_member).clear();
+        self.slash_appeal_deadline(&board_member).clear();
         self.slashed_tokens_amount()
-            .update(|slashed_amt| *slashed_amt += slash_amount);
+            .update(|slashed_amt| *slashed_amt += &escrowed_amount);
+
+        self.slash_finalized_event(&board_member, &escrowed_amount);
+    }
+
+    /// Raising the required stake only applies to signing once the grace period
+    /// (see `setStakeTopUpGracePeriod`) ended. Board members that did not top up by then
+    /// are suspended from signing until they do, see `getSuspendedBoardMembers`.
+    /// Lowering it applies right away.
+    #[only_owner]
+    #[endpoint(changeRequiredStakeAmount)]
+    fn change_required_stake_amount(&self, new_required_stake: BigUint) {
+        for offense in SlashOffense::ALL {
+            require!(
+                self.slash_tier(offense).get() <= new_required_stake,
+                SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG
+            );
+        }
+
+        let parameter_key = ManagedBuffer::new_from_bytes(b"requiredStakeAmount");
+        let old_value = self.config_value(&parameter_key);
+
+        if new_required_stake > self.required_stake_amount().get() {
+            let grace_period = self.stake_top_up_grace_period().get();
+            let deadline = self.blockchain().get_block_timestamp() + grace_period;
+            self.previous_required_stake_amount()
+                .set(self.get_effective_required_stake());
+            self.stake_top_up_deadline().set(deadline);
+        } else {
+            self.previous_required_stake_amount().clear();
+            self.stake_top_up_deadline().clear();
+        }
+
+        self.required_stake_amount().set(&new_required_stake);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[only_owner]
+    #[endpoint(setStakeTopUpGracePeriod)]
+    fn set_stake_top_up_grace_period(&self, grace_period: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"stakeTopUpGracePeriod");
+        let old_value = self.config_value(&parameter_key);
+        self.stake_top_up_grace_period().set(grace_period);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Onboarding steps board members must complete, besides staking, before they can sign.
+    /// Applies to current board members as well, see `isBoardMemberActive`.
+    #[only_owner]
+    #[endpoint(setBoardOnboardingRequirements)]
+    fn set_board_onboarding_requirements(&self, require_signing_key: bool, require_metadata: bool) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"signingKeyRequired");
+        let old_value = self.config_value(&parameter_key);
+        self.signing_key_required().set(require_signing_key);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+        let parameter_key = ManagedBuffer::new_from_bytes(b"boardMemberMetadataRequired");
+        let old_value = self.config_value(&parameter_key);
+        self.board_member_metadata_required().set(require_metadata);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     #[only_owner]
     #[endpoint(changeQuorum)]
     fn change_quorum(&self, new_quorum: usize) {
         require!(
-            new_quorum <= self.num_board_members().get(),
-            "quorum cannot exceed board size"
+            self.quorum_percentage().get() == 0,
+            QUORUM_SET_AS_PERCENTAGE_ERR_MSG
         );
-        self.quorum().set(new_quorum);
+
+        self.set_quorum(new_quorum, NO_ACTION_ID);
+    }
+
+    /// Makes the quorum follow the board size: it is set to the given percentage of the board,
+    /// rounded up, right away and whenever board members are added, removed or slashed.
+    /// `PERCENTAGE_TOTAL` (10_000) is 100%. The minimum quorum still applies.
+    ///
+    /// While set, the quorum cannot be changed through `changeQuorum` or a `ChangeQuorum` action.
+    /// 0 goes back to a fixed quorum, which keeps its current value.
+    #[only_owner]
+    #[endpoint(setQuorumPercentage)]
+    fn set_quorum_percentage(&self, quorum_percentage: u32) {
+        require!(
+            quorum_percentage <= PERCENTAGE_TOTAL,
+            INVALID_QUORUM_PERCENTAGE_ERR_MSG
+        );
+
+        let parameter_key = ManagedBuffer::new_from_bytes(b"quorumPercentage");
+        let old_value = self.config_value(&parameter_key);
+        self.quorum_percentage().set(quorum_percentage);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+        self.update_quorum_from_percentage();
+        self.require_valid_board_config();
+    }
+
+    /// Sets the number of signatures needed for `ForceReexecuteBatch`, `ReverseSlash`,
+    /// `SetSlashTier` and `MigrateTokenMode` actions.
+    /// Must be between the quorum and the board size, or 0 to disable these actions.
+    #[only_owner]
+    #[endpoint(changeSuperQuorum)]
+    fn change_super_quorum(&self, new_super_quorum: usize) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"superQuorum");
+        let old_value = self.config_value(&parameter_key);
+        self.super_quorum().set(new_super_quorum);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+        self.require_valid_board_config();
+    }
+
+    /// Sets the number of blocks actions can be signed and performed for, after being proposed.
+    /// Only applies to actions proposed afterwards. 0 means actions never expire.
+    /// Expired actions can be removed through `purgeStaleActions`.
+    #[only_owner]
+    #[endpoint(setActionTtl)]
+    fn set_action_ttl(&self, action_ttl: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"actionTtl");
+        let old_value = self.config_value(&parameter_key);
+        self.action_ttl().set(action_ttl);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the number of blocks to wait, once an action reached the quorum, before it can be performed.
+    /// Gives time to notice a wrong action, e.g. a rogue batch, and discard it (see `discardAction`).
+    /// 0 means actions can be performed as soon as they reach the quorum.
+    ///
+    /// The timelock of actions that already reached the quorum starts when a board member signs them again.
+    #[only_owner]
+    #[endpoint(setExecutionTimelock)]
+    fn set_execution_timelock(&self, execution_timelock: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"executionTimelock");
+        let old_value = self.config_value(&parameter_key);
+        self.execution_timelock().set(execution_timelock);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the number of board member vetoes needed to permanently block a pending action (see `veto`).
+    /// 0 disables vetoes.
+    #[only_owner]
+    #[endpoint(setVetoThreshold)]
+    fn set_veto_threshold(&self, veto_threshold: usize) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"vetoThreshold");
+        let old_value = self.config_value(&parameter_key);
+        self.veto_threshold().set(veto_threshold);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the gas budget for executing Ethereum -> Elrond batches. 0 means no limit.
+    #[only_owner]
+    #[endpoint(setMaxTxBatchGas)]
+    fn set_max_tx_batch_gas(&self, max_tx_batch_gas: u64) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"maxTxBatchGas");
+        let old_value = self.config_value(&parameter_key);
+        self.max_tx_batch_gas().set(max_tx_batch_gas);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
     }
 
     /// Maps an ESDT token to an ERC20 address. Used by relayers.
@@ -99,11 +328,11 @@ pub trait SetupModule:
     fn add_mapping(&self, erc20_address: EthAddress<Self::Api>, token_id: TokenIdentifier) {
         require!(
             self.erc20_address_for_token_id(&token_id).is_empty(),
-            "Mapping already exists for token ID"
+            TOKEN_ID_MAPPING_EXISTS_ERR_MSG
         );
         require!(
             self.token_id_for_erc20_address(&erc20_address).is_empty(),
-            "Mapping already exists for ERC20 token"
+            ERC20_MAPPING_EXISTS_ERR_MSG
         );
 
         self.erc20_address_for_token_id(&token_id)
@@ -117,11 +346,11 @@ pub trait SetupModule:
     fn clear_mapping(&self, erc20_address: EthAddress<Self::Api>, token_id: TokenIdentifier) {
         require!(
             !self.erc20_address_for_token_id(&token_id).is_empty(),
-            "Mapping does not exist for ERC20 token"
+            ERC20_MAPPING_DOES_NOT_EXIST_ERR_MSG
         );
         require!(
             !self.token_id_for_erc20_address(&erc20_address).is_empty(),
-            "Mapping does not exist for token id"
+            TOKEN_ID_MAPPING_DOES_NOT_EXIST_ERR_MSG
         );
 
         let mapped_erc_20 = self.erc20_address_for_token_id(&token_id).get();
@@ -129,13 +358,53 @@ pub trait SetupModule:
 
         require!(
             erc20_address.raw_addr == mapped_erc_20.raw_addr && token_id == mapped_token_id,
-            "Invalid mapping"
+            INVALID_MAPPING_ERR_MSG
         );
 
         self.erc20_address_for_token_id(&token_id).clear();
         self.token_id_for_erc20_address(&erc20_address).clear();
     }
 
+    /// Pauses the whole bridge: no actions can be proposed or performed on the multisig,
+    /// and EsdtSafe, MultiTransferEsdt and NftSafe (if set up) are paused as well.
+    /// The EsdtSafe accounting is snapshotted, same as with `pauseEsdtSafe`.
+    ///
+    /// The wrapping and EGLD swap contracts are not owned by the multisig, so they are left as they are.
+    /// A paused MultiTransferEsdt does not call them anymore.
+    #[only_owner]
+    #[endpoint(pause)]
+    fn pause_endpoint(&self) {
+        self.set_paused(true);
+        self.pause_esdt_safe();
+
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .pause_endpoint()
+            .execute_on_dest_context();
+
+        if !self.nft_safe_address().is_empty() {
+            self.pause_nft_safe();
+        }
+    }
+
+    /// Unpauses the multisig and all the contracts paused by `pause`,
+    /// including the ones that were paused on their own before, e.g. through `pauseEsdtSafe`.
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause_endpoint(&self) {
+        self.set_paused(false);
+        self.unpause_esdt_safe();
+
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .unpause_endpoint()
+            .execute_on_dest_context();
+
+        if !self.nft_safe_address().is_empty() {
+            self.unpause_nft_safe();
+        }
+    }
+
     #[only_owner]
     #[endpoint(pauseEsdtSafe)]
     fn pause_esdt_safe(&self) {
@@ -143,6 +412,8 @@ pub trait SetupModule:
             .get_esdt_safe_proxy_instance()
             .pause_endpoint()
             .execute_on_dest_context();
+
+        self.snapshot_bridge_accounting();
     }
 
     #[only_owner]
@@ -222,32 +493,85 @@ pub trait SetupModule:
             .execute_on_dest_context();
     }
 
+    #[only_owner]
+    #[endpoint(esdtSafeSetTokenMetadata)]
+    fn esdt_safe_set_token_metadata(
+        &self,
+        token_id: TokenIdentifier,
+        remote_ticker: ManagedBuffer,
+        remote_decimals: u8,
+        logo_uri_hash: ManagedByteArray<32>,
+    ) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_token_metadata(token_id, remote_ticker, remote_decimals, logo_uri_hash)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the accepted destination address length for the given chain in the EsdtSafe SC.
+    #[only_owner]
+    #[endpoint(esdtSafeSetAddressRule)]
+    fn esdt_safe_set_address_rule(&self, chain_id: ChainId, min_len: u32, max_len: u32) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_address_rule(chain_id, min_len, max_len)
+            .execute_on_dest_context();
+    }
+
     /// Sets maximum batch size for the EsdtSafe SC.
     /// If a batch reaches this amount of transactions, it is considered full,
     /// and a new incoming transaction will be put into a new batch.
+    ///
+    /// If a chain ID is provided, the setting only applies to that chain's batches.
     #[only_owner]
     #[endpoint(esdtSafeSetMaxTxBatchSize)]
-    fn esdt_safe_set_max_tx_batch_size(&self, new_max_tx_batch_size: usize) {
+    fn esdt_safe_set_max_tx_batch_size(
+        &self,
+        new_max_tx_batch_size: usize,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
         let _: IgnoreValue = self
             .get_esdt_safe_proxy_instance()
-            .set_max_tx_batch_size(new_max_tx_batch_size)
+            .set_max_tx_batch_size(new_max_tx_batch_size, opt_chain_id)
             .execute_on_dest_context();
     }
 
     /// Sets the maximum block duration in which an EsdtSafe batch accepts transactions
     /// For a batch to be considered "full", it has to either reach `maxTxBatchSize` transactions,
     /// or have txBatchBlockDuration blocks pass since the first tx was added in the batch
+    ///
+    /// If a chain ID is provided, the setting only applies to that chain's batches.
     #[only_owner]
     #[endpoint(esdtSafeSetMaxTxBatchBlockDuration)]
-    fn esdt_safe_set_max_tx_batch_block_duration(&self, new_max_tx_batch_block_duration: u64) {
+    fn esdt_safe_set_max_tx_batch_block_duration(
+        &self,
+        new_max_tx_batch_block_duration: u64,
+        opt_chain_id: OptionalValue<ChainId>,
+    ) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_max_tx_batch_block_duration(new_max_tx_batch_block_duration, opt_chain_id)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the number of blocks a deposit of the token is held for in the EsdtSafe,
+    /// before the batch containing it can be relayed. A hold period of 0 removes it.
+    #[only_owner]
+    #[endpoint(esdtSafeSetDepositHoldPeriod)]
+    fn esdt_safe_set_deposit_hold_period(
+        &self,
+        token_id: TokenIdentifier,
+        hold_period_blocks: u64,
+    ) {
         let _: IgnoreValue = self
             .get_esdt_safe_proxy_instance()
-            .set_max_tx_batch_block_duration(new_max_tx_batch_block_duration)
+            .set_deposit_hold_period(token_id, hold_period_blocks)
             .execute_on_dest_context();
     }
 
     /// Sets the maximum bridged amount for the token for the Elrond -> Ethereum direction.
-    /// Any attempt to transfer over this amount will be rejected.
+    /// Any attempt to transfer over this amount will be rejected,
+    /// unless deposit splitting is enabled for the token (see `esdtSafeSetDepositSplitting`).
     #[only_owner]
     #[endpoint(esdtSafeSetMaxBridgedAmountForToken)]
     fn esdt_safe_set_max_bridged_amount_for_token(
@@ -261,6 +585,125 @@ pub trait SetupModule:
             .execute_on_dest_context();
     }
 
+    /// Lets the EsdtSafe split deposits of the token above its max bridged amount
+    /// into several transactions, instead of rejecting them.
+    #[only_owner]
+    #[endpoint(esdtSafeSetDepositSplitting)]
+    fn esdt_safe_set_deposit_splitting(&self, token_id: TokenIdentifier, enabled: bool) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_deposit_splitting(token_id, enabled)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the EsdtSafe's bridge domain to this SC's one (see `setBridgeDomain`),
+    /// so both compute the same batch hashes.
+    #[only_owner]
+    #[endpoint(esdtSafeSetBridgeDomain)]
+    fn esdt_safe_set_bridge_domain(&self) {
+        let bridge_domain = self.get_configured_bridge_domain();
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_bridge_domain(bridge_domain.chain_id, bridge_domain.bridge_instance_id)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the EsdtSafe's EVM domain to this SC's one (see `setEvmDomain`),
+    /// so both compute the same batch attestations.
+    #[only_owner]
+    #[endpoint(esdtSafeSetEvmDomain)]
+    fn esdt_safe_set_evm_domain(&self) {
+        let evm_domain = self.get_configured_evm_domain();
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_evm_domain(
+                evm_domain.name,
+                evm_domain.version,
+                evm_domain.chain_id,
+                evm_domain.verifying_contract,
+            )
+            .execute_on_dest_context();
+    }
+
+    /// Sets the contract the EsdtSafe SC consults before accepting a deposit,
+    /// or removes it if no address is given. See `screening_module::screening_hook::ScreeningHook`.
+    #[only_owner]
+    #[endpoint(esdtSafeSetScreeningContract)]
+    fn esdt_safe_set_screening_contract(
+        &self,
+        opt_screening_contract: OptionalValue<ManagedAddress>,
+    ) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_screening_contract(opt_screening_contract)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the maximum USD value of all the Elrond -> Ethereum transfers in a day, across all tokens.
+    /// 0 disables the limit. See `proposeEsdtSafeResetDailyUsdVolume` to override it for the current day.
+    #[only_owner]
+    #[endpoint(esdtSafeSetDailyUsdLimit)]
+    fn esdt_safe_set_daily_usd_limit(&self, daily_usd_limit: BigUint) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_daily_usd_limit(daily_usd_limit)
+            .execute_on_dest_context();
+    }
+
+    /// Refunds left unclaimed in the EsdtSafe for longer than this period (in seconds) expire.
+    /// 0 means refunds never expire. See `proposeEsdtSafeSweepExpiredRefunds`.
+    #[only_owner]
+    #[endpoint(esdtSafeSetRefundExpiryPeriod)]
+    fn esdt_safe_set_refund_expiry_period(&self, period: u64) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_refund_expiry_period(period)
+            .execute_on_dest_context();
+    }
+
+    /// Bounty the EsdtSafe pays from the token's accumulated fees to keepers claiming refunds
+    /// on behalf of users. 0 disables relayed claims for the token.
+    #[only_owner]
+    #[endpoint(esdtSafeSetRefundClaimBounty)]
+    fn esdt_safe_set_refund_claim_bounty(&self, token_id: TokenIdentifier, bounty: BigUint) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_refund_claim_bounty(token_id, bounty)
+            .execute_on_dest_context();
+    }
+
+    /// If set, the EsdtSafe returns the fee of rejected transactions to the users, along with the refund.
+    #[only_owner]
+    #[endpoint(esdtSafeSetRefundFeesOnRejection)]
+    fn esdt_safe_set_refund_fees_on_rejection(&self, refund_fees: bool) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_refund_fees_on_rejection(refund_fees)
+            .execute_on_dest_context();
+    }
+
+    /// Once this many EsdtSafe batches are waiting for their statuses,
+    /// new Elrond -> Ethereum transactions are rejected. 0 means no limit.
+    #[only_owner]
+    #[endpoint(esdtSafeSetMaxPendingBatches)]
+    fn esdt_safe_set_max_pending_batches(&self, max_pending_batches: u64) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_max_pending_batches(max_pending_batches)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the maximum amount of the token that can be sent to any single destination address in one epoch.
+    /// 0 disables the limit.
+    #[only_owner]
+    #[endpoint(esdtSafeSetDestinationEpochLimit)]
+    fn esdt_safe_set_destination_epoch_limit(&self, token_id: TokenIdentifier, limit: BigUint) {
+        let _: IgnoreValue = self
+            .get_esdt_safe_proxy_instance()
+            .set_destination_epoch_limit(token_id, limit)
+            .execute_on_dest_context();
+    }
+
     /// Same as the function above, but for Ethereum -> Elrond transactions.
     #[only_owner]
     #[endpoint(multiTransferEsdtSetMaxBridgedAmountForToken)]
@@ -275,6 +718,76 @@ pub trait SetupModule:
             .execute_on_dest_context();
     }
 
+    /// Sets the contract the MultiTransferEsdt SC consults before delivering a transfer,
+    /// or removes it if no address is given. Rejected transfers are refunded.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetScreeningContract)]
+    fn multi_transfer_esdt_set_screening_contract(
+        &self,
+        opt_screening_contract: OptionalValue<ManagedAddress>,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .set_screening_contract(opt_screening_contract)
+            .execute_on_dest_context();
+    }
+
+    /// Incoming transfers of more than `threshold` of the token are quarantined by MultiTransferEsdt,
+    /// until a `ReleaseQuarantinedTransfers` or `RefundQuarantinedTransfers` action is performed.
+    /// 0 disables the rule.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetQuarantineThreshold)]
+    fn multi_transfer_esdt_set_quarantine_threshold(
+        &self,
+        token_id: TokenIdentifier,
+        threshold: BigUint,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .set_quarantine_threshold(token_id, threshold)
+            .execute_on_dest_context();
+    }
+
+    /// Incoming transfers from these Ethereum addresses are always quarantined.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtAddQuarantinedOrigins)]
+    fn multi_transfer_esdt_add_quarantined_origins(
+        &self,
+        origins: MultiValueEncoded<EthAddress<Self::Api>>,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .add_quarantined_origins(origins)
+            .execute_on_dest_context();
+    }
+
+    #[only_owner]
+    #[endpoint(multiTransferEsdtRemoveQuarantinedOrigins)]
+    fn multi_transfer_esdt_remove_quarantined_origins(
+        &self,
+        origins: MultiValueEncoded<EthAddress<Self::Api>>,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .remove_quarantined_origins(origins)
+            .execute_on_dest_context();
+    }
+
+    /// Liquidity of the token kept by MultiTransferEsdt in unlock mode (see `proposeMigrateTokenMode`).
+    /// Incoming transfers that would breach it are deferred until claimed.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetReserveBuffer)]
+    fn multi_transfer_esdt_set_reserve_buffer(
+        &self,
+        token_id: TokenIdentifier,
+        reserve_buffer: BigUint,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .set_reserve_buffer(token_id, reserve_buffer)
+            .execute_on_dest_context();
+    }
+
     /// Any failed Ethereum -> Elrond transactions are added into so-called "refund batches"
     /// This configures the size of a batch.
     #[only_owner]
@@ -282,7 +795,7 @@ pub trait SetupModule:
     fn multi_transfer_esdt_set_max_refund_tx_batch_size(&self, new_max_tx_batch_size: usize) {
         let _: IgnoreValue = self
             .get_multi_transfer_esdt_proxy_instance()
-            .set_max_tx_batch_size(new_max_tx_batch_size)
+            .set_max_tx_batch_size(new_max_tx_batch_size, OptionalValue::<ChainId>::None)
             .execute_on_dest_context();
     }
 
@@ -296,7 +809,10 @@ pub trait SetupModule:
     ) {
         let _: IgnoreValue = self
             .get_multi_transfer_esdt_proxy_instance()
-            .set_max_tx_batch_block_duration(new_max_tx_batch_block_duration)
+            .set_max_tx_batch_block_duration(
+                new_max_tx_batch_block_duration,
+                OptionalValue::<ChainId>::None,
+            )
             .execute_on_dest_context();
     }
 
@@ -318,4 +834,238 @@ pub trait SetupModule:
             .set_wrapping_contract_address(opt_wrapping_contract_address)
             .execute_on_dest_context();
     }
+
+    /// Sets the EGLD swap contract, used to unwrap the incoming WEGLD transfers
+    /// proposed through `proposeMultiTransferEsdtBatchWithEgldUnwrap`. Without arguments, the unwrapping is disabled.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetEgldSwapContract)]
+    fn multi_transfer_esdt_set_egld_swap_contract(
+        &self,
+        opt_swap_contract: OptionalValue<MultiValue2<ManagedAddress, TokenIdentifier>>,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .set_egld_swap_contract(opt_swap_contract)
+            .execute_on_dest_context();
+    }
+
+    /// Sets the contract recording the Ethereum transfers executed by MultiTransferEsdt,
+    /// which outlives redeployments of this SC and of MultiTransferEsdt,
+    /// so a batch can never be executed twice. Without arguments, the recording is disabled.
+    #[only_owner]
+    #[endpoint(multiTransferEsdtSetReplayProtectionContract)]
+    fn multi_transfer_esdt_set_replay_protection_contract(
+        &self,
+        opt_replay_protection_contract: OptionalValue<ManagedAddress>,
+    ) {
+        let _: IgnoreValue = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .set_replay_protection_contract_address(opt_replay_protection_contract)
+            .execute_on_dest_context();
+    }
+
+    /// NftSafe and NftTransfer are deployed and configured separately,
+    /// and then have their ownership changed to this Multisig SC, same as the ESDT contracts.
+    ///
+    /// Each of them is then given the other's address, so the NftTransfer can release the NFTs locked in the NftSafe.
+    #[only_owner]
+    #[endpoint(setNftBridgeAddresses)]
+    fn set_nft_bridge_addresses(
+        &self,
+        nft_safe_sc_address: ManagedAddress,
+        nft_transfer_sc_address: ManagedAddress,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&nft_safe_sc_address),
+            NFT_SAFE_NOT_SC_ERR_MSG
+        );
+        require!(
+            self.blockchain()
+                .is_smart_contract(&nft_transfer_sc_address),
+            NFT_TRANSFER_NOT_SC_ERR_MSG
+        );
+
+        self.nft_safe_address().set(&nft_safe_sc_address);
+        self.nft_transfer_address().set(&nft_transfer_sc_address);
+
+        let _: IgnoreValue = self
+            .get_nft_safe_proxy_instance()
+            .set_nft_transfer_contract_address(nft_transfer_sc_address)
+            .execute_on_dest_context();
+        let _: IgnoreValue = self
+            .get_nft_transfer_proxy_instance()
+            .set_nft_safe_contract_address(nft_safe_sc_address)
+            .execute_on_dest_context();
+    }
+
+    #[only_owner]
+    #[endpoint(pauseNftSafe)]
+    fn pause_nft_safe(&self) {
+        let _: IgnoreValue = self
+            .get_nft_safe_proxy_instance()
+            .pause_endpoint()
+            .execute_on_dest_context();
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseNftSafe)]
+    fn unpause_nft_safe(&self) {
+        let _: IgnoreValue = self
+            .get_nft_safe_proxy_instance()
+            .unpause_endpoint()
+            .execute_on_dest_context();
+    }
+
+    /// See the NftSafe's `addTokenToWhitelist`.
+    #[only_owner]
+    #[endpoint(nftSafeAddTokenToWhitelist)]
+    fn nft_safe_add_token_to_whitelist(&self, token_id: TokenIdentifier, is_mint_burn: bool) {
+        let _: IgnoreValue = self
+            .get_nft_safe_proxy_instance()
+            .add_token_to_whitelist(token_id, is_mint_burn)
+            .execute_on_dest_context();
+    }
+
+    #[only_owner]
+    #[endpoint(nftSafeRemoveTokenFromWhitelist)]
+    fn nft_safe_remove_token_from_whitelist(&self, token_id: TokenIdentifier) {
+        let _: IgnoreValue = self
+            .get_nft_safe_proxy_instance()
+            .remove_token_from_whitelist(token_id)
+            .execute_on_dest_context();
+    }
+
+    // private
+
+    /// `action_id` is the ID of the `ChangeQuorum` action, or 0 when changed by the owner.
+    fn set_quorum(&self, new_quorum: usize, action_id: usize) {
+        self.save_quorum(new_quorum, action_id);
+        self.require_valid_board_config();
+    }
+
+    fn save_quorum(&self, new_quorum: usize, action_id: usize) {
+        let parameter_key = ManagedBuffer::new_from_bytes(b"quorum");
+        let old_value = self.config_value(&parameter_key);
+        self.quorum().set(new_quorum);
+        self.emit_config_changed(parameter_key, old_value, action_id);
+    }
+
+    fn remove_user_and_check_quorum(&self, board_member: &ManagedAddress) {
+        self.remove_board_member(board_member);
+        self.update_quorum_from_percentage();
+        self.require_valid_board_config();
+    }
+
+    /// Must be called after every change of the board. Does nothing for a fixed quorum.
+    /// The board config is not checked, callers do it once the change is complete.
+    fn update_quorum_from_percentage(&self) {
+        let quorum_percentage = self.quorum_percentage().get() as u64;
+        if quorum_percentage == 0 {
+            return;
+        }
+
+        let num_board_members = self.num_board_members().get() as u64;
+        let percentage_total = PERCENTAGE_TOTAL as u64;
+        let quorum = (num_board_members * quorum_percentage).div_ceil(percentage_total);
+        let min_quorum = core::cmp::max(self.min_quorum().get(), ABSOLUTE_MIN_QUORUM);
+        let new_quorum = core::cmp::max(quorum as usize, min_quorum);
+        if new_quorum != self.quorum().get() {
+            self.save_quorum(new_quorum, NO_ACTION_ID);
+        }
+    }
+
+    /// Unlike `removeUser`, slashing does not check the board config, so a board member
+    /// cannot avoid it just because the board is at its minimum size.
+    /// The board might then be smaller than the quorum (see `validateConfig`),
+    /// until the owner adds another board member.
+    fn slash(&self, board_member: &ManagedAddress, offense: SlashOffense) {
+        self.remove_board_member(board_member);
+        self.update_quorum_from_percentage();
+
+        let slash_amount = self.slash_tier(offense).get();
+        let delegated_slash_amount = self.slash_delegated_stake(board_member, &slash_amount);
+
+        // remove the rest of the slashed amount from user stake amount
+        self.amount_staked(board_member)
+            .update(|stake| *stake -= &(&slash_amount - &delegated_slash_amount));
+
+        let appeal_window = self.slash_appeal_window().get();
+        if appeal_window == 0 {
+            // add it to total slashed amount pool
+            self.slashed_tokens_amount()
+                .update(|slashed_amt| *slashed_amt += slash_amount);
+            return;
+        }
+
+        // a new slash during the appeal window extends the window for the whole escrowed amount
+        let appeal_deadline = self.blockchain().get_block_timestamp() + appeal_window;
+        self.escrowed_slash_amount(board_member)
+            .update(|escrowed| *escrowed += &slash_amount);
+        self.escrowed_delegated_slash_amount(board_member)
+            .update(|escrowed| *escrowed += &delegated_slash_amount);
+        self.slash_appeal_deadline(board_member)
+            .set(appeal_deadline);
+
+        self.slash_escrowed_event(board_member, &slash_amount, appeal_deadline);
+    }
+
+    /// The required stake may have been lowered since the action was proposed.
+    fn set_slash_tier(&self, offense: SlashOffense, amount: &BigUint, action_id: usize) {
+        require!(
+            amount <= &self.required_stake_amount().get(),
+            SLASH_AMOUNT_ABOVE_REQUIRED_STAKE_ERR_MSG
+        );
+
+        let parameter_key = self.config_key(b"slashTier", &offense);
+        let old_value = self.config_value(&parameter_key);
+        self.slash_tier(offense).set(amount);
+        self.emit_config_changed(parameter_key, old_value, action_id);
+    }
+
+    /// Gives the escrowed stake back to the board member.
+    /// Board membership is not restored, the owner has to add the member again.
+    fn reverse_slash(&self, board_member: &ManagedAddress) {
+        require!(
+            self.is_slash_under_appeal(board_member),
+            NO_SLASH_UNDER_APPEAL_ERR_MSG
+        );
+
+        let escrowed_amount = self.escrowed_slash_amount(board_member).take();
+        let escrowed_delegated_amount = self.escrowed_delegated_slash_amount(board_member).take();
+        self.slash_appeal_deadline(board_member).clear();
+        self.amount_staked(board_member)
+            .update(|stake| *stake += &(&escrowed_amount - &escrowed_delegated_amount));
+        self.total_delegated_stake(board_member)
+            .update(|delegated_stake| *delegated_stake += &escrowed_delegated_amount);
+
+        self.slash_reversed_event(board_member, &escrowed_amount);
+    }
+
+    fn is_slash_under_appeal(&self, board_member: &ManagedAddress) -> bool {
+        !self.escrowed_slash_amount(board_member).is_empty()
+            && self.blockchain().get_block_timestamp()
+                < self.slash_appeal_deadline(board_member).get()
+    }
+
+    #[event("slashEscrowed")]
+    fn slash_escrowed_event(
+        &self,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        #[indexed] appeal_deadline: u64,
+    );
+
+    #[event("slashReversed")]
+    fn slash_reversed_event(
+        &self,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("slashFinalized")]
+    fn slash_finalized_event(
+        &self,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
 }
@@ -0,0 +1,70 @@
+multiversx_sc::imports!();
+
+use bridge_errors::{
+    ONLY_BOARD_MEMBERS_CAN_SET_SIGNING_KEY_ERR_MSG, SIGNING_KEY_ALREADY_IN_USE_ERR_MSG,
+};
+
+use crate::user_role::UserRole;
+
+/// Lets relayer operators keep their staked funds on a cold key.
+///
+/// The signing key can sign, propose and perform actions for its board member,
+/// while staking, slashing and everything else stays with the board member's own address.
+#[multiversx_sc::module]
+pub trait SigningKeyModule: crate::storage::StorageModule + crate::util::UtilModule {
+    /// Sets the address that signs on behalf of the caller, replacing the previous one.
+    /// The signing key must not have a role of its own, nor be used by another board member.
+    #[endpoint(setSigningKey)]
+    fn set_signing_key(&self, signing_key: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.get_user_role(&caller).is_board_member(),
+            ONLY_BOARD_MEMBERS_CAN_SET_SIGNING_KEY_ERR_MSG
+        );
+        require!(
+            self.get_user_role(&signing_key) == UserRole::None
+                && self.signing_key_owner(&signing_key).is_empty(),
+            SIGNING_KEY_ALREADY_IN_USE_ERR_MSG
+        );
+
+        self.clear_signing_key(&caller);
+        self.signing_key(&caller).set(&signing_key);
+        self.signing_key_owner(&signing_key).set(&caller);
+    }
+
+    #[endpoint(removeSigningKey)]
+    fn remove_signing_key(&self) {
+        let caller = self.blockchain().get_caller();
+        self.clear_signing_key(&caller);
+    }
+
+    #[view(getSigningKey)]
+    fn get_signing_key(&self, board_member: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let signing_key_mapper = self.signing_key(&board_member);
+        if signing_key_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(signing_key_mapper.get())
+    }
+
+    #[view(getSigningKeyOwner)]
+    fn get_signing_key_owner(&self, signing_key: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let owner_mapper = self.signing_key_owner(&signing_key);
+        if owner_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(owner_mapper.get())
+    }
+
+    fn clear_signing_key(&self, board_member: &ManagedAddress) {
+        let signing_key_mapper = self.signing_key(board_member);
+        if signing_key_mapper.is_empty() {
+            return;
+        }
+
+        self.signing_key_owner(&signing_key_mapper.get()).clear();
+        signing_key_mapper.clear();
+    }
+}
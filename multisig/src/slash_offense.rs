@@ -0,0 +1,17 @@
+multiversx_sc::derive_imports!();
+
+/// Kinds of board member misbehavior, each with its own slash amount (see `getSlashTiers`).
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum SlashOffense {
+    Inactivity,
+    WrongAttestation,
+    Equivocation,
+}
+
+impl SlashOffense {
+    pub const ALL: [SlashOffense; 3] = [
+        SlashOffense::Inactivity,
+        SlashOffense::WrongAttestation,
+        SlashOffense::Equivocation,
+    ];
+}
@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use transaction::transaction_status::TransactionStatus;
+
+/// A pending set-status action for an EsdtSafe batch.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct StatusProposal<M: ManagedTypeApi> {
+    pub action_id: usize,
+    pub valid_signer_count: usize,
+    pub statuses: ManagedVec<M, TransactionStatus>,
+}
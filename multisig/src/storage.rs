@@ -2,9 +2,11 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use eth_address::EthAddress;
-use transaction::transaction_status::TransactionStatus;
+use transaction::{transaction_status::PackedTransactionStatuses, ChainId};
 
-use crate::action::Action;
+use crate::action::{Action, ActionExecutionStatus};
+use crate::board_member_metadata::BoardMemberMetadata;
+use crate::slash_offense::SlashOffense;
 use crate::user_role::UserRole;
 
 pub type EthBatchHash<M> = ManagedByteArray<M, 32>; // keccak256(ManagedVec<EthTransaction<Self::Api>)
@@ -16,6 +18,24 @@ pub trait StorageModule {
     #[storage_mapper("quorum")]
     fn quorum(&self) -> SingleValueMapper<usize>;
 
+    /// If set, the quorum follows the board size, see `setQuorumPercentage`.
+    #[view(getQuorumPercentage)]
+    #[storage_mapper("quorumPercentage")]
+    fn quorum_percentage(&self) -> SingleValueMapper<u32>;
+
+    /// Number of signatures needed to force the re-execution of a batch.
+    /// 0 means re-executions are disabled.
+    #[view(getSuperQuorum)]
+    #[storage_mapper("superQuorum")]
+    fn super_quorum(&self) -> SingleValueMapper<usize>;
+
+    /// Gas budget for executing Ethereum -> Elrond batches, passed to MultiTransferEsdt.
+    /// Transfers that don't fit in it are executed later, through `executeRemainingTransfers`.
+    /// 0 means no limit.
+    #[view(getMaxTxBatchGas)]
+    #[storage_mapper("maxTxBatchGas")]
+    fn max_tx_batch_gas(&self) -> SingleValueMapper<u64>;
+
     #[storage_mapper("user")]
     fn user_mapper(&self) -> UserMapper;
 
@@ -34,26 +54,140 @@ pub trait StorageModule {
     #[storage_mapper("action_signer_ids")]
     fn action_signer_ids(&self, action_id: usize) -> UnorderedSetMapper<usize>;
 
+    /// Number of blocks to wait after an action reached the quorum, before it can be performed.
+    /// 0 means no wait.
+    #[view(getExecutionTimelock)]
+    #[storage_mapper("executionTimelock")]
+    fn execution_timelock(&self) -> SingleValueMapper<u64>;
+
+    /// Block nonce from which the action can be performed, set once it reached the quorum.
+    #[view(getActionUnlockBlock)]
+    #[storage_mapper("actionUnlockBlock")]
+    fn action_unlock_block(&self, action_id: usize) -> SingleValueMapper<u64>;
+
+    /// User ID of the board member or proposer that proposed the action.
+    #[storage_mapper("actionProposer")]
+    fn action_proposer(&self, action_id: usize) -> SingleValueMapper<usize>;
+
+    /// Board members that voted to discard the action, see `discardAction`.
+    #[storage_mapper("actionDiscardVotes")]
+    fn action_discard_votes(&self, action_id: usize) -> UnorderedSetMapper<usize>;
+
+    /// Number of board member vetoes that permanently block a pending action, see `veto`.
+    /// 0 means vetoes are disabled.
+    #[view(getVetoThreshold)]
+    #[storage_mapper("vetoThreshold")]
+    fn veto_threshold(&self) -> SingleValueMapper<usize>;
+
+    /// Board members that vetoed the action, with the reason they gave. Kept for audit once the action is blocked.
+    #[storage_mapper("actionVetoes")]
+    fn action_vetoes(&self, action_id: usize) -> MapMapper<usize, ManagedBuffer>;
+
+    #[view(isActionVetoed)]
+    #[storage_mapper("isActionVetoed")]
+    fn is_action_vetoed(&self, action_id: usize) -> SingleValueMapper<bool>;
+
+    /// keccak256 of the encoded vetoed actions, which cannot be proposed again.
+    #[storage_mapper("vetoedActionHashes")]
+    fn vetoed_action_hashes(&self) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    /// Number of blocks a proposed action can be signed and performed for. 0 means actions never expire.
+    #[view(getActionTtl)]
+    #[storage_mapper("actionTtl")]
+    fn action_ttl(&self) -> SingleValueMapper<u64>;
+
+    /// Block nonce at which the action expires, set when it is proposed.
+    /// 0 for actions proposed while the TTL was 0, which never expire.
+    #[view(getActionExpiryBlock)]
+    #[storage_mapper("actionExpiryBlock")]
+    fn action_expiry_block(&self, action_id: usize) -> SingleValueMapper<u64>;
+
+    #[view(getActionExecutionStatus)]
+    #[storage_mapper("actionExecutionStatus")]
+    fn action_execution_status(&self, action_id: usize)
+        -> SingleValueMapper<ActionExecutionStatus>;
+
     /// The required amount to stake for accepting relayer position
     #[view(getRequiredStakeAmount)]
     #[storage_mapper("requiredStakeAmount")]
     fn required_stake_amount(&self) -> SingleValueMapper<BigUint>;
 
+    /// Seconds board members have to top up their stake after `changeRequiredStakeAmount` raised it.
+    #[view(getStakeTopUpGracePeriod)]
+    #[storage_mapper("stakeTopUpGracePeriod")]
+    fn stake_top_up_grace_period(&self) -> SingleValueMapper<u64>;
+
+    /// End of the current top-up grace period, as a block timestamp.
+    #[view(getStakeTopUpDeadline)]
+    #[storage_mapper("stakeTopUpDeadline")]
+    fn stake_top_up_deadline(&self) -> SingleValueMapper<u64>;
+
+    /// Required stake before the last increase, which is still enough until `getStakeTopUpDeadline`.
+    #[storage_mapper("previousRequiredStakeAmount")]
+    fn previous_required_stake_amount(&self) -> SingleValueMapper<BigUint>;
+
+    /// Address that signs and proposes on behalf of a board member, see `setSigningKey`.
+    #[storage_mapper("signingKey")]
+    fn signing_key(&self, board_member: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("signingKeyOwner")]
+    fn signing_key_owner(&self, signing_key: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    /// See `registerBoardMemberMetadata`.
+    #[storage_mapper("boardMemberMetadata")]
+    fn board_member_metadata(
+        &self,
+        board_member: &ManagedAddress,
+    ) -> SingleValueMapper<BoardMemberMetadata<Self::Api>>;
+
+    /// If set, board members cannot sign until they set a signing key.
+    #[view(isSigningKeyRequired)]
+    #[storage_mapper("signingKeyRequired")]
+    fn signing_key_required(&self) -> SingleValueMapper<bool>;
+
+    /// If set, board members cannot sign until they register their metadata.
+    #[view(isBoardMemberMetadataRequired)]
+    #[storage_mapper("boardMemberMetadataRequired")]
+    fn board_member_metadata_required(&self) -> SingleValueMapper<bool>;
+
+    /// Contract notified once the action is executed, see `setExecutionNotifier`.
+    #[storage_mapper("actionExecutionNotifier")]
+    fn action_execution_notifier(&self, action_id: usize) -> SingleValueMapper<ManagedAddress>;
+
+    /// Token board members stake, and users delegate, set at deployment.
+    #[view(getStakingToken)]
+    #[storage_mapper("stakingToken")]
+    fn staking_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
     /// Staked amount by each board member.
     #[view(getAmountStaked)]
     #[storage_mapper("amountStaked")]
     fn amount_staked(&self, board_member_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
-    /// Amount of stake slashed if a relayer is misbehaving
-    #[view(getSlashAmount)]
-    #[storage_mapper("slashAmount")]
-    fn slash_amount(&self) -> SingleValueMapper<BigUint>;
+    /// Amount of stake slashed for each offense, see `proposeSetSlashTier`
+    #[view(getSlashTier)]
+    #[storage_mapper("slashTier")]
+    fn slash_tier(&self, offense: SlashOffense) -> SingleValueMapper<BigUint>;
 
     /// Total slashed tokens accumulated
     #[view(getSlashedTokensAmount)]
     #[storage_mapper("slashedTokensAmount")]
     fn slashed_tokens_amount(&self) -> SingleValueMapper<BigUint>;
 
+    /// Period (in seconds) during which a slash can be reversed. 0 means slashes are final immediately.
+    #[view(getSlashAppealWindow)]
+    #[storage_mapper("slashAppealWindow")]
+    fn slash_appeal_window(&self) -> SingleValueMapper<u64>;
+
+    /// Slashed stake held until the end of the appeal window, per board member
+    #[view(getEscrowedSlashAmount)]
+    #[storage_mapper("escrowedSlashAmount")]
+    fn escrowed_slash_amount(&self, board_member: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getSlashAppealDeadline)]
+    #[storage_mapper("slashAppealDeadline")]
+    fn slash_appeal_deadline(&self, board_member: &ManagedAddress) -> SingleValueMapper<u64>;
+
     #[view(getLastExecutedEthBatchId)]
     #[storage_mapper("lastExecutedEthBatchId")]
     fn last_executed_eth_batch_id(&self) -> SingleValueMapper<u64>;
@@ -72,7 +206,34 @@ pub trait StorageModule {
     fn action_id_for_set_current_transaction_batch_status(
         &self,
         esdt_safe_batch_id: u64,
-    ) -> MapMapper<ManagedVec<TransactionStatus>, usize>;
+    ) -> MapMapper<PackedTransactionStatuses<Self::Api>, usize>;
+
+    #[storage_mapper("actionIdForSetChainTransactionBatchStatus")]
+    fn action_id_for_set_chain_transaction_batch_status(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+    ) -> MapMapper<PackedTransactionStatuses<Self::Api>, usize>;
+
+    #[view(getLastExecutedEthNftBatchId)]
+    #[storage_mapper("lastExecutedEthNftBatchId")]
+    fn last_executed_eth_nft_batch_id(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastExecutedEthNftTxId)]
+    #[storage_mapper("lastExecutedEthNftTxId")]
+    fn last_executed_eth_nft_tx_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("nftBatchIdToActionIdMapping")]
+    fn nft_batch_id_to_action_id_mapping(
+        &self,
+        batch_id: u64,
+    ) -> MapMapper<EthBatchHash<Self::Api>, usize>;
+
+    #[storage_mapper("actionIdForSetCurrentNftBatchStatus")]
+    fn action_id_for_set_current_nft_batch_status(
+        &self,
+        nft_safe_batch_id: u64,
+    ) -> MapMapper<PackedTransactionStatuses<Self::Api>, usize>;
 
     /// Mapping between ERC20 Ethereum address and Elrond ESDT Token Identifiers
 
@@ -99,4 +260,12 @@ pub trait StorageModule {
     #[view(getMultiTransferEsdtAddress)]
     #[storage_mapper("multiTransferEsdtAddress")]
     fn multi_transfer_esdt_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getNftSafeAddress)]
+    #[storage_mapper("nftSafeAddress")]
+    fn nft_safe_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getNftTransferAddress)]
+    #[storage_mapper("nftTransferAddress")]
+    fn nft_transfer_address(&self) -> SingleValueMapper<ManagedAddress>;
 }
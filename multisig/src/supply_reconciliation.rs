@@ -0,0 +1,16 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use esdt_safe::reconciliation::TokenReconciliation;
+
+/// Bridge-wide supply accounting of a token.
+///
+/// `remote_outstanding` is the amount burned on MultiversX minus the amount minted back,
+/// i.e. the net supply that left for Ethereum through executed batches.
+/// It is negative for tokens that were first bridged from Ethereum.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct SupplyReconciliation<M: ManagedTypeApi> {
+    pub esdt_safe: TokenReconciliation<M>,
+    pub total_minted: BigUint<M>,
+    pub remote_outstanding: BigInt<M>,
+}
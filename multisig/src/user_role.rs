@@ -4,6 +4,8 @@ multiversx_sc::derive_imports!();
 pub enum UserRole {
     None,
     BoardMember,
+    Proposer,
+    Executor,
 }
 
 impl UserRole {
@@ -11,4 +13,20 @@ impl UserRole {
     pub fn is_board_member(&self) -> bool {
         matches!(*self, UserRole::BoardMember)
     }
+
+    /// Board members and proposers can propose and perform actions,
+    /// but only board members can sign them.
+    #[inline(always)]
+    pub fn can_propose(&self) -> bool {
+        matches!(*self, UserRole::BoardMember | UserRole::Proposer)
+    }
+
+    /// Executors can only perform actions that were already signed.
+    #[inline(always)]
+    pub fn can_perform(&self) -> bool {
+        matches!(
+            *self,
+            UserRole::BoardMember | UserRole::Proposer | UserRole::Executor
+        )
+    }
 }
@@ -1,10 +1,29 @@
 multiversx_sc::imports!();
 
-use transaction::{EthTransaction, EthTxAsMultiValue};
+use bridge_errors::{
+    ACTION_ALREADY_PROPOSED_ERR_MSG, ACTION_SERIALIZATION_FAILED_ERR_MSG,
+    BATCH_IDS_NOT_CONSECUTIVE_ERR_MSG, BATCH_NOT_FULL_ERR_MSG, BATCH_SERIALIZATION_FAILED_ERR_MSG,
+    CURRENT_BATCH_EMPTY_ERR_MSG, INVALID_STAKING_TOKEN_ERR_MSG, INVALID_TX_ID_ERR_MSG,
+    NFT_BRIDGE_NOT_SET_UP_ERR_MSG, NO_BATCH_STATUSES_PROVIDED_ERR_MSG,
+    STATUS_COUNT_MISMATCH_ERR_MSG, WRONG_ESDT_SAFE_BATCH_ID_ERR_MSG,
+};
+use transaction::nft_transaction::{EthNftTransaction, EthNftTxAsMultiValue};
+use transaction::{
+    transaction_status::{
+        pack_batch_statuses, BatchStatuses, PackedTransactionStatuses, TransactionStatus,
+    },
+    ChainId, EthTransaction, EthTxAsMultiValue, TxBatchSplitInFields, ETHEREUM_CHAIN_ID,
+    TX_MULTIRESULT_NR_FIELDS,
+};
 
+use crate::action::{Action, ActionExecutionStatus};
 use crate::storage::EthBatchHash;
+use crate::supply_reconciliation::SupplyReconciliation;
 use crate::user_role::UserRole;
 
+use tx_batch_module::BatchStatus;
+use tx_batch_module::ProxyTrait as _;
+
 #[multiversx_sc::module]
 pub trait UtilModule: crate::storage::StorageModule {
     fn get_user_role(&self, user: &ManagedAddress) -> UserRole {
@@ -16,6 +35,70 @@ pub trait UtilModule: crate::storage::StorageModule {
         }
     }
 
+    /// Amount of the staking token paid with the call, see `getStakingToken`.
+    fn get_staking_payment(&self) -> BigUint {
+        let (token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            token_id == self.staking_token().get(),
+            INVALID_STAKING_TOKEN_ERR_MSG
+        );
+
+        amount
+    }
+
+    fn send_staking_token(&self, to: &ManagedAddress, amount: &BigUint) {
+        self.send()
+            .direct(to, &self.staking_token().get(), 0, amount);
+    }
+
+    /// The user the caller acts for. Addresses without a role of their own
+    /// act for the board member that set them as signing key, if any.
+    fn get_acting_user(&self, caller: &ManagedAddress) -> ManagedAddress {
+        if self.get_user_role(caller) != UserRole::None {
+            return caller.clone();
+        }
+
+        let owner_mapper = self.signing_key_owner(caller);
+        if owner_mapper.is_empty() {
+            caller.clone()
+        } else {
+            owner_mapper.get()
+        }
+    }
+
+    fn count_valid_signers(&self, action_id: usize) -> usize {
+        self.action_signer_ids(action_id)
+            .iter()
+            .filter(|signer_id| self.is_valid_signer(*signer_id))
+            .count()
+    }
+
+    fn count_valid_discard_votes(&self, action_id: usize) -> usize {
+        self.action_discard_votes(action_id)
+            .iter()
+            .filter(|voter_id| self.is_valid_signer(*voter_id))
+            .count()
+    }
+
+    fn count_valid_vetoes(&self, action_id: usize) -> usize {
+        self.action_vetoes(action_id)
+            .keys()
+            .filter(|vetoer_id| self.is_valid_signer(*vetoer_id))
+            .count()
+    }
+
+    fn is_valid_signer(&self, user_id: usize) -> bool {
+        let user_role = self.user_id_to_role(user_id).get();
+        let user_address = self
+            .user_mapper()
+            .get_user_address(user_id)
+            .unwrap_or_default();
+
+        user_role.is_board_member()
+            && self.has_enough_stake(&user_address)
+            && self.has_completed_onboarding(&user_address)
+    }
+
     fn is_valid_action_id(&self, action_id: usize) -> bool {
         let min_id = 1;
         let max_id = self.action_mapper().len();
@@ -37,12 +120,45 @@ pub trait UtilModule: crate::storage::StorageModule {
     }
 
     fn has_enough_stake(&self, board_member_address: &ManagedAddress) -> bool {
-        let required_stake = self.required_stake_amount().get();
+        let required_stake = self.get_effective_required_stake();
         let amount_staked = self.amount_staked(board_member_address).get();
 
         amount_staked >= required_stake
     }
 
+    /// Checks the onboarding steps enabled by the owner, other than staking.
+    fn has_completed_onboarding(&self, board_member_address: &ManagedAddress) -> bool {
+        let has_signing_key = !self.signing_key_required().get()
+            || !self.signing_key(board_member_address).is_empty();
+        let has_metadata = !self.board_member_metadata_required().get()
+            || !self.board_member_metadata(board_member_address).is_empty();
+
+        has_signing_key && has_metadata
+    }
+
+    /// Only active board members can sign, and only their signatures count towards the quorum.
+    fn is_active_board_member(&self, address: &ManagedAddress) -> bool {
+        self.get_user_role(address).is_board_member()
+            && self.has_enough_stake(address)
+            && self.has_completed_onboarding(address)
+    }
+
+    /// The required stake, or the previous one during a top-up grace period, whichever is lower.
+    fn get_effective_required_stake(&self) -> BigUint {
+        let required_stake = self.required_stake_amount().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if current_timestamp >= self.stake_top_up_deadline().get() {
+            return required_stake;
+        }
+
+        let previous_required_stake = self.previous_required_stake_amount().get();
+        if previous_required_stake < required_stake {
+            previous_required_stake
+        } else {
+            required_stake
+        }
+    }
+
     fn transfers_multi_value_to_eth_tx_vec(
         &self,
         transfers: MultiValueEncoded<EthTxAsMultiValue<Self::Api>>,
@@ -68,23 +184,243 @@ pub trait UtilModule: crate::storage::StorageModule {
         let mut current_expected_tx_id = last_executed_eth_tx_id + 1;
 
         for eth_tx in eth_tx_vec {
-            require!(eth_tx.tx_nonce == current_expected_tx_id, "Invalid Tx ID");
+            require!(
+                eth_tx.tx_nonce == current_expected_tx_id,
+                INVALID_TX_ID_ERR_MSG
+            );
             current_expected_tx_id += 1;
         }
     }
 
-    fn hash_eth_tx_batch(
+    fn transfers_multi_value_to_eth_nft_tx_vec(
         &self,
-        eth_tx_batch: &ManagedVec<EthTransaction<Self::Api>>,
-    ) -> EthBatchHash<Self::Api> {
+        transfers: MultiValueEncoded<EthNftTxAsMultiValue<Self::Api>>,
+    ) -> ManagedVec<EthNftTransaction<Self::Api>> {
+        let mut transfers_as_eth_tx = ManagedVec::new();
+        for transfer in transfers {
+            let (from, to, token_id, token_nonce, attributes, tx_nonce) = transfer.into_tuple();
+
+            transfers_as_eth_tx.push(EthNftTransaction {
+                from,
+                to,
+                token_id,
+                token_nonce,
+                attributes,
+                tx_nonce,
+            });
+        }
+
+        transfers_as_eth_tx
+    }
+
+    fn require_valid_eth_nft_tx_ids(&self, eth_tx_vec: &ManagedVec<EthNftTransaction<Self::Api>>) {
+        let last_executed_eth_tx_id = self.last_executed_eth_nft_tx_id().get();
+        let mut current_expected_tx_id = last_executed_eth_tx_id + 1;
+
+        for eth_tx in eth_tx_vec {
+            require!(
+                eth_tx.tx_nonce == current_expected_tx_id,
+                INVALID_TX_ID_ERR_MSG
+            );
+            current_expected_tx_id += 1;
+        }
+    }
+
+    fn hash_eth_tx_batch<T: TopEncode>(&self, eth_tx_batch: &T) -> EthBatchHash<Self::Api> {
         let mut serialized = ManagedBuffer::new();
         if eth_tx_batch.top_encode(&mut serialized).is_err() {
-            sc_panic!("Failed to serialized batch");
+            sc_panic!(BATCH_SERIALIZATION_FAILED_ERR_MSG);
         }
 
         self.crypto().keccak256(&serialized)
     }
 
+    fn serialize_action(&self, action: &Action<Self::Api>) -> ManagedBuffer {
+        let mut serialized = ManagedBuffer::new();
+        if action.top_encode(&mut serialized).is_err() {
+            sc_panic!(ACTION_SERIALIZATION_FAILED_ERR_MSG);
+        }
+
+        serialized
+    }
+
+    fn get_action_hash(&self, action: &Action<Self::Api>) -> ManagedByteArray<Self::Api, 32> {
+        self.crypto().keccak256(self.serialize_action(action))
+    }
+
+    fn set_status_action_ids_mapper(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+    ) -> MapMapper<PackedTransactionStatuses<Self::Api>, usize> {
+        if chain_id == ETHEREUM_CHAIN_ID {
+            self.action_id_for_set_current_transaction_batch_status(esdt_safe_batch_id)
+        } else {
+            self.action_id_for_set_chain_transaction_batch_status(chain_id, esdt_safe_batch_id)
+        }
+    }
+
+    /// Checks that a set-status action can be proposed for the given batch, with the given statuses.
+    /// Returns the message the proposal would fail with, if any.
+    fn get_batch_statuses_error(
+        &self,
+        chain_id: ChainId,
+        esdt_safe_batch_id: u64,
+        statuses_vec: &ManagedVec<TransactionStatus>,
+        packed_statuses: &PackedTransactionStatuses<Self::Api>,
+    ) -> Option<&'static str> {
+        let call_result: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
+            .get_esdt_safe_proxy_instance()
+            .get_current_tx_batch(OptionalValue::Some(chain_id))
+            .execute_on_dest_context();
+        let (current_batch_id, current_batch_transactions) = match call_result {
+            OptionalValue::Some(batch) => batch.into_tuple(),
+            OptionalValue::None => return Some(CURRENT_BATCH_EMPTY_ERR_MSG),
+        };
+
+        let action_ids_mapper = self.set_status_action_ids_mapper(chain_id, esdt_safe_batch_id);
+        if action_ids_mapper.contains_key(packed_statuses) {
+            return Some(ACTION_ALREADY_PROPOSED_ERR_MSG);
+        }
+
+        let current_batch_len = current_batch_transactions.raw_len() / TX_MULTIRESULT_NR_FIELDS;
+        if current_batch_len != statuses_vec.len() {
+            return Some(STATUS_COUNT_MISMATCH_ERR_MSG);
+        }
+        if esdt_safe_batch_id != current_batch_id {
+            return Some(WRONG_ESDT_SAFE_BATCH_ID_ERR_MSG);
+        }
+
+        None
+    }
+
+    /// The first batch is checked the same as for a single batch proposal.
+    /// The following ones must be consecutive and full, with a status for each of their transactions.
+    fn get_multiple_batch_statuses_error(
+        &self,
+        chain_id: ChainId,
+        batch_statuses: &ManagedVec<BatchStatuses<Self::Api>>,
+    ) -> Option<&'static str> {
+        if batch_statuses.is_empty() {
+            return Some(NO_BATCH_STATUSES_PROVIDED_ERR_MSG);
+        }
+
+        let first_batch = batch_statuses.get(0);
+        let error = self.get_batch_statuses_error(
+            chain_id,
+            first_batch.batch_id,
+            &first_batch.tx_statuses,
+            &pack_batch_statuses(batch_statuses),
+        );
+        if error.is_some() {
+            return error;
+        }
+
+        let mut expected_batch_id = first_batch.batch_id;
+        for batch in batch_statuses.iter().skip(1) {
+            expected_batch_id += 1;
+            if batch.batch_id != expected_batch_id {
+                return Some(BATCH_IDS_NOT_CONSECUTIVE_ERR_MSG);
+            }
+
+            let batch_status: BatchStatus<Self::Api> = self
+                .get_esdt_safe_proxy_instance()
+                .get_batch_status(batch.batch_id, OptionalValue::Some(chain_id))
+                .execute_on_dest_context();
+            if !matches!(batch_status, BatchStatus::Full) {
+                return Some(BATCH_NOT_FULL_ERR_MSG);
+            }
+
+            let call_result: OptionalValue<TxBatchSplitInFields<Self::Api>> = self
+                .get_esdt_safe_proxy_instance()
+                .get_batch(batch.batch_id, OptionalValue::Some(chain_id))
+                .execute_on_dest_context();
+            let batch_len = match call_result {
+                OptionalValue::Some(tx_batch) => {
+                    tx_batch.into_tuple().1.raw_len() / TX_MULTIRESULT_NR_FIELDS
+                }
+                OptionalValue::None => return Some(BATCH_NOT_FULL_ERR_MSG),
+            };
+            if batch_len != batch.tx_statuses.len() {
+                return Some(STATUS_COUNT_MISMATCH_ERR_MSG);
+            }
+        }
+
+        None
+    }
+
+    /// An action is stale if it sets the statuses of an EsdtSafe batch that was already settled.
+    /// Stale actions can never be performed, and can be purged by anyone.
+    fn is_stale_action(&self, action_id: usize) -> bool {
+        match self.action_mapper().get(action_id) {
+            Action::SetCurrentTransactionBatchStatus {
+                esdt_safe_batch_id, ..
+            } => self.is_esdt_safe_batch_settled(ETHEREUM_CHAIN_ID, esdt_safe_batch_id),
+            Action::SetChainTransactionBatchStatus {
+                chain_id,
+                esdt_safe_batch_id,
+                ..
+            } => self.is_esdt_safe_batch_settled(chain_id, esdt_safe_batch_id),
+            Action::SetMultipleTransactionBatchStatuses {
+                chain_id,
+                batch_statuses,
+            } => self.is_esdt_safe_batch_settled(chain_id, batch_statuses.get(0).batch_id),
+            _ => false,
+        }
+    }
+
+    fn is_action_call_pending(&self, action_id: usize) -> bool {
+        self.action_execution_status(action_id).get() == ActionExecutionStatus::Pending
+    }
+
+    fn is_expired_action(&self, action_id: usize) -> bool {
+        let expiry_block = self.action_expiry_block(action_id).get();
+        expiry_block > 0 && self.blockchain().get_block_nonce() >= expiry_block
+    }
+
+    /// Always `false` for actions whose timelock did not start, see `sign`.
+    fn is_execution_timelock_over(&self, action_id: usize) -> bool {
+        if self.execution_timelock().get() == 0 {
+            return true;
+        }
+
+        let unlock_block_mapper = self.action_unlock_block(action_id);
+        !unlock_block_mapper.is_empty()
+            && self.blockchain().get_block_nonce() >= unlock_block_mapper.get()
+    }
+
+    fn is_esdt_safe_batch_settled(&self, chain_id: ChainId, esdt_safe_batch_id: u64) -> bool {
+        let watermarks: MultiValue3<u64, u64, u64> = self
+            .get_esdt_safe_proxy_instance()
+            .get_batch_watermarks(OptionalValue::Some(chain_id))
+            .execute_on_dest_context();
+        let (_, last_settled_batch_id, _) = watermarks.into_tuple();
+
+        esdt_safe_batch_id <= last_settled_batch_id
+    }
+
+    fn get_supply_reconciliation(
+        &self,
+        token_id: TokenIdentifier,
+    ) -> SupplyReconciliation<Self::Api> {
+        let esdt_safe: esdt_safe::reconciliation::TokenReconciliation<Self::Api> = self
+            .get_esdt_safe_proxy_instance()
+            .get_token_reconciliation(token_id.clone())
+            .execute_on_dest_context();
+        let total_minted: BigUint = self
+            .get_multi_transfer_esdt_proxy_instance()
+            .total_minted(&token_id)
+            .execute_on_dest_context();
+        let remote_outstanding = BigInt::from_biguint(Sign::Plus, esdt_safe.total_burned.clone())
+            - BigInt::from_biguint(Sign::Plus, total_minted.clone());
+
+        SupplyReconciliation {
+            esdt_safe,
+            total_minted,
+            remote_outstanding,
+        }
+    }
+
     // proxies
 
     #[proxy]
@@ -103,4 +439,30 @@ pub trait UtilModule: crate::storage::StorageModule {
     fn get_multi_transfer_esdt_proxy_instance(&self) -> multi_transfer_esdt::Proxy<Self::Api> {
         self.multi_transfer_esdt_proxy(self.multi_transfer_esdt_address().get())
     }
+
+    #[proxy]
+    fn nft_safe_proxy(&self, sc_address: ManagedAddress) -> nft_safe::Proxy<Self::Api>;
+
+    #[proxy]
+    fn nft_transfer_proxy(&self, sc_address: ManagedAddress) -> nft_transfer::Proxy<Self::Api>;
+
+    fn get_nft_safe_proxy_instance(&self) -> nft_safe::Proxy<Self::Api> {
+        let nft_safe_address_mapper = self.nft_safe_address();
+        require!(
+            !nft_safe_address_mapper.is_empty(),
+            NFT_BRIDGE_NOT_SET_UP_ERR_MSG
+        );
+
+        self.nft_safe_proxy(nft_safe_address_mapper.get())
+    }
+
+    fn get_nft_transfer_proxy_instance(&self) -> nft_transfer::Proxy<Self::Api> {
+        let nft_transfer_address_mapper = self.nft_transfer_address();
+        require!(
+            !nft_transfer_address_mapper.is_empty(),
+            NFT_BRIDGE_NOT_SET_UP_ERR_MSG
+        );
+
+        self.nft_transfer_proxy(nft_transfer_address_mapper.get())
+    }
 }
@@ -0,0 +1,80 @@
+use multisig::action::Action;
+use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopDecode};
+use multiversx_sc::types::{ManagedAddress, TokenIdentifier};
+use multiversx_sc_scenario::DebugApi;
+use transaction::transaction_status::TransactionStatus;
+
+/// `SetCurrentTransactionBatchStatus` for batch 3, as stored by the first multisig release.
+fn stored_set_current_batch_status() -> Vec<u8> {
+    let mut bytes = vec![1u8];
+    bytes.extend_from_slice(&3u64.to_be_bytes());
+    bytes.extend_from_slice(&2u32.to_be_bytes());
+    bytes.extend_from_slice(&[3, 4]);
+    bytes
+}
+
+/// `BatchTransferEsdtToken` for batch 5, with a single transfer,
+/// as stored by the first multisig release.
+fn stored_batch_transfer_esdt_token() -> Vec<u8> {
+    let mut bytes = vec![2u8];
+    bytes.extend_from_slice(&5u64.to_be_bytes());
+    bytes.extend_from_slice(&1u32.to_be_bytes());
+    bytes.extend_from_slice(&[0x11; 20]);
+    bytes.extend_from_slice(&[0x22; 32]);
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"BRIDGE-123456");
+    bytes.extend_from_slice(&[0, 0, 0, 2, 0x01, 0x90]);
+    bytes.extend_from_slice(&7u64.to_be_bytes());
+    bytes
+}
+
+#[test]
+fn test_decode_stored_set_current_batch_status() {
+    let _ = DebugApi::dummy();
+    let stored = stored_set_current_batch_status();
+
+    let action = Action::<DebugApi>::top_decode(stored.as_slice()).unwrap();
+    assert_eq!(action.action_type(), 1);
+    match &action {
+        Action::SetCurrentTransactionBatchStatus {
+            esdt_safe_batch_id,
+            tx_batch_status,
+        } => {
+            assert_eq!(*esdt_safe_batch_id, 3);
+            assert_eq!(tx_batch_status.len(), 2);
+            assert_eq!(tx_batch_status.get(0), TransactionStatus::Executed);
+            assert_eq!(tx_batch_status.get(1), TransactionStatus::Rejected);
+        }
+        _ => panic!("stored action decoded as another variant"),
+    }
+
+    assert_eq!(top_encode_to_vec_u8_or_panic(&action), stored);
+}
+
+#[test]
+fn test_decode_stored_batch_transfer_esdt_token() {
+    let _ = DebugApi::dummy();
+    let stored = stored_batch_transfer_esdt_token();
+
+    let action = Action::<DebugApi>::top_decode(stored.as_slice()).unwrap();
+    assert_eq!(action.action_type(), 2);
+    match &action {
+        Action::BatchTransferEsdtToken {
+            eth_batch_id,
+            transfers,
+        } => {
+            assert_eq!(*eth_batch_id, 5);
+            assert_eq!(transfers.len(), 1);
+
+            let transfer = transfers.get(0);
+            assert_eq!(transfer.from.raw_addr.to_byte_array(), [0x11; 20]);
+            assert!(transfer.to == ManagedAddress::from([0x22u8; 32]));
+            assert!(transfer.token_id == TokenIdentifier::from("BRIDGE-123456"));
+            assert!(transfer.amount == 400u32);
+            assert_eq!(transfer.tx_nonce, 7);
+        }
+        _ => panic!("stored action decoded as another variant"),
+    }
+
+    assert_eq!(top_encode_to_vec_u8_or_panic(&action), stored);
+}
@@ -1,8 +1,123 @@
+#[test]
+fn action_archive_go() {
+    multiversx_sc_scenario::run_go("mandos/action_archive.scen.json");
+}
+
+#[test]
+fn action_bundle_go() {
+    multiversx_sc_scenario::run_go("mandos/action_bundle.scen.json");
+}
+
+#[test]
+fn action_bundle_checks_go() {
+    multiversx_sc_scenario::run_go("mandos/action_bundle_checks.scen.json");
+}
+
+#[test]
+fn action_encoding_go() {
+    multiversx_sc_scenario::run_go("mandos/action_encoding.scen.json");
+}
+
+#[test]
+fn action_expiry_go() {
+    multiversx_sc_scenario::run_go("mandos/action_expiry.scen.json");
+}
+
+#[test]
+fn action_lifecycle_events_go() {
+    multiversx_sc_scenario::run_go("mandos/action_lifecycle_events.scen.json");
+}
+
+#[test]
+fn action_signers_go() {
+    multiversx_sc_scenario::run_go("mandos/action_signers.scen.json");
+}
+
+#[test]
+fn action_statistics_go() {
+    multiversx_sc_scenario::run_go("mandos/action_statistics.scen.json");
+}
+
+#[test]
+fn action_veto_go() {
+    multiversx_sc_scenario::run_go("mandos/action_veto.scen.json");
+}
+
+#[test]
+fn add_board_member_action_go() {
+    multiversx_sc_scenario::run_go("mandos/add_board_member_action.scen.json");
+}
+
+#[test]
+fn auto_propose_next_round_go() {
+    multiversx_sc_scenario::run_go("mandos/auto_propose_next_round.scen.json");
+}
+
+#[test]
+fn board_member_metadata_go() {
+    multiversx_sc_scenario::run_go("mandos/board_member_metadata.scen.json");
+}
+
+#[test]
+fn board_onboarding_go() {
+    multiversx_sc_scenario::run_go("mandos/board_onboarding.scen.json");
+}
+
+#[test]
+fn bridge_domain_go() {
+    multiversx_sc_scenario::run_go("mandos/bridge_domain.scen.json");
+}
+
+#[test]
+fn change_quorum_action_go() {
+    multiversx_sc_scenario::run_go("mandos/change_quorum_action.scen.json");
+}
+
+#[test]
+fn child_contract_ownership_go() {
+    multiversx_sc_scenario::run_go("mandos/child_contract_ownership.scen.json");
+}
+
+#[test]
+fn config_validation_go() {
+    multiversx_sc_scenario::run_go("mandos/config_validation.scen.json");
+}
+
 #[test]
 fn create_elrond_to_ethereum_tx_batch_go() {
     multiversx_sc_scenario::run_go("mandos/create_elrond_to_ethereum_tx_batch.scen.json");
 }
 
+#[test]
+fn discard_action_go() {
+    multiversx_sc_scenario::run_go("mandos/discard_action.scen.json");
+}
+
+#[test]
+fn egld_unwrap_batch_go() {
+    multiversx_sc_scenario::run_go("mandos/egld_unwrap_batch.scen.json");
+}
+
+#[test]
+fn equivocation_evidence_not_conflicting_go() {
+    multiversx_sc_scenario::run_go("mandos/equivocation_evidence_not_conflicting.scen.json");
+}
+
+#[test]
+fn equivocation_evidence_slash_go() {
+    multiversx_sc_scenario::run_go("mandos/equivocation_evidence_slash.scen.json");
+}
+
+#[test]
+fn esdt_staking_go() {
+    multiversx_sc_scenario::run_go("mandos/esdt_staking.scen.json");
+}
+
+#[test]
+fn eth_signatures_go() {
+    multiversx_sc_scenario::run_go("mandos/eth_signatures.scen.json");
+}
+
 #[test]
 fn ethereum_to_elrond_tx_batch_ok_go() {
     multiversx_sc_scenario::run_go("mandos/ethereum_to_elrond_tx_batch_ok.scen.json");
@@ -18,21 +133,151 @@ fn execute_elrond_to_ethereum_tx_batch_go() {
     multiversx_sc_scenario::run_go("mandos/execute_elrond_to_ethereum_tx_batch.scen.json");
 }
 
+#[test]
+fn execution_notifier_go() {
+    multiversx_sc_scenario::run_go("mandos/execution_notifier.scen.json");
+}
+
+#[test]
+fn execution_timelock_go() {
+    multiversx_sc_scenario::run_go("mandos/execution_timelock.scen.json");
+}
+
+#[test]
+fn executor_lifecycle_go() {
+    multiversx_sc_scenario::run_go("mandos/executor_lifecycle.scen.json");
+}
+
+#[test]
+fn force_reexecute_batch_go() {
+    multiversx_sc_scenario::run_go("mandos/force_reexecute_batch.scen.json");
+}
+
 #[test]
 fn get_empty_batch_go() {
     multiversx_sc_scenario::run_go("mandos/get_empty_batch.scen.json");
 }
 
+#[test]
+fn get_pending_action_full_info_go() {
+    multiversx_sc_scenario::run_go("mandos/get_pending_action_full_info.scen.json");
+}
+
+#[test]
+fn max_board_size_go() {
+    multiversx_sc_scenario::run_go("mandos/max_board_size.scen.json");
+}
+
+#[test]
+fn migrate_token_mode_go() {
+    multiversx_sc_scenario::run_go("mandos/migrate_token_mode.scen.json");
+}
+
+#[test]
+fn multicall_go() {
+    multiversx_sc_scenario::run_go("mandos/multicall.scen.json");
+}
+
+#[test]
+fn multiple_batch_statuses_go() {
+    multiversx_sc_scenario::run_go("mandos/multiple_batch_statuses.scen.json");
+}
+
+#[test]
+fn pending_action_call_go() {
+    multiversx_sc_scenario::run_go("mandos/pending_action_call.scen.json");
+}
+
+#[test]
+fn proposer_lifecycle_go() {
+    multiversx_sc_scenario::run_go("mandos/proposer_lifecycle.scen.json");
+}
+
+#[test]
+fn purge_stale_actions_go() {
+    multiversx_sc_scenario::run_go("mandos/purge_stale_actions.scen.json");
+}
+
+#[test]
+fn quarantine_go() {
+    multiversx_sc_scenario::run_go("mandos/quarantine.scen.json");
+}
+
+#[test]
+fn quorum_percentage_go() {
+    multiversx_sc_scenario::run_go("mandos/quorum_percentage.scen.json");
+}
+
 #[test]
 fn reject_elrond_to_ethereum_tx_batch_go() {
     multiversx_sc_scenario::run_go("mandos/reject_elrond_to_ethereum_tx_batch.scen.json");
 }
 
+#[test]
+fn relayer_heartbeat_go() {
+    multiversx_sc_scenario::run_go("mandos/relayer_heartbeat.scen.json");
+}
+
+#[test]
+fn remote_reserves_attestation_go() {
+    multiversx_sc_scenario::run_go("mandos/remote_reserves_attestation.scen.json");
+}
+
+#[test]
+fn remove_user_action_go() {
+    multiversx_sc_scenario::run_go("mandos/remove_user_action.scen.json");
+}
+
+#[test]
+fn screening_contract_go() {
+    multiversx_sc_scenario::run_go("mandos/screening_contract.scen.json");
+}
+
 #[test]
 fn setup_go() {
     multiversx_sc_scenario::run_go("mandos/setup.scen.json");
 }
 
+#[test]
+fn sign_batch_go() {
+    multiversx_sc_scenario::run_go("mandos/sign_batch.scen.json");
+}
+
+#[test]
+fn signing_key_go() {
+    multiversx_sc_scenario::run_go("mandos/signing_key.scen.json");
+}
+
+#[test]
+fn slash_appeal_go() {
+    multiversx_sc_scenario::run_go("mandos/slash_appeal.scen.json");
+}
+
+#[test]
+fn slash_board_member_action_go() {
+    multiversx_sc_scenario::run_go("mandos/slash_board_member_action.scen.json");
+}
+
+#[test]
+fn slash_tiers_go() {
+    multiversx_sc_scenario::run_go("mandos/slash_tiers.scen.json");
+}
+
+#[test]
+fn stake_delegation_go() {
+    multiversx_sc_scenario::run_go("mandos/stake_delegation.scen.json");
+}
+
+#[test]
+fn stake_top_up_go() {
+    multiversx_sc_scenario::run_go("mandos/stake_top_up.scen.json");
+}
+
+#[test]
+fn unsign_go() {
+    multiversx_sc_scenario::run_go("mandos/unsign.scen.json");
+}
+
 #[test]
 fn unstake_go() {
     multiversx_sc_scenario::run_go("mandos/unstake.scen.json");
@@ -44,3 +289,8 @@ fn upgrade_child_sc_go() {
     multiversx_sc_scenario::run_go("mandos/upgrade_child_sc.scen.json");
 }
 */
+
+#[test]
+fn upgrade_without_staking_token_go() {
+    multiversx_sc_scenario::run_go("mandos/upgrade_without_staking_token.scen.json");
+}
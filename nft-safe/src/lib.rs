@@ -0,0 +1,374 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use config_events_module::NO_ACTION_ID;
+use core::convert::TryFrom;
+
+use eth_address::ETH_ADDRESS_LEN;
+use migration_module::bridge_version::{
+    BridgeVersion, InterfaceVersion, TRANSFER_FIELD_NFT_ATTRIBUTES,
+};
+use migration_module::StorageVersion;
+use transaction::nft_transaction::{NftTransaction, NftTransactionData, NftTxBatchSplitInFields};
+use transaction::{transaction_status::TransactionStatus, Transaction, ETHEREUM_CHAIN_ID};
+
+const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
+const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = 100; // ~10 minutes
+const NFT_AMOUNT: u32 = 1;
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+
+/// Elrond -> Ethereum side of the NFT bridge, and the only contract holding bridged NFTs.
+///
+/// Works the same way as the EsdtSafe, with the same batches, but deposits are not charged a fee.
+/// Batches hold transactions of a single unit of the token,
+/// while the NFT nonce and attributes are stored separately, by transaction nonce.
+///
+/// Once bridged, NFTs of mint/burn tokens are burned, and minted again by the NftTransfer SC when they come back.
+/// NFTs of the other tokens are locked in this contract, and released through the NftTransfer SC instead.
+#[multiversx_sc::contract]
+pub trait NftSafe:
+    tx_batch_module::TxBatchModule
+    + migration_module::MigrationModule
+    + multiversx_sc_modules::pause::PauseModule
+    + config_events_module::ConfigEventsModule
+{
+    #[init]
+    fn init(&self) {
+        self.max_tx_batch_size()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
+        self.max_tx_batch_block_duration()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+
+        // batch ID 0 is considered invalid
+        self.first_batch_id().set_if_empty(1);
+        self.last_batch_id().set_if_empty(1);
+
+        self.set_paused(true);
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(INTERFACE_VERSION, 0, TRANSFER_FIELD_NFT_ATTRIBUTES)
+    }
+
+    // endpoints - owner-only
+
+    /// is_mint_burn - Whether bridged NFTs of the token are burned, instead of being locked.
+    /// Requires the contract to have the NFTBurn role for the token.
+    #[only_owner]
+    #[endpoint(addTokenToWhitelist)]
+    fn add_token_to_whitelist(&self, token_id: TokenIdentifier, is_mint_burn: bool) {
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+        if is_mint_burn {
+            let roles = self.blockchain().get_esdt_local_roles(&token_id);
+            require!(
+                roles.has_role(&EsdtLocalRole::NftBurn),
+                "NFTBurn role not set for mint/burn token"
+            );
+        }
+
+        let _ = self.token_whitelist().insert(token_id.clone());
+        self.mint_burn_token(&token_id).set(is_mint_burn);
+    }
+
+    #[only_owner]
+    #[endpoint(removeTokenFromWhitelist)]
+    fn remove_token_from_whitelist(&self, token_id: TokenIdentifier) {
+        let _ = self.token_whitelist().swap_remove(&token_id);
+        self.mint_burn_token(&token_id).clear();
+    }
+
+    /// The NftTransfer SC, which is the only one allowed to release locked NFTs.
+    #[only_owner]
+    #[endpoint(setNftTransferContractAddress)]
+    fn set_nft_transfer_contract_address(&self, nft_transfer_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&nft_transfer_address),
+            "Invalid NftTransfer address"
+        );
+
+        let parameter_key = ManagedBuffer::new_from_bytes(b"nftTransferContractAddress");
+        let old_value = self.config_value(&parameter_key);
+        self.nft_transfer_contract_address()
+            .set(&nft_transfer_address);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    /// Sets the statuses for the transactions, after they were executed on the Ethereum side.
+    ///
+    /// Executed NFTs are burned for mint/burn tokens, and locked otherwise.
+    /// Rejected NFTs can be claimed back by their senders.
+    #[only_owner]
+    #[endpoint(setTransactionBatchStatus)]
+    fn set_transaction_batch_status(
+        &self,
+        batch_id: u64,
+        tx_statuses: MultiValueEncoded<TransactionStatus>,
+    ) {
+        let first_batch_id = self.first_batch_id().get();
+        require!(
+            batch_id == first_batch_id,
+            "Batches must be processed in order"
+        );
+
+        let mut tx_batch = self.pending_batches(batch_id);
+        require!(
+            tx_batch.len() == tx_statuses.len(),
+            "Invalid number of statuses provided"
+        );
+
+        for (tx, tx_status) in tx_batch.iter().zip(tx_statuses) {
+            let nft_data = self.nft_transaction_data(tx.nonce).take();
+
+            // refunded NFTs are not held by this contract, so there is nothing to burn, lock or refund
+            if tx.is_refund_tx {
+                continue;
+            }
+
+            match tx_status {
+                TransactionStatus::Executed => {
+                    if self.mint_burn_token(&tx.token_identifier).get() {
+                        self.send().esdt_local_burn(
+                            &tx.token_identifier,
+                            nft_data.token_nonce,
+                            &BigUint::from(NFT_AMOUNT),
+                        );
+                    } else {
+                        let _ = self
+                            .locked_nfts(&tx.token_identifier)
+                            .insert(nft_data.token_nonce);
+                    }
+                }
+                TransactionStatus::Rejected => {
+                    let addr = ManagedAddress::try_from(tx.from).unwrap();
+                    self.refund_nfts(&addr).push(&EsdtTokenPayment::new(
+                        tx.token_identifier,
+                        nft_data.token_nonce,
+                        BigUint::from(NFT_AMOUNT),
+                    ));
+                }
+                _ => {
+                    sc_panic!("Transaction status may only be set to Executed or Rejected");
+                }
+            }
+
+            self.set_status_event(batch_id, tx.nonce, tx_status);
+        }
+
+        self.clear_first_batch(ETHEREUM_CHAIN_ID, &mut tx_batch);
+    }
+
+    /// Converts failed Ethereum -> Elrond NFT transfers, saved by the NftTransfer SC,
+    /// to Elrond -> Ethereum transactions, sending the NFTs back to their Ethereum senders.
+    #[only_owner]
+    #[endpoint(addRefundBatch)]
+    fn add_refund_batch(&self, refund_transactions: ManagedVec<NftTransaction<Self::Api>>) {
+        let block_nonce = self.blockchain().get_block_nonce();
+        let mut new_transactions = ManagedVec::new();
+        let mut original_tx_nonces = ManagedVec::<Self::Api, u64>::new();
+
+        for refund_tx in &refund_transactions {
+            let tx_nonce = self.get_and_save_next_tx_id();
+            self.nft_transaction_data(tx_nonce).set(NftTransactionData {
+                token_nonce: refund_tx.token_nonce,
+                attributes: refund_tx.attributes,
+            });
+
+            // "from" and "to" are inverted, since this was initially an Ethereum -> Elrond tx
+            new_transactions.push(Transaction {
+                block_nonce,
+                nonce: tx_nonce,
+                from: refund_tx.to,
+                to: refund_tx.from,
+                token_identifier: refund_tx.token_identifier,
+                amount: BigUint::from(NFT_AMOUNT),
+                is_refund_tx: true,
+            });
+            original_tx_nonces.push(refund_tx.nonce);
+        }
+
+        let batch_ids = self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &new_transactions);
+        for (i, tx) in new_transactions.iter().enumerate() {
+            let batch_id = batch_ids.get(i);
+            let original_tx_nonce = original_tx_nonces.get(i);
+
+            self.add_refund_transaction_event(batch_id, tx.nonce, original_tx_nonce);
+        }
+    }
+
+    /// Sends a locked NFT to the recipient of an Ethereum -> Elrond transfer.
+    /// Only the NftTransfer SC may release NFTs.
+    #[endpoint(releaseNft)]
+    fn release_nft(&self, to: ManagedAddress, token_id: TokenIdentifier, token_nonce: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.nft_transfer_contract_address().is_empty()
+                && caller == self.nft_transfer_contract_address().get(),
+            "Only the NftTransfer SC may release NFTs"
+        );
+        require!(
+            self.locked_nfts(&token_id).swap_remove(&token_nonce),
+            "NFT is not locked"
+        );
+
+        self.send()
+            .direct_esdt(&to, &token_id, token_nonce, &BigUint::from(NFT_AMOUNT));
+    }
+
+    // endpoints
+
+    /// Create an Elrond -> Ethereum NFT transfer. Only a single NFT may be sent per call.
+    /// The NFT is kept by the contract until the batch status is set.
+    #[payable("*")]
+    #[endpoint(createTransaction)]
+    fn create_transaction(&self, to: ManagedBuffer) {
+        require!(self.not_paused(), "Cannot create transaction while paused");
+        require!(to.len() == ETH_ADDRESS_LEN, "Invalid destination address");
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            self.token_whitelist().contains(&payment.token_identifier),
+            "Token not in whitelist"
+        );
+        require!(
+            payment.token_nonce > 0 && payment.amount == NFT_AMOUNT,
+            "Only NFTs may be bridged"
+        );
+
+        let own_sc_address = self.blockchain().get_sc_address();
+        let token_data = self.blockchain().get_esdt_token_data(
+            &own_sc_address,
+            &payment.token_identifier,
+            payment.token_nonce,
+        );
+
+        let caller = self.blockchain().get_caller();
+        let tx_nonce = self.get_and_save_next_tx_id();
+        self.nft_transaction_data(tx_nonce).set(NftTransactionData {
+            token_nonce: payment.token_nonce,
+            attributes: token_data.attributes,
+        });
+
+        let tx = Transaction {
+            block_nonce: self.blockchain().get_block_nonce(),
+            nonce: tx_nonce,
+            from: caller.as_managed_buffer().clone(),
+            to,
+            token_identifier: payment.token_identifier,
+            amount: payment.amount,
+            is_refund_tx: false,
+        };
+
+        let batch_id = self.add_to_batch(ETHEREUM_CHAIN_ID, tx);
+        self.create_transaction_event(batch_id, tx_nonce);
+    }
+
+    /// Claim all the NFTs of failed Elrond -> Ethereum transfers.
+    #[endpoint(claimRefund)]
+    fn claim_refund(&self) -> ManagedVec<EsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let mut refund_mapper = self.refund_nfts(&caller);
+        require!(!refund_mapper.is_empty(), "Nothing to refund");
+
+        let mut payments = ManagedVec::new();
+        for payment in refund_mapper.iter() {
+            payments.push(payment);
+        }
+        refund_mapper.clear();
+
+        self.send().direct_multi(&caller, &payments);
+
+        payments
+    }
+
+    // views
+
+    /// Same as `getCurrentTxBatch`, but with the NFT nonce and attributes of each transaction,
+    /// instead of the amount.
+    #[view(getCurrentNftTxBatch)]
+    fn get_current_nft_tx_batch(&self) -> OptionalValue<NftTxBatchSplitInFields<Self::Api>> {
+        match self.get_current_tx_batch_id(ETHEREUM_CHAIN_ID) {
+            Some(batch_id) => self.get_nft_batch(batch_id),
+            None => OptionalValue::None,
+        }
+    }
+
+    #[view(getNftBatch)]
+    fn get_nft_batch(&self, batch_id: u64) -> OptionalValue<NftTxBatchSplitInFields<Self::Api>> {
+        let tx_batch = self.pending_batches(batch_id);
+        if tx_batch.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let mut result_vec = MultiValueEncoded::new();
+        for tx in tx_batch.iter() {
+            let nft_data = self.nft_transaction_data(tx.nonce).get();
+            result_vec.push(NftTransaction::new(tx, nft_data).into_multiresult());
+        }
+
+        OptionalValue::Some((batch_id, result_vec).into())
+    }
+
+    #[view(getRefundNfts)]
+    fn get_refund_nfts(&self, address: ManagedAddress) -> MultiValueEncoded<EsdtTokenPayment> {
+        self.refund_nfts(&address).iter().collect()
+    }
+
+    #[view(isNftLocked)]
+    fn is_nft_locked(&self, token_id: TokenIdentifier, token_nonce: u64) -> bool {
+        self.locked_nfts(&token_id).contains(&token_nonce)
+    }
+
+    // events
+
+    #[event("createTransactionEvent")]
+    fn create_transaction_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("addRefundTransactionEvent")]
+    fn add_refund_transaction_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] original_tx_id: u64,
+    );
+
+    #[event("setStatusEvent")]
+    fn set_status_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] tx_id: u64,
+        #[indexed] tx_status: TransactionStatus,
+    );
+
+    // storage
+
+    #[view(getAllKnownTokens)]
+    #[storage_mapper("tokenWhitelist")]
+    fn token_whitelist(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[view(isMintBurnToken)]
+    #[storage_mapper("mintBurnToken")]
+    fn mint_burn_token(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("nftTransactionData")]
+    fn nft_transaction_data(
+        &self,
+        tx_nonce: u64,
+    ) -> SingleValueMapper<NftTransactionData<Self::Api>>;
+
+    /// Nonces of the NFTs bridged to Ethereum that are locked in this contract, by token.
+    #[storage_mapper("lockedNfts")]
+    fn locked_nfts(&self, token_id: &TokenIdentifier) -> UnorderedSetMapper<u64>;
+
+    #[view(getNftTransferContractAddress)]
+    #[storage_mapper("nftTransferContractAddress")]
+    fn nft_transfer_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("refundNfts")]
+    fn refund_nfts(&self, address: &ManagedAddress) -> VecMapper<EsdtTokenPayment>;
+}
@@ -0,0 +1,29 @@
+#[test]
+fn add_refund_batch_go() {
+    multiversx_sc_scenario::run_go("mandos/add_refund_batch.scen.json");
+}
+
+#[test]
+fn create_transaction_ok_go() {
+    multiversx_sc_scenario::run_go("mandos/create_transaction_ok.scen.json");
+}
+
+#[test]
+fn execute_batch_locked_go() {
+    multiversx_sc_scenario::run_go("mandos/execute_batch_locked.scen.json");
+}
+
+#[test]
+fn execute_batch_rejected_go() {
+    multiversx_sc_scenario::run_go("mandos/execute_batch_rejected.scen.json");
+}
+
+#[test]
+fn mint_burn_token_go() {
+    multiversx_sc_scenario::run_go("mandos/mint_burn_token.scen.json");
+}
+
+#[test]
+fn setup_accounts_go() {
+    multiversx_sc_scenario::run_go("mandos/setup_accounts.scen.json");
+}
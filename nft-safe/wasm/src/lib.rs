@@ -0,0 +1,40 @@
+// Code generated by the multiversx-sc multi-contract system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                           16
+// Async Callback (empty):               1
+// Total number of exported functions:  18
+
+#![no_std]
+#![feature(alloc_error_handler, lang_items)]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    nft_safe
+    (
+        addTokenToWhitelist
+        removeTokenFromWhitelist
+        setMaxTxBatchSize
+        setMaxTxBatchBlockDuration
+        setTransactionBatchStatus
+        createTransaction
+        claimRefund
+        getCurrentTxBatch
+        getBatch
+        getRefundNfts
+        getAllKnownTokens
+        getFirstBatchId
+        getLastBatchId
+        pause
+        unpause
+        isPaused
+    )
+}
+
+multiversx_sc_wasm_adapter::empty_callback! {}
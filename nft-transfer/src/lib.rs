@@ -0,0 +1,209 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use config_events_module::NO_ACTION_ID;
+use migration_module::bridge_version::{
+    BridgeVersion, InterfaceVersion, TRANSFER_FIELD_NFT_ATTRIBUTES,
+};
+use migration_module::StorageVersion;
+use nft_safe::ProxyTrait as _;
+use transaction::nft_transaction::{
+    EthNftTransaction, NftTransaction, NftTransactionData, NftTxBatchSplitInFields,
+};
+use transaction::{Transaction, ETHEREUM_CHAIN_ID};
+
+const DEFAULT_MAX_TX_BATCH_SIZE: usize = 10;
+const DEFAULT_MAX_TX_BATCH_BLOCK_DURATION: u64 = u64::MAX;
+const NFT_AMOUNT: u32 = 1;
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+
+/// Ethereum -> Elrond side of the NFT bridge.
+///
+/// NFTs locked in the NftSafe are released by it. Otherwise, a new NFT is created with the bridged attributes,
+/// which requires the contract to have the NFTCreate role for the token.
+///
+/// Failed transfers are saved as refund transactions, in batches, same as in the MultiTransferEsdt,
+/// and then moved to the NftSafe to be sent back to Ethereum.
+#[multiversx_sc::contract]
+pub trait NftTransfer:
+    tx_batch_module::TxBatchModule
+    + migration_module::MigrationModule
+    + config_events_module::ConfigEventsModule
+{
+    #[init]
+    fn init(&self) {
+        self.max_tx_batch_size()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_SIZE);
+        self.max_tx_batch_block_duration()
+            .set_if_empty(DEFAULT_MAX_TX_BATCH_BLOCK_DURATION);
+
+        // batch ID 0 is considered invalid
+        self.first_batch_id().set_if_empty(1);
+        self.last_batch_id().set_if_empty(1);
+
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(INTERFACE_VERSION, 0, TRANSFER_FIELD_NFT_ATTRIBUTES)
+    }
+
+    /// The NftSafe SC, which holds the locked NFTs.
+    #[only_owner]
+    #[endpoint(setNftSafeContractAddress)]
+    fn set_nft_safe_contract_address(&self, nft_safe_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&nft_safe_address),
+            "Invalid NftSafe address"
+        );
+
+        let parameter_key = ManagedBuffer::new_from_bytes(b"nftSafeContractAddress");
+        let old_value = self.config_value(&parameter_key);
+        self.nft_safe_contract_address().set(&nft_safe_address);
+        self.emit_config_changed(parameter_key, old_value, NO_ACTION_ID);
+    }
+
+    #[only_owner]
+    #[endpoint(batchTransferNft)]
+    fn batch_transfer_nft(
+        &self,
+        batch_id: u64,
+        transfers: MultiValueEncoded<EthNftTransaction<Self::Api>>,
+    ) {
+        let nft_amount = BigUint::from(NFT_AMOUNT);
+        let mut refund_tx_list = ManagedVec::new();
+
+        for eth_tx in transfers {
+            if eth_tx.to.is_zero() || self.blockchain().is_smart_contract(&eth_tx.to) {
+                self.transfer_failed_invalid_destination(batch_id, eth_tx.tx_nonce);
+                refund_tx_list.push(self.convert_to_refund_tx(eth_tx));
+                continue;
+            }
+
+            if self.is_nft_locked(&eth_tx) {
+                let _: IgnoreValue = self
+                    .nft_safe_proxy(self.nft_safe_contract_address().get())
+                    .release_nft(
+                        eth_tx.to.clone(),
+                        eth_tx.token_id.clone(),
+                        eth_tx.token_nonce,
+                    )
+                    .execute_on_dest_context();
+            } else if self.is_local_role_set(&eth_tx.token_id, &EsdtLocalRole::NftCreate) {
+                let token_nonce = self.send().esdt_nft_create(
+                    &eth_tx.token_id,
+                    &nft_amount,
+                    eth_tx.token_id.as_managed_buffer(),
+                    &BigUint::zero(),
+                    &ManagedBuffer::new(),
+                    &eth_tx.attributes,
+                    &ManagedVec::new(),
+                );
+                self.send()
+                    .direct_esdt(&eth_tx.to, &eth_tx.token_id, token_nonce, &nft_amount);
+            } else {
+                self.transfer_failed_invalid_token(batch_id, eth_tx.tx_nonce);
+                refund_tx_list.push(self.convert_to_refund_tx(eth_tx));
+                continue;
+            }
+
+            self.transfer_performed_event(batch_id, eth_tx.tx_nonce);
+        }
+
+        self.add_multiple_tx_to_batch(ETHEREUM_CHAIN_ID, &refund_tx_list);
+    }
+
+    /// Returns the first batch of refund transactions, to be moved to the NftSafe, and clears it.
+    #[only_owner]
+    #[endpoint(getAndClearFirstRefundBatch)]
+    fn get_and_clear_first_refund_batch(
+        &self,
+    ) -> OptionalValue<NftTxBatchSplitInFields<Self::Api>> {
+        let first_batch_id = self.first_batch_id().get();
+        let mut first_batch = self.pending_batches(first_batch_id);
+        if first_batch.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let mut refund_txs = MultiValueEncoded::new();
+        for tx in first_batch.iter() {
+            let nft_data = self.nft_transaction_data(tx.nonce).take();
+            refund_txs.push(NftTransaction::new(tx, nft_data).into_multiresult());
+        }
+
+        self.clear_first_batch(ETHEREUM_CHAIN_ID, &mut first_batch);
+
+        OptionalValue::Some((first_batch_id, refund_txs).into())
+    }
+
+    // private
+
+    fn is_nft_locked(&self, eth_tx: &EthNftTransaction<Self::Api>) -> bool {
+        let nft_safe_address_mapper = self.nft_safe_contract_address();
+        if eth_tx.token_nonce == 0 || nft_safe_address_mapper.is_empty() {
+            return false;
+        }
+
+        self.nft_safe_proxy(nft_safe_address_mapper.get())
+            .is_nft_locked(eth_tx.token_id.clone(), eth_tx.token_nonce)
+            .execute_on_dest_context()
+    }
+
+    /// The refund transaction's nonce is the one of the failed transfer,
+    /// and the NFT nonce and attributes are saved under it until the refund batch is moved out.
+    fn convert_to_refund_tx(&self, eth_tx: EthNftTransaction<Self::Api>) -> Transaction<Self::Api> {
+        self.nft_transaction_data(eth_tx.tx_nonce)
+            .set(NftTransactionData {
+                token_nonce: eth_tx.token_nonce,
+                attributes: eth_tx.attributes,
+            });
+
+        Transaction {
+            block_nonce: self.blockchain().get_block_nonce(),
+            nonce: eth_tx.tx_nonce,
+            from: eth_tx.from.as_managed_buffer().clone(),
+            to: eth_tx.to.as_managed_buffer().clone(),
+            token_identifier: eth_tx.token_id,
+            amount: BigUint::from(NFT_AMOUNT),
+            is_refund_tx: true,
+        }
+    }
+
+    fn is_local_role_set(&self, token_id: &TokenIdentifier, role: &EsdtLocalRole) -> bool {
+        let roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        roles.has_role(role)
+    }
+
+    // proxies
+
+    #[proxy]
+    fn nft_safe_proxy(&self, sc_address: ManagedAddress) -> nft_safe::Proxy<Self::Api>;
+
+    // events
+
+    #[event("transferPerformedEvent")]
+    fn transfer_performed_event(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("transferFailedInvalidDestination")]
+    fn transfer_failed_invalid_destination(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    #[event("transferFailedInvalidToken")]
+    fn transfer_failed_invalid_token(&self, #[indexed] batch_id: u64, #[indexed] tx_id: u64);
+
+    // storage
+
+    #[view(getNftSafeContractAddress)]
+    #[storage_mapper("nftSafeContractAddress")]
+    fn nft_safe_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("nftTransactionData")]
+    fn nft_transaction_data(
+        &self,
+        tx_nonce: u64,
+    ) -> SingleValueMapper<NftTransactionData<Self::Api>>;
+}
@@ -0,0 +1,19 @@
+#[test]
+fn release_locked_nft_go() {
+    multiversx_sc_scenario::run_go("mandos/release_locked_nft.scen.json");
+}
+
+#[test]
+fn setup_accounts_go() {
+    multiversx_sc_scenario::run_go("mandos/setup_accounts.scen.json");
+}
+
+#[test]
+fn transfer_failed_refund_go() {
+    multiversx_sc_scenario::run_go("mandos/transfer_failed_refund.scen.json");
+}
+
+#[test]
+fn transfer_ok_go() {
+    multiversx_sc_scenario::run_go("mandos/transfer_ok.scen.json");
+}
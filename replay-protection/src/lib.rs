@@ -0,0 +1,77 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+use bridge_errors::{NOT_REGISTRAR_ERR_MSG, TRANSFER_ALREADY_EXECUTED_ERR_MSG};
+use migration_module::bridge_version::{BridgeVersion, InterfaceVersion};
+use migration_module::StorageVersion;
+
+const STORAGE_VERSION: StorageVersion = 1;
+const INTERFACE_VERSION: InterfaceVersion = (1, 0, 0);
+
+/// Registry of the executed Ethereum -> MultiversX transfers, as (batch ID, tx nonce) pairs.
+///
+/// It is kept apart from the multisig and MultiTransferEsdt contracts, so redeploying them
+/// does not reset it: a new MultiTransferEsdt only has to be added as a registrar
+/// to keep refusing the transfers executed by the previous ones.
+#[multiversx_sc::contract]
+pub trait ReplayProtection: migration_module::MigrationModule {
+    #[init]
+    fn init(&self) {
+        self.migrate_storage(STORAGE_VERSION, |_| {});
+    }
+
+    /// The interface version and the features this contract supports, see `BridgeVersion`.
+    #[view(getBridgeVersion)]
+    fn get_bridge_version(&self) -> BridgeVersion {
+        BridgeVersion::new(INTERFACE_VERSION, 0, 0)
+    }
+
+    /// Allows the address to record executed transfers, usually a MultiTransferEsdt contract.
+    #[only_owner]
+    #[endpoint(addRegistrar)]
+    fn add_registrar(&self, registrar: ManagedAddress) {
+        let _ = self.registrars().insert(registrar);
+    }
+
+    #[only_owner]
+    #[endpoint(removeRegistrar)]
+    fn remove_registrar(&self, registrar: ManagedAddress) {
+        let _ = self.registrars().swap_remove(&registrar);
+    }
+
+    /// Records the transfers of the batch as executed.
+    /// Fails if any of them was already recorded, so the caller does not execute it again.
+    #[endpoint(recordExecutedTransfers)]
+    fn record_executed_transfers(&self, batch_id: u64, tx_nonces: MultiValueEncoded<u64>) {
+        let caller = self.blockchain().get_caller();
+        require!(self.registrars().contains(&caller), NOT_REGISTRAR_ERR_MSG);
+
+        let mut nr_transfers = 0usize;
+        for tx_nonce in tx_nonces {
+            let executed_mapper = self.executed_transfer(batch_id, tx_nonce);
+            require!(!executed_mapper.get(), TRANSFER_ALREADY_EXECUTED_ERR_MSG);
+
+            executed_mapper.set(true);
+            nr_transfers += 1;
+        }
+
+        self.executed_transfers_recorded_event(batch_id, &caller, nr_transfers);
+    }
+
+    #[event("executedTransfersRecorded")]
+    fn executed_transfers_recorded_event(
+        &self,
+        #[indexed] batch_id: u64,
+        #[indexed] registrar: &ManagedAddress,
+        #[indexed] nr_transfers: usize,
+    );
+
+    #[view(getRegistrars)]
+    #[storage_mapper("registrars")]
+    fn registrars(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(wasTransferExecuted)]
+    #[storage_mapper("executedTransfer")]
+    fn executed_transfer(&self, batch_id: u64, tx_nonce: u64) -> SingleValueMapper<bool>;
+}
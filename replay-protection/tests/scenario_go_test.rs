@@ -0,0 +1,4 @@
+#[test]
+fn record_executed_transfers_go() {
+    multiversx_sc_scenario::run_go("mandos/record_executed_transfers.scen.json");
+}